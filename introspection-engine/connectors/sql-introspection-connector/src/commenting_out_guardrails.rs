@@ -48,15 +48,12 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // fields with unsupported as datatype
-    for model in datamodel.models_mut() {
-        let model_name = model.name.clone();
-
-        for field in model.scalar_fields_mut() {
+    // fields with unsupported as datatype are kept for migrations, but hidden by the query engine
+    for model in datamodel.models() {
+        for field in model.scalar_fields() {
             if let FieldType::Unsupported(tpe) = &field.field_type {
-                field.is_commented_out = true;
                 unsupported_types.push(ModelAndFieldAndType {
-                    model: model_name.clone(),
+                    model: model.name.clone(),
                     field: field.name.clone(),
                     tpe: tpe.clone(),
                 })
@@ -64,21 +61,17 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // use unsupported types to drop @@id / @@unique /@@index
-    for mf in &unsupported_types {
-        let model = datamodel.find_model_mut(&mf.model);
-        model.indices.retain(|i| !i.fields.contains(&mf.field));
-        if model.id_fields.contains(&mf.field) {
-            model.id_fields = vec![]
-        };
-    }
-
-    // models without uniques / ids
+    // models without uniques / ids, or with an id of an unsupported type, are kept for migrations,
+    // but ignored by the query engine
     for model in datamodel.models_mut() {
-        if model.strict_unique_criterias().is_empty() {
-            model.is_commented_out = true;
+        let has_unsupported_id = model
+            .scalar_fields()
+            .any(|field| field.field_type.is_unsupported() && (field.is_id || model.id_fields.contains(&field.name)));
+
+        if model.strict_unique_criterias().is_empty() || has_unsupported_id {
+            model.is_ignored = true;
             model.documentation = Some(
-                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
+                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client."
                     .to_string(),
             );
             models_without_identifiers.push(Model {
@@ -87,12 +80,17 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
         }
     }
 
-    // remove their backrelations
+    // ignore their backrelations
     for model_without_identifier in &models_without_identifiers {
         for model in datamodel.models_mut() {
+            // The fields of ignored models are ignored already.
+            if model.is_ignored {
+                continue;
+            }
+
             for field in model.relation_fields_mut() {
                 if field.points_to_model(&model_without_identifier.model) {
-                    field.is_commented_out = true;
+                    field.is_ignored = true;
                 }
             }
         }
@@ -122,10 +122,6 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
 pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarField {
     debug!("Handling column {:?}", column);
     let field_type = calculate_scalar_field_type(&column);
-    let (is_commented_out, documentation) = match field_type {
-        FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
-        _ => (false, None),
-    };
 
     let arity = match column.tpe.arity {
         _ if column.auto_increment && field_type == FieldType::Base(ScalarType::Int, None) => FieldArity::Required,
@@ -146,10 +142,12 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> ScalarFi
         default_value,
         is_unique,
         is_id,
-        documentation,
+        documentation: None,
         is_generated: false,
         is_updated_at: false,
-        is_commented_out,
+        is_commented_out: false,
+        is_ignored: false,
+        validations: Vec::new(),
     }
 }
 
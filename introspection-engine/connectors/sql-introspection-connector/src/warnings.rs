@@ -65,7 +65,7 @@ impl EnumAndValue {
 pub fn warning_models_without_identifier(affected: &Vec<Model>) -> Warning {
     Warning {
         code: 1,
-        message: "The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.".into(),
+        message: "The following models were ignored as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
@@ -82,7 +82,7 @@ pub fn warning_fields_with_empty_names(affected: &Vec<ModelAndField>) -> Warning
 pub fn warning_unsupported_types(affected: &Vec<ModelAndFieldAndType>) -> Warning {
     Warning {
         code: 3,
-        message: "These fields were commented out because Prisma currently does not support their types.".into(),
+        message: "These fields are not supported by the Prisma Client, because Prisma currently does not support their types.".into(),
         affected: serde_json::to_value(&affected).unwrap(),
     }
 }
@@ -4,7 +4,7 @@ use pretty_assertions::assert_eq;
 use test_harness::*;
 
 #[test_each_connector(tags("sqlite"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out_sqlite(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it_sqlite(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -20,14 +20,14 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_sqlite(api:
         })
         .await;
 
-    let dm = "model User {\n  id      Int    @default(autoincrement()) @id\n  // Post Post[]\n}\n\n// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n";
+    let dm = "model User {\n  id   Int    @default(autoincrement()) @id\n  Post Post[] @ignore\n}\n\n/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Post {\n  id      Int\n  user_id Int\n  User    User @relation(fields: [user_id], references: [id])\n\n  @@ignore\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
 }
 
 #[test_each_connector(tags("sqlite"))]
-async fn introspecting_a_table_without_required_uniques_should_comment_it_out_sqlite(api: &TestApi) {
+async fn introspecting_a_table_without_required_uniques_should_ignore_it_sqlite(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("Post", |t| {
@@ -37,14 +37,14 @@ async fn introspecting_a_table_without_required_uniques_should_comment_it_out_sq
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id         Int\n  // opt_unique Int? @unique\n// }\n";
+    let dm = "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Post {\n  id         Int\n  opt_unique Int? @unique\n\n  @@ignore\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
 }
 
 #[test_each_connector(tags("sqlite"))]
-async fn introspecting_a_table_without_fully_required_compound_unique_should_comment_it_out_sqlite(api: &TestApi) {
+async fn introspecting_a_table_without_fully_required_compound_unique_should_ignore_it_sqlite(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("Post", |t| {
@@ -56,14 +56,14 @@ async fn introspecting_a_table_without_fully_required_compound_unique_should_com
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id         Int\n  // opt_unique Int?\n  // req_unique Int\n\n  // @@unique([opt_unique, req_unique], name: \"sqlite_autoindex_Post_1\")\n// }\n";
+    let dm = "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Post {\n  id         Int\n  opt_unique Int?\n  req_unique Int\n\n  @@ignore\n  @@unique([opt_unique, req_unique], name: \"sqlite_autoindex_Post_1\")\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
 }
 
 #[test_each_connector(tags("mysql"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out_mysql(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it_mysql(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -79,7 +79,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out_mysql(api:
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n\n  // @@index([user_id], name: \"user_id\")\n// }\n\nmodel User {\n  id      Int    @default(autoincrement()) @id\n  // Post Post[]\n}\n";
+    let dm = "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Post {\n  id      Int\n  user_id Int\n  User    User @relation(fields: [user_id], references: [id])\n\n  @@ignore\n  @@index([user_id], name: \"user_id\")\n}\n\nmodel User {\n  id   Int    @default(autoincrement()) @id\n  Post Post[] @ignore\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
@@ -130,7 +130,7 @@ async fn introspecting_an_enum_with_an_invalid_value_as_default_should_work(api:
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_also_drop_its_usages(api: &TestApi) {
+async fn introspecting_an_unsupported_id_type_should_keep_its_usages_and_ignore_the_model(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -148,15 +148,15 @@ async fn introspecting_an_unsupported_type_should_and_commenting_it_out_should_a
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"The following models were ignored as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  id             Int     @unique\n  dummy          Int\n  // This type is currently not supported.\n  // network_mac macaddr\n}\n");
+    assert_eq!(&result, "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Test {\n  id          Int                    @unique\n  dummy       Int\n  network_mac Unsupported(\"macaddr\")\n\n  @@id([network_mac, dummy])\n  @@ignore\n  @@index([network_mac, dummy], name: \"non_unique\")\n  @@unique([network_mac, dummy], name: \"unique\")\n}\n");
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_with_only_an_unsupported_id_type_should_ignore_it(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -170,15 +170,15 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":1,\"message\":\"The following models were commented out as they do not have a valid unique identifier or id. This is currently not supported by Prisma.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":1,\"message\":\"The following models were ignored as they do not have a valid unique identifier or id. This is currently not supported by the Prisma Client.\",\"affected\":[{\"model\":\"Test\"}]},{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n// }\n");
+    assert_eq!(&result, "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Test {\n  dummy       Int\n  network_mac Unsupported(\"macaddr\") @id\n\n  @@ignore\n}\n");
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi) {
+async fn introspecting_an_unsupported_type_should_keep_its_raw_type(api: &TestApi) {
     let barrel = api.barrel();
     let _setup_schema = barrel
         .execute(|migration| {
@@ -193,11 +193,11 @@ async fn introspecting_an_unsupported_type_should_comment_it_out(api: &TestApi)
     let warnings = dbg!(api.introspection_warnings().await);
     assert_eq!(
         &warnings,
-        "[{\"code\":3,\"message\":\"These fields were commented out because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
+        "[{\"code\":3,\"message\":\"These fields are not supported by the Prisma Client, because Prisma currently does not support their types.\",\"affected\":[{\"model\":\"Test\",\"field\":\"network_mac\",\"tpe\":\"macaddr\"}]}]"
     );
 
     let result = dbg!(api.introspect().await);
-    assert_eq!(&result, "model Test {\n  id             Int      @default(autoincrement()) @id\n  network_inet   String?\n  // This type is currently not supported.\n  // network_mac macaddr?\n}\n");
+    assert_eq!(&result, "model Test {\n  id           Int                     @default(autoincrement()) @id\n  network_inet String?\n  network_mac  Unsupported(\"macaddr\")?\n}\n");
 }
 
 #[test_each_connector(tags("postgres"))]
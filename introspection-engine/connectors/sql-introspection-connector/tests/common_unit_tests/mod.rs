@@ -32,31 +32,29 @@ fn a_data_model_can_be_generated_from_a_schema() {
             database_name: None,
             name: "Table1".to_string(),
             documentation: Some(
-                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled."
+                "The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client."
                     .to_string(),
             ),
             is_embedded: false,
+            extends: vec![],
             is_generated: false,
-            is_commented_out: true,
+            is_commented_out: false,
+            is_ignored: true,
             indices: vec![],
             id_fields: vec![],
             fields: col_types
                 .iter()
                 .map(|col_type| {
-                    let (field_type, is_commented_out, documentation) = match col_type {
-                        ColumnTypeFamily::Boolean => (FieldType::Base(ScalarType::Boolean, None), false, None),
-                        ColumnTypeFamily::DateTime => (FieldType::Base(ScalarType::DateTime, None), false, None),
-                        ColumnTypeFamily::Float => (FieldType::Base(ScalarType::Float, None), false, None),
-                        ColumnTypeFamily::Int => (FieldType::Base(ScalarType::Int, None), false, None),
-                        ColumnTypeFamily::String => (FieldType::Base(ScalarType::String, None), false, None),
-                        ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
-                        ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
-                        ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
-                        x => (
-                            FieldType::Unsupported(x.to_string()),
-                            true,
-                            Some("This type is currently not supported.".to_string()),
-                        ),
+                    let field_type = match col_type {
+                        ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
+                        ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
+                        ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
+                        ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
+                        ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
+                        ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
+                        ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
+                        ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+                        x => FieldType::Unsupported(x.to_string()),
                     };
                     Field::ScalarField(ScalarField {
                         name: col_type.to_string(),
@@ -66,15 +64,18 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         default_value: None,
                         is_unique: false,
                         is_id: false,
-                        documentation,
+                        documentation: None,
                         is_generated: false,
                         is_updated_at: false,
-                        is_commented_out,
+                        is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     })
                 })
                 .collect(),
         }],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -110,7 +111,9 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            extends: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field::ScalarField(ScalarField::new(
                     "optional",
@@ -129,6 +132,8 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
                 Field::ScalarField(ScalarField::new(
                     "list",
@@ -141,6 +146,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -191,7 +197,9 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            extends: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field::ScalarField(ScalarField::new(
                     "no_default",
@@ -210,6 +218,8 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
                 Field::ScalarField(ScalarField {
                     name: "bool_default".to_string(),
@@ -223,6 +233,8 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
                 Field::ScalarField(ScalarField {
                     name: "float_default".to_string(),
@@ -236,6 +248,8 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
                 Field::ScalarField(ScalarField {
                     name: "string_default".to_string(),
@@ -249,6 +263,8 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
             ],
             is_generated: false,
@@ -260,6 +276,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -324,7 +341,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field::ScalarField(ScalarField {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -337,6 +356,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 })],
                 is_generated: false,
                 indices: vec![],
@@ -348,7 +369,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field::ScalarField(ScalarField {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -361,6 +384,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 })],
                 is_generated: false,
                 indices: vec![],
@@ -372,7 +397,9 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![Field::ScalarField(ScalarField {
                     name: "primary".to_string(),
                     arity: FieldArity::Required,
@@ -385,6 +412,8 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 })],
                 is_generated: false,
                 indices: vec![],
@@ -392,6 +421,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -485,7 +515,9 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            extends: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field::ScalarField(ScalarField::new(
                     "non_unique",
@@ -504,6 +536,8 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
             ],
             is_generated: false,
@@ -511,6 +545,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             id_fields: vec![],
         }],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -556,7 +591,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -570,6 +607,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -597,7 +636,9 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -611,6 +652,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_id".to_string(),
@@ -624,6 +667,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::ScalarField(ScalarField {
                         name: "city_name".to_string(),
@@ -637,6 +682,8 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::RelationField(RelationField::new(
                         "City",
@@ -656,6 +703,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
             },
         ],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -774,7 +822,9 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            extends: vec![],
             is_commented_out: false,
+            is_ignored: false,
             fields: vec![
                 Field::ScalarField(ScalarField {
                     name: "id".to_string(),
@@ -788,6 +838,8 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     is_generated: false,
                     is_updated_at: false,
                     is_commented_out: false,
+                    is_ignored: false,
+                    validations: Vec::new(),
                 }),
                 Field::ScalarField(ScalarField::new(
                     "name",
@@ -809,6 +861,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             id_fields: vec![],
         }],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -885,7 +938,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -899,6 +954,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::ScalarField(ScalarField::new(
                         "name",
@@ -926,7 +983,9 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                extends: vec![],
                 is_commented_out: false,
+                is_ignored: false,
                 fields: vec![
                     Field::ScalarField(ScalarField {
                         name: "id".to_string(),
@@ -940,6 +999,8 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         is_generated: false,
                         is_updated_at: false,
                         is_commented_out: false,
+                        is_ignored: false,
+                        validations: Vec::new(),
                     }),
                     Field::ScalarField(ScalarField::new(
                         "city_id",
@@ -964,6 +1025,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
             },
         ],
         enums: vec![],
+        abstract_models: vec![],
     };
 
     let schema = SqlSchema {
@@ -1084,6 +1146,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        abstract_models: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
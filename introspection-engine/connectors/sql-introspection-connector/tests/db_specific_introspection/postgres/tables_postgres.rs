@@ -287,7 +287,7 @@ async fn introspecting_a_table_with_a_multi_column_non_unique_index_should_work(
 }
 
 #[test_each_connector(tags("postgres"))]
-async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestApi) {
+async fn introspecting_a_table_without_uniques_should_ignore_it(api: &TestApi) {
     api.barrel()
         .execute(|migration| {
             migration.create_table("User", |t| {
@@ -300,7 +300,7 @@ async fn introspecting_a_table_without_uniques_should_comment_it_out(api: &TestA
         })
         .await;
 
-    let dm = "// The underlying table does not contain a valid unique identifier and can therefore currently not be handled.\n// model Post {\n  // id      Int\n  // user_id Int\n  // User    User @relation(fields: [user_id], references: [id])\n// }\n\nmodel User {\n  id      Int    @default(autoincrement()) @id\n  // Post Post[]\n}\n";
+    let dm = "/// The underlying table does not contain a valid unique identifier and can therefore currently not be handled by the Prisma Client.\nmodel Post {\n  id      Int\n  user_id Int\n  User    User @relation(fields: [user_id], references: [id])\n\n  @@ignore\n}\n\nmodel User {\n  id   Int    @default(autoincrement()) @id\n  Post Post[] @ignore\n}\n";
 
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, dm);
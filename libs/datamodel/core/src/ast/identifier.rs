@@ -1,6 +1,8 @@
 use super::*;
 use crate::error::DatamodelError;
 
+const UNSUPPORTED_TYPE_PREFIX: &str = "Unsupported(";
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Identifier {
     pub name: String,
@@ -15,6 +17,22 @@ impl Identifier {
         }
     }
 
+    /// The identifier of a field type Prisma does not support, e.g. `Unsupported("tsvector")`.
+    pub fn new_unsupported(raw_type: &str) -> Identifier {
+        Identifier::new(&format!("{}\"{}\")", UNSUPPORTED_TYPE_PREFIX, raw_type))
+    }
+
+    /// Returns the raw database type if this identifier is an `Unsupported("...")` field type.
+    pub fn unsupported_type(&self) -> Option<&str> {
+        let name = self.name.as_str();
+
+        if name.starts_with(UNSUPPORTED_TYPE_PREFIX) && name.ends_with("\")") {
+            name.get(UNSUPPORTED_TYPE_PREFIX.len() + 1..name.len() - 2)
+        } else {
+            None
+        }
+    }
+
     pub fn validate(&self, schema_item: &str) -> Result<(), DatamodelError> {
         if self.name.is_empty() {
             Err(DatamodelError::new_validation_error(
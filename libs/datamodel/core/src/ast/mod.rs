@@ -12,6 +12,7 @@ mod field;
 mod generator_config;
 mod identifier;
 mod model;
+mod schema_files;
 mod source_config;
 mod span;
 mod top;
@@ -30,6 +31,7 @@ pub use generator_config::GeneratorConfig;
 pub use identifier::Identifier;
 pub use model::Model;
 pub use r#enum::{Enum, EnumValue};
+pub use schema_files::SchemaFiles;
 pub use source_config::SourceConfig;
 pub use span::Span;
 pub use top::Top;
@@ -15,6 +15,12 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Declared with the `type` keyword: a composite type, stored inside the models using it.
+    pub is_embedded: bool,
+    /// Declared with the `abstract` keyword: only a group of fields other models can extend.
+    pub is_abstract: bool,
+    /// The abstract models this model inherits fields from.
+    pub extends: Vec<Identifier>,
 }
 
 impl Model {
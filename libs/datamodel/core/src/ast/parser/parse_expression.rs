@@ -1,10 +1,12 @@
 use once_cell::sync::Lazy;
+use pest::Parser;
 use regex::Regex;
 use std::borrow::Cow;
 
 use super::helpers::{parsing_catch_all, Token, TokenExtensions};
-use super::Rule;
+use super::{PrismaDatamodelParser, Rule};
 use crate::ast::*;
+use crate::error::DatamodelError;
 
 pub fn parse_expression(token: &Token) -> Expression {
     let first_child = token.first_relevant_child();
@@ -62,6 +64,41 @@ pub fn parse_arg_value(token: &Token) -> Expression {
     }
 }
 
+/// Splits the value of a string literal into the text between its `${...}` interpolations, as
+/// string values, and the interpolated expressions, in order. Escaped interpolations (`\${`) are
+/// part of the text. The `offset` is the position of the value in the schema.
+pub fn parse_interpolated_string(value: &str, offset: usize) -> Result<Vec<Expression>, DatamodelError> {
+    let span = Span::new(offset, offset + value.len());
+    let mut parsed = PrismaDatamodelParser::parse(Rule::string_interpolated, value)
+        .map_err(|_| DatamodelError::new_validation_error("This string interpolation is invalid.", span))?;
+
+    let mut parts = Vec::new();
+    let mut text = String::new();
+
+    for current in parsed.next().unwrap().relevant_children() {
+        match current.as_rule() {
+            Rule::string_any => text.push_str(current.as_str()),
+            Rule::string_escaped_interpolation => text.push_str("${"),
+            Rule::string_interpolate_escape => {
+                if !text.is_empty() {
+                    parts.push(Expression::StringValue(std::mem::take(&mut text), span));
+                }
+
+                let expression = parse_expression(&current.first_relevant_child());
+                parts.push(expression.with_lifted_span(offset));
+            }
+            Rule::EOI => {}
+            _ => parsing_catch_all(&current, "string interpolation"),
+        }
+    }
+
+    if !text.is_empty() {
+        parts.push(Expression::StringValue(text, span));
+    }
+
+    Ok(parts)
+}
+
 fn parse_string_literal(token: &Token) -> String {
     let current = token.first_relevant_child();
     match current.as_rule() {
@@ -0,0 +1,105 @@
+use super::parse_schema::parse_schema;
+use crate::ast::*;
+use crate::error::ErrorCollection;
+
+/// Parses the files of a schema split across multiple files, and merges them into a single AST.
+/// All spans in the AST and in the errors point to the file they come from.
+pub fn parse_schema_files(files: &SchemaFiles) -> Result<SchemaAst, ErrorCollection> {
+    let mut errors = ErrorCollection::new();
+    let mut tops = Vec::new();
+
+    for (file_id, _name, contents) in files.iter() {
+        match parse_schema(contents) {
+            Ok(ast) => tops.extend(ast.tops.into_iter().map(|mut top| {
+                set_file_id_on_top(&mut top, file_id);
+                top
+            })),
+            Err(err) => errors.append_vec(err.errors.into_iter().map(|err| err.in_file(file_id)).collect()),
+        }
+    }
+
+    errors.ok()?;
+
+    Ok(SchemaAst { tops })
+}
+
+fn set_file_id_on_top(top: &mut Top, file_id: usize) {
+    match top {
+        Top::Model(model) => {
+            set_file_id(&mut model.span, file_id);
+            set_file_id(&mut model.name.span, file_id);
+
+            for parent in model.extends.iter_mut() {
+                set_file_id(&mut parent.span, file_id);
+            }
+
+            model.fields.iter_mut().for_each(|field| set_file_id_on_field(field, file_id));
+            set_file_id_on_directives(&mut model.directives, file_id);
+        }
+        Top::Enum(enm) => {
+            set_file_id(&mut enm.span, file_id);
+            set_file_id(&mut enm.name.span, file_id);
+            set_file_id_on_directives(&mut enm.directives, file_id);
+
+            for value in enm.values.iter_mut() {
+                set_file_id(&mut value.span, file_id);
+                set_file_id(&mut value.name.span, file_id);
+                set_file_id_on_directives(&mut value.directives, file_id);
+            }
+        }
+        Top::Source(source) => {
+            set_file_id(&mut source.span, file_id);
+            set_file_id(&mut source.name.span, file_id);
+            set_file_id_on_arguments(&mut source.properties, file_id);
+        }
+        Top::Generator(generator) => {
+            set_file_id(&mut generator.span, file_id);
+            set_file_id(&mut generator.name.span, file_id);
+            set_file_id_on_arguments(&mut generator.properties, file_id);
+        }
+        Top::Type(alias) => set_file_id_on_field(alias, file_id),
+    }
+}
+
+fn set_file_id_on_field(field: &mut Field, file_id: usize) {
+    set_file_id(&mut field.span, file_id);
+    set_file_id(&mut field.name.span, file_id);
+    set_file_id(&mut field.field_type.span, file_id);
+    set_file_id_on_directives(&mut field.directives, file_id);
+}
+
+fn set_file_id_on_directives(directives: &mut [Directive], file_id: usize) {
+    for directive in directives {
+        set_file_id(&mut directive.span, file_id);
+        set_file_id(&mut directive.name.span, file_id);
+        set_file_id_on_arguments(&mut directive.arguments, file_id);
+    }
+}
+
+fn set_file_id_on_arguments(arguments: &mut [Argument], file_id: usize) {
+    for argument in arguments {
+        set_file_id(&mut argument.span, file_id);
+        set_file_id(&mut argument.name.span, file_id);
+        set_file_id_on_expression(&mut argument.value, file_id);
+    }
+}
+
+fn set_file_id_on_expression(expression: &mut Expression, file_id: usize) {
+    match expression {
+        Expression::NumericValue(_, span)
+        | Expression::BooleanValue(_, span)
+        | Expression::StringValue(_, span)
+        | Expression::Any(_, span)
+        | Expression::ConstantValue(_, span) => set_file_id(span, file_id),
+        Expression::Function(_, arguments, span) | Expression::Array(arguments, span) => {
+            set_file_id(span, file_id);
+            arguments
+                .iter_mut()
+                .for_each(|argument| set_file_id_on_expression(argument, file_id));
+        }
+    }
+}
+
+fn set_file_id(span: &mut Span, file_id: usize) {
+    span.file_id = file_id;
+}
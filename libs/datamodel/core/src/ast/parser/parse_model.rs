@@ -14,13 +14,15 @@ pub fn parse_model(token: &Token) -> Result<Model, ErrorCollection> {
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
+    let mut is_embedded = false;
+    let mut is_abstract = false;
+    let mut extends: Vec<Identifier> = vec![];
 
     for current in token.relevant_children() {
         match current.as_rule() {
-            Rule::TYPE_KEYWORD => errors.push(DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()),
-            )),
+            Rule::TYPE_KEYWORD => is_embedded = true,
+            Rule::ABSTRACT_KEYWORD => is_abstract = true,
+            Rule::model_extends => extends = current.relevant_children().iter().map(|id| id.to_id()).collect(),
             Rule::non_empty_identifier => name = Some(current.to_id()),
             Rule::directive => directives.push(parse_directive(&current)),
             Rule::field_declaration => match parse_field(&name.as_ref().unwrap().name, &current) {
@@ -46,6 +48,9 @@ pub fn parse_model(token: &Token) -> Result<Model, ErrorCollection> {
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_embedded,
+            is_abstract,
+            extends,
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
@@ -88,6 +88,7 @@ fn get_expected_from_error(positives: &[Rule]) -> Vec<&'static str> {
 fn rule_to_string(rule: Rule) -> &'static str {
     match rule {
         Rule::model_declaration => "model declaration",
+        Rule::model_extends => "extended models",
         Rule::enum_declaration => "enum declaration",
         Rule::source_block => "source definition",
         Rule::generator_block => "generator definition",
@@ -112,6 +113,7 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::directive => "directive",
         Rule::optional_type => "optional type",
         Rule::base_type => "type",
+        Rule::unsupported_type => "unsupported type",
         Rule::list_type => "list type",
         Rule::field_type => "field type",
         Rule::field_declaration => "field declaration",
@@ -131,6 +133,8 @@ fn rule_to_string(rule: Rule) -> &'static str {
         Rule::BLOCK_CLOSE => "End of block (\"}\")",
         Rule::MODEL_KEYWORD => "\"model\" keyword",
         Rule::TYPE_KEYWORD => "\"type\" keyword",
+        Rule::ABSTRACT_KEYWORD => "\"abstract\" keyword",
+        Rule::EXTENDS_KEYWORD => "\"extends\" keyword",
         Rule::ENUM_KEYWORD => "\"enum\" keyword",
         Rule::GENERATOR_KEYWORD => "\"generator\" keyword",
         Rule::DATASOURCE_KEYWORD => "\"datasource\" keyword",
@@ -70,6 +70,10 @@ fn parse_base_type(token: &Token) -> String {
     let current = token.first_relevant_child();
     match current.as_rule() {
         Rule::non_empty_identifier => current.as_str().to_string(),
+        Rule::unsupported_type => {
+            let raw_type = current.first_relevant_child().first_relevant_child().as_str();
+            Identifier::new_unsupported(raw_type).name
+        }
         _ => unreachable!("Encountered impossible type during parsing: {:?}", current.tokens()),
     }
 }
@@ -32,8 +32,9 @@ impl<'a> Reformatter<'a> {
 
         for model in datamodel.models() {
             let ast_model = schema_ast.find_model(&model.name).unwrap();
+            let inherited_fields: Vec<&str> = datamodel.inherited_fields(model).map(|field| field.name()).collect();
 
-            for field in model.fields() {
+            for field in model.fields().filter(|field| !inherited_fields.contains(&field.name())) {
                 if ast_model.fields.iter().find(|f| f.name.name == field.name()).is_none() {
                     let ast_field = lowerer.lower_field(&field, &datamodel)?;
 
@@ -222,12 +223,23 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let has_keyword = |keyword: Rule| token.clone().into_inner().any(|current| current.as_rule() == keyword);
+
+        let block_type = if has_keyword(Rule::TYPE_KEYWORD) {
+            "type"
+        } else if has_keyword(Rule::ABSTRACT_KEYWORD) {
+            "abstract model"
+        } else {
+            "model"
+        };
+
         self.reformat_block_element_internal(
-            "model",
+            block_type,
             target,
             &token,
             Box::new(|table, renderer, token, model_name| {
                 match token.as_rule() {
+                    Rule::TYPE_KEYWORD | Rule::ABSTRACT_KEYWORD => {}
                     Rule::directive => {
                         // model level Directives reset the table. -> .render() does that
                         table.render(renderer);
@@ -273,22 +285,38 @@ impl<'a> Reformatter<'a> {
     ) {
         let mut table = TableFormat::new();
         let mut block_name = "";
+        let mut block_extends: Vec<&str> = Vec::new();
         let mut block_has_opened = false;
 
         for current in token.clone().into_inner() {
             // println!("block: {:?} |{:?}|", current.as_rule(), current.as_str());
             match current.as_rule() {
                 Rule::BLOCK_OPEN => {
+                    // Begin.
                     block_has_opened = true;
+                    if block_extends.is_empty() {
+                        renderer.write(&format!("{} {} {{", block_type, block_name));
+                    } else {
+                        renderer.write(&format!(
+                            "{} {} extends {} {{",
+                            block_type,
+                            block_name,
+                            block_extends.join(", ")
+                        ));
+                    }
+                    renderer.end_line();
+                    renderer.indent_up();
                 }
                 Rule::BLOCK_CLOSE => {}
 
-                Rule::non_empty_identifier | Rule::maybe_empty_identifier => {
-                    // Begin.
-                    block_name = current.as_str();
-                    renderer.write(&format!("{} {} {{", block_type, block_name));
-                    renderer.end_line();
-                    renderer.indent_up();
+                Rule::non_empty_identifier | Rule::maybe_empty_identifier => block_name = current.as_str(),
+                Rule::model_extends => {
+                    block_extends = current
+                        .clone()
+                        .into_inner()
+                        .filter(|parent| parent.as_rule() == Rule::non_empty_identifier)
+                        .map(|parent| parent.as_str())
+                        .collect();
                 }
                 Rule::comment_block => {
                     for current in current.clone().into_inner() {
@@ -443,8 +471,20 @@ impl<'a> Reformatter<'a> {
 
     fn get_identifier(token: &Token) -> String {
         for current in token.clone().into_inner() {
-            if let Rule::non_empty_identifier | Rule::maybe_empty_identifier = current.as_rule() {
-                return current.as_str().to_string();
+            match current.as_rule() {
+                Rule::non_empty_identifier | Rule::maybe_empty_identifier => return current.as_str().to_string(),
+                Rule::unsupported_type => {
+                    let raw_type = current
+                        .clone()
+                        .into_inner()
+                        .flat_map(|token| token.into_inner())
+                        .find(|token| token.as_rule() == Rule::string_content)
+                        .map(|token| token.as_str())
+                        .unwrap_or("");
+
+                    return crate::ast::Identifier::new_unsupported(raw_type).name;
+                }
+                _ => {}
             }
         }
 
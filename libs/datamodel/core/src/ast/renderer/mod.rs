@@ -173,8 +173,22 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_embedded {
+            "type"
+        } else if model.is_abstract {
+            "abstract model"
+        } else {
+            "model"
+        };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
+
+        if !model.extends.is_empty() {
+            let parents: Vec<&str> = model.extends.iter().map(|parent| parent.name.as_str()).collect();
+            self.write(&format!(" extends {}", parents.join(", ")));
+        }
+
         self.write(" {");
         self.end_line();
         self.indent_up();
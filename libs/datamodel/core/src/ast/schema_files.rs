@@ -0,0 +1,79 @@
+use std::path::Path;
+
+/// The files of a schema split across multiple files. The position of a file in the list is the
+/// `file_id` of the spans pointing into it.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaFiles {
+    files: Vec<SchemaFile>,
+}
+
+#[derive(Debug, Clone)]
+struct SchemaFile {
+    name: String,
+    contents: String,
+}
+
+impl SchemaFiles {
+    pub fn new() -> Self {
+        SchemaFiles { files: Vec::new() }
+    }
+
+    /// Loads all the `.prisma` files in a directory, in alphabetical order. Subdirectories are
+    /// not visited.
+    pub fn load_dir(dir: &Path) -> std::io::Result<Self> {
+        let mut paths = Vec::new();
+
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+
+            if path.is_file() && path.extension().map(|ext| ext == "prisma").unwrap_or(false) {
+                paths.push(path);
+            }
+        }
+
+        paths.sort();
+
+        let mut files = SchemaFiles::new();
+
+        for path in paths {
+            let contents = std::fs::read_to_string(&path)?;
+            files.add(path.to_string_lossy().into_owned(), contents);
+        }
+
+        Ok(files)
+    }
+
+    /// Adds a file, and returns its `file_id`.
+    pub fn add(&mut self, name: impl Into<String>, contents: impl Into<String>) -> usize {
+        self.files.push(SchemaFile {
+            name: name.into(),
+            contents: contents.into(),
+        });
+
+        self.files.len() - 1
+    }
+
+    pub fn name(&self, file_id: usize) -> Option<&str> {
+        self.files.get(file_id).map(|file| file.name.as_str())
+    }
+
+    pub fn contents(&self, file_id: usize) -> Option<&str> {
+        self.files.get(file_id).map(|file| file.contents.as_str())
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Iterates over the `file_id`, name and contents of all files.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str, &str)> {
+        self.files
+            .iter()
+            .enumerate()
+            .map(|(file_id, file)| (file_id, file.name.as_str(), file.contents.as_str()))
+    }
+}
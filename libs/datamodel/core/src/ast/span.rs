@@ -3,22 +3,30 @@
 pub struct Span {
     pub start: usize,
     pub end: usize,
+    /// The file the span is in, for schemas split across multiple files. See
+    /// [SchemaFiles](struct.SchemaFiles.html). Single file schemas only use file `0`.
+    pub file_id: usize,
 }
 
 impl Span {
     pub fn new(start: usize, end: usize) -> Span {
-        Span { start, end }
+        Span { start, end, file_id: 0 }
     }
 
     // Creates a new empty span.
     pub fn empty() -> Span {
-        Span { start: 0, end: 0 }
+        Span {
+            start: 0,
+            end: 0,
+            file_id: 0,
+        }
     }
     /// Creates a new ast::Span from a pest::Span.
     pub fn from_pest(s: pest::Span) -> Span {
         Span {
             start: s.start(),
             end: s.end(),
+            file_id: 0,
         }
     }
 
@@ -27,8 +35,14 @@ impl Span {
         Span {
             start: offset + self.start,
             end: offset + self.end,
+            file_id: self.file_id,
         }
     }
+
+    /// The same span, in another file.
+    pub fn in_file(&self, file_id: usize) -> Span {
+        Span { file_id, ..*self }
+    }
 }
 
 impl std::fmt::Display for Span {
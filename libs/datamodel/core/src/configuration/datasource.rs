@@ -10,6 +10,8 @@ pub struct Datasource {
     /// the provider that was selected as active from all specified providers
     pub active_provider: String,
     pub url: StringFromEnvVar,
+    /// the URLs of read replicas of the database, read-only operations may be sent to
+    pub replica_urls: Vec<StringFromEnvVar>,
     pub documentation: Option<String>,
     /// a connector representing the intersection of all providers specified
     pub combined_connector: Box<dyn Connector>,
@@ -35,6 +37,24 @@ pub struct StringFromEnvVar {
     /// contains the name of env var if the value was read from one
     pub from_env_var: Option<String>,
     pub value: String,
+    /// The value used when the env var is not set, from `env("VAR", "fallback")`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// The string with `${VAR}` placeholders the value was interpolated from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+impl StringFromEnvVar {
+    /// A value that was given in the schema as is.
+    pub fn new_literal(value: String) -> Self {
+        StringFromEnvVar {
+            from_env_var: None,
+            value,
+            fallback: None,
+            template: None,
+        }
+    }
 }
 
 impl PreviewFeatures for Datasource {
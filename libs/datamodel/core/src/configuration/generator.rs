@@ -12,6 +12,9 @@ pub struct Generator {
     pub binary_targets: Vec<String>,
     #[serde(default = "Vec::new")]
     pub preview_features: Vec<String>,
+    /// `rule:severity` entries configuring the lint rules, see the `lint` module.
+    #[serde(default = "Vec::new", skip_serializing_if = "Vec::is_empty")]
+    pub lint_rules: Vec<String>,
     pub config: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
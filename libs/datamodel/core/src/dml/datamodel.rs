@@ -4,12 +4,15 @@ use super::*;
 /// know that introspection encountered unsupported names or features and these are supposed
 /// to be rendered as comments. Since the parser will not set these flags when reading a schema
 /// string, only introspection and the lowering of the datamodel to the ast care about these flags.
-/// The FieldType: Unsupported behaves in the same way.
-/// Both of these are never converted into the internal datamodel.
+/// Commented out entities are never converted into the internal datamodel.
+/// Fields of `FieldType::Unsupported` are part of the schema and used by migrations, but are
+/// hidden from the query engine in the same way.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Datamodel {
     pub enums: Vec<Enum>,
     pub models: Vec<Model>,
+    /// Abstract models are never used on their own, and only kept to render the schema again.
+    pub abstract_models: Vec<Model>,
 }
 
 impl Datamodel {
@@ -17,6 +20,7 @@ impl Datamodel {
         Datamodel {
             enums: Vec::new(),
             models: Vec::new(),
+            abstract_models: Vec::new(),
         }
     }
 
@@ -77,6 +81,14 @@ impl Datamodel {
             .find(|model| model.database_name.as_deref() == Some(db_name))
     }
 
+    /// Gets an iterator over the fields a model inherits from the abstract models it extends.
+    pub fn inherited_fields<'a>(&'a self, model: &'a Model) -> impl Iterator<Item = &'a Field> + 'a {
+        self.abstract_models
+            .iter()
+            .filter(move |parent| model.extends.contains(&parent.name))
+            .flat_map(|parent| parent.fields())
+    }
+
     /// Finds parent  model for a field reference.
     pub fn find_model_by_relation_field_ref(&self, field: &RelationField) -> Option<&Model> {
         self.find_model(&self.find_related_field_bang(&field).relation_info.to)
@@ -32,9 +32,13 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field of a composite type (declared with `type`), with the type of the given name.
+    /// The value is stored as JSON.
+    CompositeType(String),
     /// native field type.
     NativeType(ScalarType, NativeTypeInstance),
-    /// This is a field with an unsupported datatype - used by introspection only.
+    /// This is a field with a datatype Prisma does not support, with the raw database type.
+    /// Written `Unsupported("tsvector")` in the schema.
     Unsupported(String),
     /// The option is Some(x) if the scalar type is based upon a type alias.
     Base(ScalarType, Option<String>),
@@ -48,6 +52,13 @@ impl FieldType {
         }
     }
 
+    pub fn is_unsupported(&self) -> bool {
+        match self {
+            FieldType::Unsupported(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn scalar_type(&self) -> Option<ScalarType> {
         match self {
             FieldType::NativeType(st, _) => Some(*st),
@@ -100,6 +111,13 @@ impl Field {
         }
     }
 
+    pub fn is_ignored(&self) -> bool {
+        match self {
+            Field::ScalarField(sf) => sf.is_ignored,
+            Field::RelationField(rf) => rf.is_ignored,
+        }
+    }
+
     pub fn arity(&self) -> &FieldArity {
         match &self {
             Field::ScalarField(sf) => &sf.arity,
@@ -201,6 +219,9 @@ pub struct RelationField {
 
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// Indicates if this field is ignored by the query engine (`@ignore`).
+    pub is_ignored: bool,
 }
 
 impl RelationField {
@@ -213,6 +234,7 @@ impl RelationField {
             documentation: None,
             is_generated: false,
             is_commented_out: false,
+            is_ignored: false,
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
@@ -280,6 +302,12 @@ pub struct ScalarField {
 
     /// Indicates if this field has to be commented out.
     pub is_commented_out: bool,
+
+    /// Indicates if this field is ignored by the query engine (`@ignore`).
+    pub is_ignored: bool,
+
+    /// Constraints on the values of this field, enforced by the query engine.
+    pub validations: Vec<FieldValidation>,
 }
 
 impl ScalarField {
@@ -297,6 +325,8 @@ impl ScalarField {
             is_generated: false,
             is_updated_at: false,
             is_commented_out: false,
+            is_ignored: false,
+            validations: Vec::new(),
         }
     }
     /// Creates a new field with the given name and type, marked as generated and optional.
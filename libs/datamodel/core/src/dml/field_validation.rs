@@ -0,0 +1,55 @@
+use regex::Regex;
+use std::fmt;
+
+/// A constraint on the values written to a scalar field, e.g. `@length(min: 1, max: 20)`.
+/// These are not part of the database schema, but enforced by the query engine on every write.
+#[derive(Debug, PartialEq, Clone)]
+pub enum FieldValidation {
+    /// The number of characters of a String, e.g. `@length(min: 1, max: 20)`.
+    Length { min: Option<u64>, max: Option<u64> },
+    /// A regular expression a String has to match, e.g. `@pattern("^[a-z]+$")`.
+    Pattern(Pattern),
+    /// The inclusive bounds of a number, e.g. `@range(min: 0, max: 100)`.
+    Range { min: Option<f64>, max: Option<f64> },
+}
+
+impl FieldValidation {
+    /// The name of the attribute declaring this validation.
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldValidation::Length { .. } => "length",
+            FieldValidation::Pattern(_) => "pattern",
+            FieldValidation::Range { .. } => "range",
+        }
+    }
+}
+
+/// The regular expression of a `@pattern` validation. It is compiled once, when the schema is validated.
+#[derive(Debug, Clone)]
+pub struct Pattern(Regex);
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Pattern(Regex::new(pattern)?))
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for Pattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
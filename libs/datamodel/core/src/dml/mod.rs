@@ -4,6 +4,7 @@ mod datamodel;
 mod default_value;
 mod r#enum;
 mod field;
+mod field_validation;
 mod model;
 mod relation_info;
 mod traits;
@@ -11,6 +12,7 @@ mod traits;
 pub use self::datamodel::*;
 pub use default_value::*;
 pub use field::*;
+pub use field_validation::*;
 pub use model::*;
 pub use r#enum::*;
 pub use relation_info::*;
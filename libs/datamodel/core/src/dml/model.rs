@@ -22,6 +22,10 @@ pub struct Model {
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
     pub is_commented_out: bool,
+    /// Indicates if this model is ignored by the query engine (`@@ignore`).
+    pub is_ignored: bool,
+    /// The abstract models this model inherits fields from. The inherited fields are part of `fields`.
+    pub extends: Vec<String>,
 }
 
 /// Represents an index defined via `@@index` or `@@unique`.
@@ -72,6 +76,8 @@ impl Model {
             is_embedded: false,
             is_generated: false,
             is_commented_out: false,
+            is_ignored: false,
+            extends: vec![],
         }
     }
 
@@ -198,10 +204,13 @@ impl Model {
     /// returns the order of unique criterias ordered based on their precedence
     fn unique_criterias(&self, allow_optional: bool) -> Vec<UniqueCriteria> {
         let mut result = Vec::new();
+        // commented out fields and fields of unsupported types can not be used by the query engine
+        let is_unusable = |field: &ScalarField| field.is_commented_out || field.field_type.is_unsupported();
+
         // first candidate: the singular id field
         {
             if let Some(x) = self.singular_id_fields().next() {
-                if !x.is_commented_out {
+                if !is_unusable(x) {
                     result.push(UniqueCriteria::new(vec![x]))
                 }
             }
@@ -215,7 +224,7 @@ impl Model {
                 .map(|f| self.find_scalar_field(&f).unwrap())
                 .collect();
 
-            if !id_fields.is_empty() && !id_fields.iter().any(|f| f.is_commented_out) {
+            if !id_fields.is_empty() && !id_fields.iter().any(|f| is_unusable(f)) {
                 result.push(UniqueCriteria::new(id_fields));
             }
         }
@@ -224,7 +233,7 @@ impl Model {
         {
             let mut unique_required_fields: Vec<_> = self
                 .scalar_fields()
-                .filter(|field| field.is_unique && (field.is_required() || allow_optional) && !field.is_commented_out)
+                .filter(|field| field.is_unique && (field.is_required() || allow_optional) && !is_unusable(field))
                 .map(|f| UniqueCriteria::new(vec![f]))
                 .collect();
 
@@ -239,9 +248,9 @@ impl Model {
                 .filter(|id| id.tpe == IndexType::Unique)
                 .filter_map(|id| {
                     let fields: Vec<_> = id.fields.iter().map(|f| self.find_scalar_field(&f).unwrap()).collect();
-                    let no_fields_are_commented_out = !fields.iter().any(|f| f.is_commented_out);
+                    let no_fields_are_unusable = !fields.iter().any(|f| is_unusable(f));
                     let all_fields_are_required = fields.iter().all(|f| f.is_required());
-                    if (all_fields_are_required || allow_optional) && no_fields_are_commented_out {
+                    if (all_fields_are_required || allow_optional) && no_fields_are_unusable {
                         Some(UniqueCriteria::new(fields))
                     } else {
                         None
@@ -1,4 +1,5 @@
 use super::DatamodelError;
+use crate::ast::SchemaFiles;
 
 /// Represents a list of validation or parser errors.
 ///
@@ -64,6 +65,19 @@ impl ErrorCollection {
 
         String::from_utf8_lossy(&message).into_owned()
     }
+
+    /// Like `to_pretty_string`, for schemas split across multiple files. Each error is printed
+    /// against the file it points to.
+    pub fn to_pretty_string_in_files(&self, files: &SchemaFiles) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in self.to_iter() {
+            err.pretty_print_in_files(&mut message, files)
+                .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
 }
 
 impl std::fmt::Display for ErrorCollection {
@@ -1,7 +1,8 @@
 mod collection;
 pub use collection::*;
 
-use crate::ast::Span;
+use crate::ast::{SchemaFiles, Span};
+use crate::quick_fix::QuickFix;
 use colored::Colorize;
 use thiserror::Error;
 
@@ -107,6 +108,12 @@ pub enum DatamodelError {
     #[error("Error validating model \"{}\": {}", model_name, message)]
     ModelValidationError { message: String, model_name: String, span: Span  },
 
+    #[error("Error validating model \"{}\": Automatic related field generation would cause a naming conflict. Please add an explicit opposite relation field.", model_name)]
+    MissingOppositeRelationFieldError { model_name: String, field_name: String, span: Span },
+
+    #[error("Error parsing attribute \"@relation\": The relation field `{}` on Model `{}` must specify the `fields` argument in the @relation directive. You can run `prisma format` to fix this automatically.", field_name, model_name)]
+    MissingRelationFieldsArgumentError { model_name: String, field_name: String, span: Span },
+
     #[error("Error validating field `{}` in model `{}`: {}", field, model, message)]
     FieldValidationError { message: String, model: String, field: String, span: Span },
 
@@ -267,6 +274,22 @@ impl DatamodelError {
         }
     }
 
+    pub fn new_missing_opposite_relation_field_error(model_name: &str, field_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::MissingOppositeRelationFieldError {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            span,
+        }
+    }
+
+    pub fn new_missing_relation_fields_argument_error(model_name: &str, field_name: &str, span: Span) -> DatamodelError {
+        DatamodelError::MissingRelationFieldsArgumentError {
+            model_name: String::from(model_name),
+            field_name: String::from(field_name),
+            span,
+        }
+    }
+
     pub fn new_enum_validation_error(message: &str, enum_name: &str, span: Span) -> DatamodelError {
         DatamodelError::EnumValidationError {
             message: String::from(message),
@@ -380,6 +403,8 @@ impl DatamodelError {
             DatamodelError::ValidationError { span, .. } => *span,
             DatamodelError::LegacyParserError { span, .. } => *span,
             DatamodelError::ModelValidationError { span, .. } => *span,
+            DatamodelError::MissingOppositeRelationFieldError { span, .. } => *span,
+            DatamodelError::MissingRelationFieldsArgumentError { span, .. } => *span,
             DatamodelError::DuplicateDirectiveError { span, .. } => *span,
             DatamodelError::DuplicateConfigKeyError { span, .. } => *span,
             DatamodelError::DuplicateTopError { span, .. } => *span,
@@ -396,6 +421,61 @@ impl DatamodelError {
             DatamodelError::ConnectorError { span, .. } => *span,
         }
     }
+
+    /// The same error, pointing to a file of a schema split across multiple files.
+    pub fn in_file(mut self, file_id: usize) -> DatamodelError {
+        self.span_mut().file_id = file_id;
+        self
+    }
+
+    fn span_mut(&mut self) -> &mut Span {
+        match self {
+            DatamodelError::ArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveArgumentNotFound { span, .. } => span,
+            DatamodelError::ArgumentCountMissmatch { span, .. } => span,
+            DatamodelError::SourceArgumentNotFound { span, .. } => span,
+            DatamodelError::GeneratorArgumentNotFound { span, .. } => span,
+            DatamodelError::DirectiveValidationError { span, .. } => span,
+            DatamodelError::DirectiveNotKnownError { span, .. } => span,
+            DatamodelError::ReservedScalarTypeError { span, .. } => span,
+            DatamodelError::FunctionNotKnownError { span, .. } => span,
+            DatamodelError::DatasourceProviderNotKnownError { span, .. } => span,
+            DatamodelError::LiteralParseError { span, .. } => span,
+            DatamodelError::TypeNotFoundError { span, .. } => span,
+            DatamodelError::ScalarTypeNotFoundError { span, .. } => span,
+            DatamodelError::ParserError { span, .. } => span,
+            DatamodelError::FunctionalEvaluationError { span, .. } => span,
+            DatamodelError::EnvironmentFunctionalEvaluationError { span, .. } => span,
+            DatamodelError::TypeMismatchError { span, .. } => span,
+            DatamodelError::ValueParserError { span, .. } => span,
+            DatamodelError::ValidationError { span, .. } => span,
+            DatamodelError::LegacyParserError { span, .. } => span,
+            DatamodelError::ModelValidationError { span, .. } => span,
+            DatamodelError::MissingOppositeRelationFieldError { span, .. } => span,
+            DatamodelError::MissingRelationFieldsArgumentError { span, .. } => span,
+            DatamodelError::DuplicateDirectiveError { span, .. } => span,
+            DatamodelError::DuplicateConfigKeyError { span, .. } => span,
+            DatamodelError::DuplicateTopError { span, .. } => span,
+            DatamodelError::DuplicateFieldError { span, .. } => span,
+            DatamodelError::DuplicateEnumValueError { span, .. } => span,
+            DatamodelError::DuplicateArgumentError { span, .. } => span,
+            DatamodelError::DuplicateDefaultArgumentError { span, .. } => span,
+            DatamodelError::UnusedArgumentError { span, .. } => span,
+            DatamodelError::ScalarListFieldsAreNotSupported { span, .. } => span,
+            DatamodelError::MultipleIndexesWithSameNameAreNotSupported { span, .. } => span,
+            DatamodelError::FieldValidationError { span, .. } => span,
+            DatamodelError::SourceValidationError { span, .. } => span,
+            DatamodelError::EnumValidationError { span, .. } => span,
+            DatamodelError::ConnectorError { span, .. } => span,
+        }
+    }
+
+    /// Edits that resolve the error, derived from the schema it was reported for. See
+    /// [QuickFix](../quick_fix/struct.QuickFix.html).
+    pub fn quick_fixes(&self, schema: &str) -> Vec<QuickFix> {
+        crate::quick_fix::quick_fixes(self, schema)
+    }
+
     pub fn description(&self) -> String {
         format!("{}", self)
     }
@@ -403,6 +483,15 @@ impl DatamodelError {
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
         pretty_print_error(f, file_name, text, self)
     }
+
+    /// Pretty prints the error against the file of a multi-file schema its span points to.
+    pub fn pretty_print_in_files(&self, f: &mut dyn std::io::Write, files: &SchemaFiles) -> std::io::Result<()> {
+        let file_id = self.span().file_id;
+        let file_name = files.name(file_id).unwrap_or("");
+        let text = files.contents(file_id).unwrap_or("");
+
+        pretty_print_error(f, file_name, text, self)
+    }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
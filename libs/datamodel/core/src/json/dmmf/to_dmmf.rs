@@ -23,13 +23,33 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.enums.push(enum_to_dmmf(&enum_model));
     }
 
-    for model in schema.models() {
-        datamodel.models.push(model_to_dmmf(&model));
+    // Ignored models and fields are not exposed by the query engine.
+    for model in schema.models().filter(|model| !model.is_ignored) {
+        datamodel.models.push(model_to_dmmf(schema, &model));
     }
 
     datamodel
 }
 
+fn has_unsupported_fields(model: &dml::Model, fields: &[String]) -> bool {
+    fields.iter().any(|name| {
+        model
+            .find_scalar_field(name)
+            .map(|field| field.field_type.is_unsupported())
+            .unwrap_or(false)
+    })
+}
+
+fn points_to_ignored_model(schema: &dml::Datamodel, field: &dml::Field) -> bool {
+    match field {
+        dml::Field::RelationField(rf) => schema
+            .find_model(&rf.relation_info.to)
+            .map(|related_model| related_model.is_ignored)
+            .unwrap_or(false),
+        dml::Field::ScalarField(_) => false,
+    }
+}
+
 fn enum_to_dmmf(en: &dml::Enum) -> Enum {
     let mut enm = Enum {
         name: en.name.clone(),
@@ -52,12 +72,16 @@ fn enum_value_to_dmmf(en: &dml::EnumValue) -> EnumValue {
     }
 }
 
-fn model_to_dmmf(model: &dml::Model) -> Model {
+fn model_to_dmmf(schema: &dml::Datamodel, model: &dml::Model) -> Model {
     Model {
         name: model.name.clone(),
         db_name: model.database_name.clone(),
         is_embedded: model.is_embedded,
-        fields: model.fields().map(|f| field_to_dmmf(model, f)).collect(),
+        fields: model
+            .fields()
+            .filter(|f| !f.is_ignored() && !f.field_type().is_unsupported() && !points_to_ignored_model(schema, f))
+            .map(|f| field_to_dmmf(model, f))
+            .collect(),
         is_generated: Some(model.is_generated),
         documentation: model.documentation.clone(),
         id_fields: model.id_fields.clone(),
@@ -65,7 +89,7 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
             .indices
             .iter()
             .filter_map(|i| {
-                if i.tpe == IndexType::Unique {
+                if i.tpe == IndexType::Unique && !has_unsupported_fields(model, &i.fields) {
                     Some(i.fields.clone())
                 } else {
                     None
@@ -76,7 +100,7 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
             .indices
             .iter()
             .filter_map(|i| {
-                if i.tpe == IndexType::Unique {
+                if i.tpe == IndexType::Unique && !has_unsupported_fields(model, &i.fields) {
                     Some(UniqueIndex {
                         name: i.name.clone(),
                         fields: i.fields.clone(),
@@ -120,6 +144,7 @@ fn get_field_kind(field: &dml::Field) -> String {
         dml::FieldType::Relation(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
         dml::FieldType::Base(_, _) => String::from("scalar"),
+        dml::FieldType::CompositeType(_) => String::from("object"),
         tpe => unimplemented!("DMMF does not support field type {:?}", tpe),
     }
 }
@@ -163,6 +188,7 @@ fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type() {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
         dml::FieldType::Enum(t) => t.clone(),
+        dml::FieldType::CompositeType(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
         dml::FieldType::NativeType(t, _) => type_to_string(t),
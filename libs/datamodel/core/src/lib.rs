@@ -16,6 +16,8 @@
 //! * `configuration`: contains the models representing the Datasources and Generators of a Prisma schema
 //! * `transform`: contains the logic to turn an AST into models and vice versa
 //! * `json`: contains the logic to turn models into their JSON/DMMF representation
+//! * `lint`: contains the lint rules reporting naming conventions and risky patterns in a valid schema
+//! * `quick_fix`: contains the edits that resolve common validation errors
 //!
 //! The flow between the layers is depicted in the following diagram.
 //!<pre>
@@ -82,6 +84,8 @@ pub mod configuration;
 pub mod dml;
 pub mod error;
 pub mod json;
+pub mod lint;
+pub mod quick_fix;
 pub mod transform;
 pub mod walkers;
 
@@ -135,6 +139,16 @@ fn parse_datamodel_internal(
     validator.validate(&ast)
 }
 
+/// Parses and validates a schema split across multiple files, using core attributes only.
+/// Error spans carry the id of the file they point to, see `ErrorCollection::to_pretty_string_in_files`.
+pub fn parse_datamodel_from_files(files: &ast::SchemaFiles) -> Result<Datamodel, error::ErrorCollection> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    let sources = load_sources(&ast, false, vec![])?;
+    let validator = ValidationPipeline::new(&sources);
+
+    validator.validate(&ast)
+}
+
 /// Validates a [Schema AST](/ast/struct.SchemaAst.html) and returns its
 /// [Datamodel](/struct.Datamodel.html).
 pub fn lift_ast_to_datamodel(ast: &ast::SchemaAst) -> Result<Datamodel, error::ErrorCollection> {
@@ -156,6 +170,26 @@ pub fn parse_schema_ast(datamodel_string: &str) -> Result<SchemaAst, error::Erro
     ast::parser::parse_schema(datamodel_string)
 }
 
+pub fn parse_schema_ast_from_files(files: &ast::SchemaFiles) -> Result<SchemaAst, error::ErrorCollection> {
+    ast::parser::parse_schema_files(files)
+}
+
+/// Parses and validates a datamodel string, and checks it with all builtin lint rules. The severities of
+/// the rules are configured by the `lintRules` of the generators.
+pub fn lint_datamodel(datamodel_string: &str) -> Result<Vec<lint::LintDiagnostic>, error::ErrorCollection> {
+    let ast = ast::parser::parse_schema(datamodel_string)?;
+    let sources = load_sources(&ast, true, vec![])?;
+    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+    let datamodel = ValidationPipeline::new(&sources).validate(&ast)?;
+
+    let schema = lint::LintedSchema {
+        ast: &ast,
+        datamodel: &datamodel,
+    };
+
+    Ok(lint::Linter::new().lint(&schema, &lint::LintConfig::from_generators(&generators)))
+}
+
 /// Loads all configuration blocks from a datamodel using the built-in source definitions.
 pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, error::ErrorCollection> {
     let ast = ast::parser::parse_schema(datamodel_string)?;
@@ -168,6 +202,18 @@ pub fn parse_configuration(datamodel_string: &str) -> Result<Configuration, erro
     })
 }
 
+/// Loads all configuration blocks from a schema split across multiple files.
+pub fn parse_configuration_from_files(files: &ast::SchemaFiles) -> Result<Configuration, error::ErrorCollection> {
+    let ast = ast::parser::parse_schema_files(files)?;
+    let datasources = load_sources(&ast, false, vec![])?;
+    let generators = GeneratorLoader::load_generators_from_ast(&ast)?;
+
+    Ok(Configuration {
+        datasources,
+        generators,
+    })
+}
+
 /// - `datasource_url_overrides`: the tuples consist of datasource name and url
 pub fn parse_configuration_with_url_overrides(
     schema: &str,
@@ -0,0 +1,202 @@
+//! Lint rules report naming conventions and risky patterns in a valid schema.
+//!
+//! Unlike validation errors, lint diagnostics never prevent a schema from being used. Every rule
+//! has a default severity, which can be changed per rule with the `lintRules` property of a generator:
+//!
+//! ```ignore
+//! generator client {
+//!   provider  = "prisma-client-js"
+//!   lintRules = ["fieldNameCamelCase:off", "floatForMoney:error"]
+//! }
+//! ```
+mod naming;
+mod relations;
+mod types;
+
+use crate::{ast, configuration::Generator, dml};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// How a violation of a lint rule is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Severity {
+    Off,
+    Warning,
+    Error,
+}
+
+impl std::str::FromStr for Severity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "off" => Ok(Severity::Off),
+            "warn" | "warning" => Ok(Severity::Warning),
+            "error" => Ok(Severity::Error),
+            _ => Err(format!(
+                "Unknown lint severity `{}`. Expected one of `off`, `warning` or `error`.",
+                s
+            )),
+        }
+    }
+}
+
+/// A violation of a lint rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintDiagnostic {
+    /// The name of the violated rule.
+    pub rule: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    /// The location of the violation in the schema.
+    pub span: ast::Span,
+}
+
+/// A lint rule. Rules get the validated datamodel, and the AST it was lifted from for locations.
+pub trait LintRule {
+    /// The name used to configure the rule, in camelCase.
+    fn name(&self) -> &'static str;
+
+    /// The severity of violations, unless configured otherwise.
+    fn default_severity(&self) -> Severity;
+
+    /// Returns the message and location of each violation.
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)>;
+}
+
+/// The schema a lint rule checks.
+pub struct LintedSchema<'a> {
+    pub ast: &'a ast::SchemaAst,
+    pub datamodel: &'a dml::Datamodel,
+}
+
+impl<'a> LintedSchema<'a> {
+    /// The location of the name of a model.
+    pub fn model_span(&self, model: &dml::Model) -> ast::Span {
+        self.ast
+            .find_model(&model.name)
+            .map(|ast_model| ast_model.name.span)
+            .unwrap_or_else(ast::Span::empty)
+    }
+
+    /// The location of a field. Inherited fields are located in the abstract model defining them.
+    pub fn field_span(&self, model: &dml::Model, field_name: &str) -> ast::Span {
+        std::iter::once(&model.name)
+            .chain(model.extends.iter())
+            .filter_map(|name| self.ast.find_model(name))
+            .flat_map(|ast_model| ast_model.fields.iter())
+            .find(|field| field.name.name == field_name)
+            .map(|field| field.span)
+            .unwrap_or_else(|| self.model_span(model))
+    }
+}
+
+/// The severities configured for lint rules.
+#[derive(Debug, Default)]
+pub struct LintConfig {
+    severities: HashMap<String, Severity>,
+}
+
+impl LintConfig {
+    /// Collects the `lintRules` of all generators. Later generators override earlier ones.
+    pub fn from_generators(generators: &[Generator]) -> LintConfig {
+        let severities = generators
+            .iter()
+            .flat_map(|generator| generator.lint_rules.iter())
+            .filter_map(|entry| Self::parse_entry(entry).ok())
+            .map(|(rule, severity)| (rule.to_owned(), severity))
+            .collect();
+
+        LintConfig { severities }
+    }
+
+    /// Parses a `rule:severity` entry of the `lintRules` generator property.
+    pub fn parse_entry(entry: &str) -> Result<(&str, Severity), String> {
+        let mut parts = entry.splitn(2, ':');
+
+        match (parts.next(), parts.next()) {
+            (Some(rule), Some(severity)) if !rule.trim().is_empty() => Ok((rule.trim(), severity.trim().parse()?)),
+            _ => Err(format!(
+                "Invalid lint rule configuration `{}`. Expected the format `rule:severity`.",
+                entry
+            )),
+        }
+    }
+
+    /// Configures the severity of a rule.
+    pub fn set_severity(&mut self, rule: &str, severity: Severity) {
+        self.severities.insert(rule.to_owned(), severity);
+    }
+
+    fn severity(&self, rule: &dyn LintRule) -> Severity {
+        self.severities
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.default_severity())
+    }
+}
+
+/// The registry of lint rules.
+pub struct Linter {
+    rules: Vec<Box<dyn LintRule>>,
+}
+
+impl Linter {
+    /// Creates a linter with all builtin rules registered.
+    pub fn new() -> Linter {
+        let mut linter = Linter { rules: Vec::new() };
+
+        linter.add(Box::new(naming::ModelNamePascalCase {}));
+        linter.add(Box::new(naming::FieldNameCamelCase {}));
+        linter.add(Box::new(relations::RelationScalarIndex {}));
+        linter.add(Box::new(relations::OptionalRelationMap {}));
+        linter.add(Box::new(types::FloatForMoney {}));
+
+        linter
+    }
+
+    /// Registers a rule. Panics if a rule with the same name is registered already.
+    pub fn add(&mut self, rule: Box<dyn LintRule>) {
+        if self.rules.iter().any(|existing| existing.name() == rule.name()) {
+            panic!("Duplicate lint rule definition: {}", rule.name());
+        }
+
+        self.rules.push(rule);
+    }
+
+    /// The names of all registered rules.
+    pub fn rule_names(&self) -> Vec<&'static str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Runs all rules that are not turned off, ordered by their location in the schema.
+    pub fn lint(&self, schema: &LintedSchema, config: &LintConfig) -> Vec<LintDiagnostic> {
+        let mut diagnostics: Vec<LintDiagnostic> = self
+            .rules
+            .iter()
+            .map(|rule| (rule, config.severity(rule.as_ref())))
+            .filter(|(_, severity)| *severity != Severity::Off)
+            .flat_map(|(rule, severity)| {
+                rule.check(schema)
+                    .into_iter()
+                    .map(move |(message, span)| LintDiagnostic {
+                        rule: rule.name(),
+                        severity,
+                        message,
+                        span,
+                    })
+            })
+            .collect();
+
+        diagnostics.sort_by_key(|diagnostic| (diagnostic.span.file_id, diagnostic.span.start));
+
+        diagnostics
+    }
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,94 @@
+use super::{LintRule, LintedSchema, Severity};
+use crate::ast;
+
+/// Model names should be in PascalCase, e.g. `UserProfile`.
+pub struct ModelNamePascalCase {}
+
+/// Field names should be in camelCase, e.g. `createdAt`.
+pub struct FieldNameCamelCase {}
+
+impl LintRule for ModelNamePascalCase {
+    fn name(&self) -> &'static str {
+        "modelNamePascalCase"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)> {
+        schema
+            .ast
+            .models()
+            .into_iter()
+            .filter(|model| !is_pascal_case(&model.name.name))
+            .map(|model| {
+                (
+                    format!(
+                        "The model name `{}` is not in PascalCase. Consider renaming it to `{}` and using `@@map` to keep the table name.",
+                        model.name.name,
+                        to_camel_case(&model.name.name, true)
+                    ),
+                    model.name.span,
+                )
+            })
+            .collect()
+    }
+}
+
+impl LintRule for FieldNameCamelCase {
+    fn name(&self) -> &'static str {
+        "fieldNameCamelCase"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)> {
+        schema
+            .ast
+            .models()
+            .into_iter()
+            .flat_map(|model| model.fields.iter())
+            .filter(|field| !is_camel_case(&field.name.name))
+            .map(|field| {
+                (
+                    format!(
+                        "The field name `{}` is not in camelCase. Consider renaming it to `{}` and using `@map` to keep the column name.",
+                        field.name.name,
+                        to_camel_case(&field.name.name, false)
+                    ),
+                    field.name.span,
+                )
+            })
+            .collect()
+    }
+}
+
+fn is_pascal_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_uppercase()) && !name.contains(|c| c == '_' || c == '-')
+}
+
+fn is_camel_case(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase()) && !name.contains(|c| c == '_' || c == '-')
+}
+
+/// Joins the words of a snake_case or kebab-case name, capitalizing all but the first word.
+fn to_camel_case(name: &str, capitalize_first: bool) -> String {
+    name.split(|c| c == '_' || c == '-')
+        .filter(|word| !word.is_empty())
+        .enumerate()
+        .map(|(idx, word)| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap();
+            let first: String = if idx > 0 || capitalize_first {
+                first.to_uppercase().collect()
+            } else {
+                first.to_lowercase().collect()
+            };
+
+            first + chars.as_str()
+        })
+        .collect()
+}
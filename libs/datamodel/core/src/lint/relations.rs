@@ -0,0 +1,99 @@
+use super::{LintRule, LintedSchema, Severity};
+use crate::{ast, dml};
+
+/// The scalar fields of a relation should be indexed, as they are used in every join.
+pub struct RelationScalarIndex {}
+
+/// The foreign key fields of optional relations should name their column with `@map`.
+pub struct OptionalRelationMap {}
+
+impl LintRule for RelationScalarIndex {
+    fn name(&self) -> &'static str {
+        "relationScalarIndex"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)> {
+        let mut violations = Vec::new();
+
+        for model in schema.datamodel.models().filter(|model| !model.is_ignored) {
+            for field in model
+                .relation_fields()
+                .filter(|field| !field.relation_info.fields.is_empty())
+            {
+                let fields = &field.relation_info.fields;
+
+                if !is_indexed(model, fields) {
+                    violations.push((
+                        format!(
+                            "The relation scalar fields of `{}` are not indexed. Add `@@index([{}])` to the model `{}` to avoid full table scans.",
+                            field.name,
+                            fields.join(", "),
+                            model.name
+                        ),
+                        schema.field_span(model, &field.name),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl LintRule for OptionalRelationMap {
+    fn name(&self) -> &'static str {
+        "optionalRelationMap"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Off
+    }
+
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)> {
+        let mut violations = Vec::new();
+
+        for model in schema.datamodel.models().filter(|model| !model.is_ignored) {
+            for field in model.relation_fields().filter(|field| field.is_optional()) {
+                let unmapped_fields = field
+                    .relation_info
+                    .fields
+                    .iter()
+                    .filter_map(|name| model.find_scalar_field(name))
+                    .filter(|sf| sf.database_name.is_none());
+
+                for sf in unmapped_fields {
+                    violations.push((
+                        format!(
+                            "The foreign key field `{}` of the optional relation `{}` does not name its column with `@map`.",
+                            sf.name, field.name
+                        ),
+                        schema.field_span(model, &sf.name),
+                    ));
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+/// A primary key, unique constraint or index starting with the given fields covers them.
+fn is_indexed(model: &dml::Model, fields: &[String]) -> bool {
+    let starts_with = |index_fields: &[String]| index_fields.starts_with(fields);
+
+    let single_field_constraint = match fields {
+        [field] => model
+            .find_scalar_field(field)
+            .map(|sf| sf.is_id || sf.is_unique)
+            .unwrap_or(false),
+        _ => false,
+    };
+
+    single_field_constraint
+        || starts_with(&model.id_fields[..])
+        || model.indices.iter().any(|index| starts_with(&index.fields[..]))
+}
@@ -0,0 +1,89 @@
+use super::{LintRule, LintedSchema, Severity};
+use crate::{ast, dml};
+
+/// Words in field names that hint at monetary values.
+const MONEY_WORDS: &[&str] = &["price", "amount", "cost", "money", "balance", "salary", "fee"];
+
+/// Monetary values should not be stored as floating point numbers, which can not represent them exactly.
+pub struct FloatForMoney {}
+
+impl LintRule for FloatForMoney {
+    fn name(&self) -> &'static str {
+        "floatForMoney"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Warning
+    }
+
+    fn check(&self, schema: &LintedSchema) -> Vec<(String, ast::Span)> {
+        let mut violations = Vec::new();
+
+        for model in schema.datamodel.models().filter(|model| !model.is_ignored) {
+            let money_fields = model
+                .scalar_fields()
+                .filter(|sf| sf.field_type.scalar_type() == Some(dml::ScalarType::Float) && is_money_name(&sf.name));
+
+            for sf in money_fields {
+                violations.push((
+                    format!(
+                        "The field `{}` looks like it stores money, but Float can not represent amounts exactly. Use Decimal instead.",
+                        sf.name
+                    ),
+                    schema.field_span(model, &sf.name),
+                ));
+            }
+        }
+
+        violations
+    }
+}
+
+/// Whether one of the words of a camelCase, snake_case or kebab-case name, singular or plural, is a
+/// money word. `unitPrice` and `total_fees` are, `coffee` and `costume` are not.
+fn is_money_name(name: &str) -> bool {
+    name_words(name).iter().any(|word| {
+        MONEY_WORDS
+            .iter()
+            .any(|money_word| word == money_word || word.strip_suffix('s') == Some(*money_word))
+    })
+}
+
+/// The lowercased words of a name. Words are separated by `_`, `-` and the start of a capitalized
+/// word, so `USDPrice` is `usd` and `price`.
+fn name_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for (idx, c) in chars.iter().enumerate() {
+        if *c == '_' || *c == '-' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+
+            continue;
+        }
+
+        let previous = if idx > 0 { chars.get(idx - 1) } else { None };
+        let next = chars.get(idx + 1);
+        let starts_word = c.is_uppercase()
+            && match previous {
+                Some(previous) if previous.is_uppercase() => next.map(|next| next.is_lowercase()).unwrap_or(false),
+                Some(previous) => previous.is_alphanumeric(),
+                None => false,
+            };
+
+        if starts_word && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        word.extend(c.to_lowercase());
+    }
+
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+}
@@ -0,0 +1,56 @@
+use super::{QuickFix, TextEdit};
+use crate::ast;
+
+/// Quotes default values of String fields, and removes the quotes around numbers, booleans and
+/// enum values given as strings.
+pub(super) fn default_value_type(ast: &ast::SchemaAst, span: ast::Span) -> Option<QuickFix> {
+    let (field, value) = ast.models().into_iter().find_map(|model| {
+        model.fields.iter().find_map(|field| {
+            field
+                .directives
+                .iter()
+                .filter(|directive| directive.name.name == "default")
+                .filter_map(|directive| directive.arguments.first())
+                .map(|argument| &argument.value)
+                .find(|value| value.span() == span)
+                .map(|value| (field, value))
+        })
+    })?;
+
+    let field_type = field.field_type.name.as_str();
+
+    let new_text = match value {
+        ast::Expression::NumericValue(raw, _)
+        | ast::Expression::BooleanValue(raw, _)
+        | ast::Expression::ConstantValue(raw, _)
+            if field_type == "String" =>
+        {
+            format!("\"{}\"", raw)
+        }
+        ast::Expression::StringValue(raw, _) if is_unquoted_value_of(raw, field_type, ast) => raw.clone(),
+        _ => return None,
+    };
+
+    let title = match value {
+        ast::Expression::StringValue(_, _) => "Remove the quotes around the default value",
+        _ => "Quote the default value",
+    };
+
+    Some(QuickFix {
+        title: title.to_owned(),
+        edits: vec![TextEdit::replace(span, new_text)],
+    })
+}
+
+/// Whether the content of a string literal is a valid default value for the type without its quotes.
+fn is_unquoted_value_of(raw: &str, field_type: &str, ast: &ast::SchemaAst) -> bool {
+    match field_type {
+        "Int" | "BigInt" => raw.parse::<i64>().is_ok(),
+        "Float" | "Decimal" => raw.parse::<f64>().map(f64::is_finite).unwrap_or(false),
+        "Boolean" => raw == "true" || raw == "false",
+        _ => ast
+            .find_enum(field_type)
+            .map(|enm| enm.values.iter().any(|value| value.name.name == raw))
+            .unwrap_or(false),
+    }
+}
@@ -0,0 +1,63 @@
+use super::{QuickFix, TextEdit};
+use crate::{ast, transform::directives::AllDirectives};
+
+/// Names further away from every known attribute are not considered typos.
+const MAX_TYPO_DISTANCE: usize = 2;
+
+/// Replaces an unknown attribute with the closest known attribute for the same kind of block.
+pub(super) fn misspelled_directive(ast: &ast::SchemaAst, name: &str, span: ast::Span) -> Option<QuickFix> {
+    let all_directives = AllDirectives::new();
+    let is_at = |directives: &[ast::Directive]| directives.iter().any(|directive| directive.name.span == span);
+
+    let models = ast.models();
+    let enums = ast.enums();
+
+    let (prefix, known_names): (&str, Vec<&str>) = if models.iter().any(|m| is_at(&m.directives)) {
+        ("@@", all_directives.model.directive_names().collect())
+    } else if models
+        .iter()
+        .flat_map(|m| m.fields.iter())
+        .any(|f| is_at(&f.directives))
+    {
+        ("@", all_directives.field.directive_names().collect())
+    } else if enums.iter().any(|e| is_at(&e.directives)) {
+        ("@@", all_directives.enm.directive_names().collect())
+    } else if enums.iter().flat_map(|e| e.values.iter()).any(|v| is_at(&v.directives)) {
+        ("@", all_directives.enm_value.directive_names().collect())
+    } else {
+        return None;
+    };
+
+    let (distance, closest) = known_names
+        .into_iter()
+        .map(|known| (edit_distance(&name.to_lowercase(), &known.to_lowercase()), known))
+        .min_by_key(|(distance, _)| *distance)?;
+
+    if distance > MAX_TYPO_DISTANCE || distance >= name.len() {
+        return None;
+    }
+
+    Some(QuickFix {
+        title: format!("Replace with `{}{}`", prefix, closest),
+        edits: vec![TextEdit::replace(span, closest.to_owned())],
+    })
+}
+
+/// The Levenshtein distance, counting inserted, removed and replaced characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let replaced = previous_row[j] + if a_char == *b_char { 0 } else { 1 };
+            row.push(replaced.min(previous_row[j + 1] + 1).min(row[j] + 1));
+        }
+
+        previous_row = row;
+    }
+
+    previous_row[b.len()]
+}
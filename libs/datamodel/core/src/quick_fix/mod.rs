@@ -0,0 +1,67 @@
+//! Quick fixes are edits that resolve a validation error without further input from the user, like
+//! correcting a misspelled attribute name. Editors offer them as code actions, see `prisma-fmt lsp`.
+//!
+//! Fixes are derived from the error and the AST of the schema it was reported for, so the errors
+//! themselves stay plain data.
+mod defaults;
+mod directives;
+mod relations;
+
+use crate::{ast, error::DatamodelError};
+
+/// A fix for an error, as edits to the schema the error was reported for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickFix {
+    /// Describes the fix for the user, e.g. in the list of code actions of an editor.
+    pub title: String,
+    pub edits: Vec<TextEdit>,
+}
+
+/// Replaces the text of a span. Insertions use an empty span.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextEdit {
+    pub span: ast::Span,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    fn replace(span: ast::Span, new_text: String) -> TextEdit {
+        TextEdit { span, new_text }
+    }
+
+    fn insert(offset: usize, new_text: String) -> TextEdit {
+        TextEdit {
+            span: ast::Span::new(offset, offset),
+            new_text,
+        }
+    }
+}
+
+/// The quick fixes for an error reported for the given schema. Schemas that can not be parsed have
+/// none, as well as most kinds of errors.
+pub fn quick_fixes(error: &DatamodelError, schema: &str) -> Vec<QuickFix> {
+    let ast = match ast::parser::parse_schema(schema) {
+        Ok(ast) => ast,
+        Err(_) => return Vec::new(),
+    };
+
+    let fix = match error {
+        DatamodelError::DirectiveNotKnownError { directive_name, span } => {
+            directives::misspelled_directive(&ast, directive_name, *span)
+        }
+        DatamodelError::DirectiveValidationError {
+            directive_name, span, ..
+        } if directive_name == "default" => defaults::default_value_type(&ast, *span),
+        // Some values of `@default` are checked without wrapping the error.
+        DatamodelError::TypeMismatchError { span, .. } => defaults::default_value_type(&ast, *span),
+        DatamodelError::MissingOppositeRelationFieldError { model_name, span, .. } => {
+            relations::missing_opposite_field(&ast, schema, model_name, *span)
+        }
+        DatamodelError::MissingRelationFieldsArgumentError { model_name, span, .. } => {
+            relations::missing_fields_argument(&ast, schema, model_name, *span)
+        }
+        _ => None,
+    };
+
+    fix.into_iter().collect()
+}
@@ -0,0 +1,204 @@
+use super::{QuickFix, TextEdit};
+use crate::{ast, common::NameNormalizer};
+
+/// The indentation of fields added to a model without fields.
+const DEFAULT_INDENTATION: &str = "  ";
+
+/// Adds an explicit opposite relation field for a relation field that has none. For a list field,
+/// the singular opposite field also gets its scalar field, with `@relation(fields, references)`.
+pub(super) fn missing_opposite_field(
+    ast: &ast::SchemaAst,
+    schema: &str,
+    model_name: &str,
+    span: ast::Span,
+) -> Option<QuickFix> {
+    let model = ast.find_model(model_name)?;
+    let field = model.fields.iter().find(|field| field.span == span)?;
+    let related_model = ast
+        .find_model(&field.field_type.name)
+        .filter(|related| related.name.name != model.name.name)?;
+    let name = relation_name(field);
+
+    let has_opposite_field = related_model
+        .fields
+        .iter()
+        .any(|f| f.field_type.name == model.name.name && relation_name(f) == name);
+
+    if has_opposite_field {
+        return None;
+    }
+
+    let is_taken = |field_name: &str| related_model.fields.iter().any(|f| f.name.name == field_name);
+    let base_name = match field.arity {
+        ast::FieldArity::List => model.name.name.camel_case(),
+        _ => format!("{}s", model.name.name.camel_case()),
+    };
+    let opposite_name = vec![
+        base_name.clone(),
+        format!("{}{}", base_name, field.name.name.pascal_case()),
+    ]
+    .into_iter()
+    .find(|candidate| !is_taken(candidate.as_str()))?;
+
+    let mut relation_args: Vec<String> = name.iter().map(|name| format!("\"{}\"", name)).collect();
+    let mut lines = Vec::new();
+
+    let field_type = match field.arity {
+        ast::FieldArity::List => {
+            if let Some(id_field) = single_id_field(model) {
+                let scalar_name = format!("{}{}", opposite_name, id_field.name.name.pascal_case());
+
+                if is_taken(scalar_name.as_str()) {
+                    return None;
+                }
+
+                relation_args.push(format!("fields: [{}]", scalar_name));
+                relation_args.push(format!("references: [{}]", id_field.name.name));
+                lines.push(format!("{} {}?", scalar_name, id_field.field_type.name));
+            }
+
+            format!("{}?", model.name.name)
+        }
+        _ => format!("{}[]", model.name.name),
+    };
+
+    let relation = if relation_args.is_empty() {
+        String::new()
+    } else {
+        format!(" @relation({})", relation_args.join(", "))
+    };
+
+    lines.insert(0, format!("{} {}{}", opposite_name, field_type, relation));
+
+    Some(QuickFix {
+        title: format!(
+            "Add the opposite relation field `{}` to the model `{}`",
+            opposite_name, related_model.name.name
+        ),
+        edits: vec![insert_fields(schema, related_model, &lines)],
+    })
+}
+
+/// Adds the `fields` argument to the `@relation` attribute of a relation field on the singular
+/// side of a one-to-many relation, with `references` to the id of the related model if that is
+/// missing too. The scalar field is added to the model unless a field with its name exists.
+pub(super) fn missing_fields_argument(
+    ast: &ast::SchemaAst,
+    schema: &str,
+    model_name: &str,
+    span: ast::Span,
+) -> Option<QuickFix> {
+    let model = ast.find_model(model_name)?;
+    let field = model.fields.iter().find(|field| field.span == span)?;
+    let related_model = ast.find_model(&field.field_type.name)?;
+    let relation = field
+        .directives
+        .iter()
+        .find(|directive| directive.name.name == "relation");
+    let references = relation.and_then(|relation| relation_argument(relation, "references"));
+
+    let referenced_field = match references {
+        Some(ast::Expression::Array(values, _)) => match values.as_slice() {
+            [ast::Expression::ConstantValue(name, _)] => related_model.fields.iter().find(|f| &f.name.name == name)?,
+            _ => return None,
+        },
+        Some(_) => return None,
+        None => single_id_field(related_model)?,
+    };
+
+    let scalar_name = format!("{}{}", field.name.name, referenced_field.name.name.pascal_case());
+
+    let mut new_arguments = vec![format!("fields: [{}]", scalar_name)];
+    if references.is_none() {
+        new_arguments.push(format!("references: [{}]", referenced_field.name.name));
+    }
+    let new_arguments = new_arguments.join(", ");
+
+    let mut edits = vec![match relation {
+        Some(relation) if schema[..relation.span.end].ends_with(')') => {
+            let separator = if relation.arguments.is_empty() { "" } else { ", " };
+            TextEdit::insert(relation.span.end - 1, format!("{}{}", separator, new_arguments))
+        }
+        Some(relation) => TextEdit::insert(relation.span.end, format!("({})", new_arguments)),
+        None => {
+            let end = field
+                .directives
+                .last()
+                .map(|directive| directive.span.end)
+                .unwrap_or(field.field_type.span.end);
+
+            TextEdit::insert(end, format!(" @relation({})", new_arguments))
+        }
+    }];
+
+    if model.fields.iter().all(|f| f.name.name != scalar_name) {
+        let arity = match field.arity {
+            ast::FieldArity::Optional => "?",
+            _ => "",
+        };
+        let line = format!("{} {}{}", scalar_name, referenced_field.field_type.name, arity);
+
+        edits.push(insert_fields(schema, model, &[line]));
+    }
+
+    Some(QuickFix {
+        title: format!(
+            "Add `fields: [{}]` to the relation field `{}`",
+            scalar_name, field.name.name
+        ),
+        edits,
+    })
+}
+
+/// The value of a named argument of a `@relation` attribute.
+fn relation_argument<'a>(relation: &'a ast::Directive, name: &str) -> Option<&'a ast::Expression> {
+    relation
+        .arguments
+        .iter()
+        .find(|argument| argument.name.name == name)
+        .map(|argument| &argument.value)
+}
+
+/// The name of the relation, from the `@relation` attribute of the field.
+fn relation_name(field: &ast::Field) -> Option<&str> {
+    field
+        .directives
+        .iter()
+        .filter(|directive| directive.name.name == "relation")
+        .flat_map(|directive| directive.arguments.iter())
+        .find(|argument| argument.name.name.is_empty() || argument.name.name == "name")
+        .and_then(|argument| match &argument.value {
+            ast::Expression::StringValue(name, _) => Some(name.as_str()),
+            _ => None,
+        })
+}
+
+fn single_id_field(model: &ast::Model) -> Option<&ast::Field> {
+    model
+        .fields
+        .iter()
+        .find(|field| field.directives.iter().any(|directive| directive.name.name == "id"))
+}
+
+/// Inserts the field declarations before the closing brace of the model, indented like its fields.
+fn insert_fields(schema: &str, model: &ast::Model, lines: &[String]) -> TextEdit {
+    let closing_brace = model.span.end - 1;
+    let line_start = |offset: usize| schema[..offset].rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+    let indentation = model
+        .fields
+        .first()
+        .map(|field| &schema[line_start(field.name.span.start)..field.name.span.start])
+        .filter(|prefix| prefix.trim().is_empty())
+        .unwrap_or(DEFAULT_INDENTATION);
+
+    let declarations: String = lines.iter().map(|line| format!("{}{}\n", indentation, line)).collect();
+
+    let closing_line_start = line_start(closing_brace);
+
+    if schema[closing_line_start..closing_brace].trim().is_empty() {
+        TextEdit::insert(closing_line_start, declarations)
+    } else {
+        TextEdit::insert(closing_brace, format!("\n{}", declarations))
+    }
+}
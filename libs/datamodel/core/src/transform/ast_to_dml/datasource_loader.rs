@@ -11,6 +11,7 @@ use crate::{ast, Datasource};
 use datamodel_connector::{CombinedConnector, Connector};
 
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const REPLICA_URLS_KEY: &str = "replicaUrls";
 
 /// Is responsible for loading and validating Datasources defined in an AST.
 pub struct DatasourceLoader {
@@ -96,37 +97,33 @@ impl DatasourceLoader {
             .find(|x| &x.0 == source_name)
             .map(|x| &x.1);
 
-        let (env_var_for_url, url) = match (url_args.as_str_from_env(), override_url) {
-            (_, _) if ignore_datasource_urls => {
+        let url = match override_url {
+            _ if ignore_datasource_urls => {
                 // glorious hack. ask marcus
-                (None, format!("{}://", providers.first().unwrap()))
+                StringFromEnvVar::new_literal(format!("{}://", providers.first().unwrap()))
             }
-            (_, Some(url)) => {
+            Some(url) => {
                 debug!("overwriting datasource `{}` with url '{}'", &source_name, &url);
-                (None, url.to_owned())
+                StringFromEnvVar::new_literal(url.to_owned())
             }
-            (Ok((env_var, url)), _) => (env_var, url.trim().to_owned()),
-            (Err(err), _) => return Err(err),
+            None => lift_url(&url_args)?,
         };
+        validate_nonempty_url(&url, source_name, url_args.span())?;
 
-        if url.is_empty() {
-            let suffix = match &env_var_for_url {
-                Some(env_var_name) => format!(
-                    " The environment variable `{}` resolved to an empty string.",
-                    env_var_name
-                ),
-                None => "".to_owned(),
-            };
-            let msg = format!(
-                "You must provide a nonempty URL for the datasource `{}`.{}",
-                source_name, &suffix
-            );
-            return Err(DatamodelError::new_source_validation_error(
-                &msg,
-                source_name,
-                url_args.span(),
-            ));
-        }
+        // Replicas are only read by the query engine, so they are neither overridden nor required.
+        let replica_url_args = match args.arg(REPLICA_URLS_KEY).ok() {
+            Some(_) if ignore_datasource_urls => Vec::new(),
+            Some(arg) => arg.as_array(),
+            None => Vec::new(),
+        };
+        let replica_urls = replica_url_args
+            .iter()
+            .map(|arg| {
+                let url = lift_url(arg)?;
+                validate_nonempty_url(&url, source_name, arg.span())?;
+                Ok(url)
+            })
+            .collect::<Result<Vec<_>, DatamodelError>>()?;
 
         let preview_features_arg = args.arg(PREVIEW_FEATURES_KEY);
         let preview_features = match preview_features_arg.ok() {
@@ -135,10 +132,6 @@ impl DatasourceLoader {
         };
 
         let documentation = ast_source.documentation.clone().map(|comment| comment.text);
-        let url = StringFromEnvVar {
-            from_env_var: env_var_for_url,
-            value: url,
-        };
 
         let all_datasource_providers: Vec<_> = providers
             .iter()
@@ -158,7 +151,12 @@ impl DatasourceLoader {
                 let url_check_result = provider.can_handle_url(source_name, &url).map_err(|err_msg| {
                     DatamodelError::new_source_validation_error(&err_msg, source_name, url_args.span())
                 });
-                url_check_result.map(|_| provider)
+                let replica_url_check_result = replica_urls.iter().zip(&replica_url_args).try_for_each(|(url, arg)| {
+                    provider.can_handle_url(source_name, url).map_err(|err_msg| {
+                        DatamodelError::new_source_validation_error(&err_msg, source_name, arg.span())
+                    })
+                });
+                url_check_result.and(replica_url_check_result).map(|_| provider)
             })
             .collect();
 
@@ -177,6 +175,7 @@ impl DatasourceLoader {
                 provider: providers,
                 active_provider: first_successful_provider.canonical_name().to_string(),
                 url,
+                replica_urls,
                 documentation: documentation.clone(),
                 combined_connector,
                 active_connector: first_successful_provider.connector(),
@@ -192,6 +191,79 @@ impl DatasourceLoader {
     }
 }
 
+/// Reads a datasource URL given as a string, an `env()` call or a string with `${VAR}` placeholders.
+fn lift_url(arg: &ValueValidator) -> Result<StringFromEnvVar, DatamodelError> {
+    let url = match arg.as_str_from_env()? {
+        (Some(env_var), url) => StringFromEnvVar {
+            from_env_var: Some(env_var),
+            value: url.trim().to_owned(),
+            fallback: arg
+                .as_env_function()
+                .ok()
+                .and_then(|env_function| env_function.fallback().map(String::from)),
+            template: None,
+        },
+        (None, url) if url.contains("${") => StringFromEnvVar {
+            from_env_var: None,
+            value: interpolate_env_vars(&url, arg.span())?.trim().to_owned(),
+            fallback: None,
+            template: Some(url),
+        },
+        (None, url) => StringFromEnvVar::new_literal(url.trim().to_owned()),
+    };
+
+    Ok(url)
+}
+
+fn validate_nonempty_url(url: &StringFromEnvVar, source_name: &str, span: ast::Span) -> Result<(), DatamodelError> {
+    if url.value.is_empty() {
+        let suffix = match &url.from_env_var {
+            Some(env_var_name) => format!(
+                " The environment variable `{}` resolved to an empty string.",
+                env_var_name
+            ),
+            None => "".to_owned(),
+        };
+        let msg = format!(
+            "You must provide a nonempty URL for the datasource `{}`.{}",
+            source_name, &suffix
+        );
+        return Err(DatamodelError::new_source_validation_error(&msg, source_name, span));
+    }
+
+    Ok(())
+}
+
+/// Replaces the `${VAR}` placeholders of a datasource URL with the values of the environment
+/// variables. The span is the span of the string literal.
+fn interpolate_env_vars(template: &str, span: ast::Span) -> Result<String, DatamodelError> {
+    // The value starts after the opening quote.
+    let parts = ast::parser::parse_interpolated_string(template, span.start + 1)?;
+    let mut url = String::new();
+
+    for part in parts {
+        match part {
+            ast::Expression::StringValue(text, _) => url.push_str(&text),
+            ast::Expression::ConstantValue(var_name, span) => match std::env::var(&var_name) {
+                Ok(value) => url.push_str(&value),
+                Err(_) => {
+                    return Err(DatamodelError::new_environment_functional_evaluation_error(
+                        &var_name, span,
+                    ))
+                }
+            },
+            other => {
+                return Err(DatamodelError::new_functional_evaluation_error(
+                    "Only environment variables can be interpolated into a datasource URL, e.g. `${DATABASE_HOST}`.",
+                    other.span(),
+                ))
+            }
+        }
+    }
+
+    Ok(url)
+}
+
 fn get_builtin_datasource_providers() -> Vec<Box<dyn DatasourceProvider>> {
     vec![
         Box::new(MySqlDatasourceProvider::new()),
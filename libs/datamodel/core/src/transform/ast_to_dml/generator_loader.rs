@@ -1,5 +1,10 @@
 use super::super::helpers::*;
-use crate::{ast, configuration::Generator, error::*};
+use crate::{
+    ast,
+    configuration::Generator,
+    error::*,
+    lint::{LintConfig, Linter},
+};
 use std::collections::HashMap;
 
 const PROVIDER_KEY: &str = "provider";
@@ -7,12 +12,14 @@ const OUTPUT_KEY: &str = "output";
 const BINARY_TARGETS_KEY: &str = "binaryTargets";
 const EXPERIMENTAL_FEATURES_KEY: &str = "experimentalFeatures";
 const PREVIEW_FEATURES_KEY: &str = "previewFeatures";
+const LINT_RULES_KEY: &str = "lintRules";
 const FIRST_CLASS_PROPERTIES: &[&str] = &[
     PROVIDER_KEY,
     OUTPUT_KEY,
     BINARY_TARGETS_KEY,
     EXPERIMENTAL_FEATURES_KEY,
     PREVIEW_FEATURES_KEY,
+    LINT_RULES_KEY,
 ];
 
 /// Is responsible for loading and validating Generators defined in an AST.
@@ -65,6 +72,37 @@ impl GeneratorLoader {
             None => Vec::new(),
         };
 
+        let mut lint_rules = Vec::new();
+
+        if let Ok(arg) = args.arg(LINT_RULES_KEY) {
+            let rule_names = Linter::new().rule_names();
+
+            for entry in arg.as_array() {
+                let rule = entry.as_str()?;
+
+                match LintConfig::parse_entry(&rule) {
+                    Err(message) => return Err(DatamodelError::new_validation_error(&message, entry.span())),
+                    Ok((name, _)) if !rule_names.contains(&name) => {
+                        return Err(DatamodelError::new_validation_error(
+                            &format!(
+                                "Unknown lint rule `{}`. Expected one of {}.",
+                                name,
+                                rule_names
+                                    .iter()
+                                    .map(|name| format!("`{}`", name))
+                                    .collect::<Vec<_>>()
+                                    .join(", ")
+                            ),
+                            entry.span(),
+                        ))
+                    }
+                    Ok(_) => (),
+                }
+
+                lint_rules.push(rule);
+            }
+        }
+
         for prop in &ast_generator.properties {
             let is_first_class_prop = FIRST_CLASS_PROPERTIES.iter().any(|k| *k == prop.name.name);
             if is_first_class_prop {
@@ -80,6 +118,7 @@ impl GeneratorLoader {
             output,
             binary_targets,
             preview_features,
+            lint_rules,
             config: properties,
             documentation: ast_generator.documentation.clone().map(|comment| comment.text),
         })
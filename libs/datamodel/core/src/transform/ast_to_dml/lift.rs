@@ -31,16 +31,101 @@ impl<'a> LiftAstToDml<'a> {
         }
     }
 
+    /// Copies the fields of abstract models into the models extending them, in the order of the
+    /// `extends` list and before the fields of the model itself. The copied fields keep their spans,
+    /// so errors about them point to the abstract model defining them.
+    pub fn expand_abstract_models(&self, ast_schema: &ast::SchemaAst) -> Result<ast::SchemaAst, ErrorCollection> {
+        let mut expanded = ast_schema.clone();
+        let mut errors = ErrorCollection::new();
+
+        for top in expanded.tops.iter_mut() {
+            let model = match top {
+                ast::Top::Model(model) if !model.extends.is_empty() => model,
+                _ => continue,
+            };
+
+            let mut inherited_fields: Vec<ast::Field> = Vec::new();
+
+            for parent_name in &model.extends {
+                let parent = match ast_schema.find_model(&parent_name.name) {
+                    Some(parent) if parent.is_abstract => parent,
+                    Some(_) => {
+                        errors.push(DatamodelError::new_model_validation_error(
+                            &format!(
+                                "The model `{}` can not be extended, because it is not abstract.",
+                                &parent_name.name
+                            ),
+                            &model.name.name,
+                            parent_name.span,
+                        ));
+                        continue;
+                    }
+                    None => {
+                        errors.push(DatamodelError::new_model_validation_error(
+                            &format!("There is no abstract model called `{}` to extend.", &parent_name.name),
+                            &model.name.name,
+                            parent_name.span,
+                        ));
+                        continue;
+                    }
+                };
+
+                for field in &parent.fields {
+                    if inherited_fields.iter().any(|f| f.name.name == field.name.name) {
+                        errors.push(DatamodelError::new_duplicate_field_error(
+                            &model.name.name,
+                            &field.name.name,
+                            parent_name.span,
+                        ));
+                    } else {
+                        inherited_fields.push(field.clone());
+                    }
+                }
+            }
+
+            for field in &model.fields {
+                if inherited_fields.iter().any(|f| f.name.name == field.name.name) {
+                    errors.push(DatamodelError::new_duplicate_field_error(
+                        &model.name.name,
+                        &field.name.name,
+                        field.name.span,
+                    ));
+                }
+            }
+
+            inherited_fields.append(&mut model.fields);
+            model.fields = inherited_fields;
+        }
+
+        errors.ok()?;
+
+        Ok(expanded)
+    }
+
     pub fn lift(&self, ast_schema: &ast::SchemaAst) -> Result<dml::Datamodel, ErrorCollection> {
         let mut schema = dml::Datamodel::new();
         let mut errors = ErrorCollection::new();
 
+        // Abstract models are lifted first, so errors in their fields are reported once, and not for
+        // every model extending them.
+        for ast_model in ast_schema.models().into_iter().filter(|model| model.is_abstract) {
+            match self.lift_abstract_model(ast_model, ast_schema) {
+                Ok(md) => schema.abstract_models.push(md),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
         for ast_obj in &ast_schema.tops {
             match ast_obj {
                 ast::Top::Enum(en) => match self.lift_enum(&en) {
                     Ok(en) => schema.add_enum(en),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::Model(ty) if ty.is_abstract => { /* Abstract models are lifted above */ }
                 ast::Top::Model(ty) => match self.lift_model(&ty, ast_schema) {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
@@ -63,6 +148,8 @@ impl<'a> LiftAstToDml<'a> {
     fn lift_model(&self, ast_model: &ast::Model, ast_schema: &ast::SchemaAst) -> Result<dml::Model, ErrorCollection> {
         let mut model = dml::Model::new(ast_model.name.name.clone(), None);
         model.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+        model.is_embedded = ast_model.is_embedded;
+        model.extends = ast_model.extends.iter().map(|parent| parent.name.clone()).collect();
 
         let mut errors = ErrorCollection::new();
 
@@ -84,6 +171,41 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates an abstract model AST node. Abstract models only group fields, so they must
+    /// neither have model level directives nor relation fields.
+    fn lift_abstract_model(
+        &self,
+        ast_model: &ast::Model,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::Model, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for directive in &ast_model.directives {
+            errors.push(DatamodelError::new_model_validation_error(
+                "Abstract models can not have model level attributes. Define them on the models extending it instead.",
+                &ast_model.name.name,
+                directive.span,
+            ));
+        }
+
+        errors.ok()?;
+
+        let model = self.lift_model(ast_model, ast_schema)?;
+
+        for field in model.relation_fields() {
+            errors.push(DatamodelError::new_field_validation_error(
+                "Abstract models can only contain scalar and enum fields.",
+                &model.name,
+                &field.name,
+                ast_model.find_field(&field.name).span,
+            ));
+        }
+
+        errors.ok()?;
+
+        Ok(model)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -197,7 +319,9 @@ impl<'a> LiftAstToDml<'a> {
             _ => (false, ""),
         };
 
-        if let Ok(scalar_type) = ScalarType::from_str(type_name) {
+        if let Some(raw_type) = ast_field.field_type.unsupported_type() {
+            Ok((dml::FieldType::Unsupported(raw_type.to_owned()), vec![]))
+        } else if let Ok(scalar_type) = ScalarType::from_str(type_name) {
             if supports_native_types {
                 let (connector_string, connector) = (
                     &self.source.unwrap().active_provider,
@@ -296,8 +420,20 @@ impl<'a> LiftAstToDml<'a> {
             } else {
                 Ok((dml::FieldType::Base(scalar_type, type_alias), vec![]))
             }
-        } else if ast_schema.find_model(type_name).is_some() {
-            Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
+        } else if let Some(model) = ast_schema.find_model(type_name) {
+            if model.is_abstract {
+                Err(DatamodelError::new_validation_error(
+                    &format!(
+                        "The abstract model `{}` can not be used as a field type. Use `extends {}` on the model instead.",
+                        type_name, type_name
+                    ),
+                    ast_field.field_type.span,
+                ))
+            } else if model.is_embedded {
+                Ok((dml::FieldType::CompositeType(type_name.clone()), vec![]))
+            } else {
+                Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
+            }
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
         } else {
@@ -330,7 +466,7 @@ impl<'a> LiftAstToDml<'a> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if let dml::FieldType::Relation(_) | dml::FieldType::CompositeType(_) = field_type {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -127,12 +127,14 @@ impl Standardiser {
                 let source_field = source_model
                     .find_relation_field(&missing_back_relation_field.related_field)
                     .expect(STATE_ERROR);
-                errors.push(field_validation_error(
-                                "Automatic related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
-                                &source_model,
-                                &Field::RelationField(source_field.clone()),
-                                &ast_schema,
-                            ));
+                errors.push(DatamodelError::new_missing_opposite_relation_field_error(
+                    &source_model.name,
+                    &source_field.name,
+                    ast_schema
+                        .find_field(&source_model.name, &source_field.name)
+                        .expect(STATE_ERROR)
+                        .span,
+                ));
             } else {
                 let model_mut = schema.find_model_mut(&missing_back_relation_field.model);
 
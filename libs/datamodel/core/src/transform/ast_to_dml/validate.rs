@@ -18,7 +18,6 @@ pub struct Validator<'a> {
 const STATE_ERROR: &str = "Failed lookup of model, field or optional property during internal processing. This means that the internal representation was mutated incorrectly.";
 const RELATION_DIRECTIVE_NAME: &str = "relation";
 const RELATION_DIRECTIVE_NAME_WITH_AT: &str = "@relation";
-const PRISMA_FORMAT_HINT: &str = "You can run `prisma format` to fix this automatically.";
 
 impl<'a> Validator<'a> {
     /// Creates a new instance, with all builtin directives registered.
@@ -42,16 +41,40 @@ impl<'a> Validator<'a> {
             // Having a separate error collection allows checking whether any error has occurred for a model.
             let mut errors_for_model = ErrorCollection::new();
 
-            if let Err(err) = self.validate_model_has_strict_unique_criteria(
-                ast_schema.find_model(&model.name).expect(STATE_ERROR),
-                model,
-            ) {
-                errors_for_model.push(err);
+            if model.is_embedded {
+                let ast_model = ast_schema.find_model(&model.name).expect(STATE_ERROR);
+
+                if let Err(err) = self.validate_model_name(ast_model, model) {
+                    errors_for_model.push(err);
+                }
+
+                if let Err(ref mut the_errors) = self.validate_composite_type(schema, ast_model, model) {
+                    errors_for_model.append(the_errors);
+                }
+
+                all_errors.append(&mut errors_for_model);
+                continue;
+            }
+
+            // Ignored models are not exposed by the query engine, so they do not need a unique criteria.
+            if !model.is_ignored {
+                if let Err(err) = self.validate_model_has_strict_unique_criteria(
+                    ast_schema.find_model(&model.name).expect(STATE_ERROR),
+                    model,
+                ) {
+                    errors_for_model.push(err);
+                }
             }
             if let Err(err) = self.validate_model_name(ast_schema.find_model(&model.name).expect(STATE_ERROR), model) {
                 errors_for_model.push(err);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_ignored_fields(schema, ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(err) = self.validate_relations_not_ambiguous(ast_schema, model) {
                 errors_for_model.push(err);
             }
@@ -256,6 +279,23 @@ impl<'a> Validator<'a> {
         let mut errors = ErrorCollection::new();
 
         for field in model.scalar_fields() {
+            if let FieldType::CompositeType(type_name) = &field.field_type {
+                if let Err(ref mut the_errors) = self.validate_composite_type_field(ast_model, model, field, type_name)
+                {
+                    errors.append(the_errors);
+                }
+            }
+
+            let is_part_of_id = field.is_id || model.id_fields.iter().any(|id_field| id_field == &field.name);
+            if field.field_type.is_unsupported() && is_part_of_id && !model.is_ignored {
+                errors.push(DatamodelError::new_field_validation_error(
+                    &format!("Field `{}` in model `{}` can't be part of the id, because its type is not supported by Prisma. Use `@@ignore` on the model instead.", &field.name, &model.name),
+                    &model.name,
+                    &field.name,
+                    ast_model.find_field(&field.name).span,
+                ));
+            }
+
             if let Some(dml::ScalarType::Json) = field.field_type.scalar_type() {
                 // TODO: this is really ugly
                 let supports_json_type = match self.source {
@@ -280,6 +320,216 @@ impl<'a> Validator<'a> {
         }
     }
 
+    /// `@ignore` is redundant on the fields of ignored models, not allowed on id fields, and required
+    /// on the relation fields pointing to ignored models.
+    fn validate_ignored_fields(
+        &self,
+        data_model: &dml::Datamodel,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        for field in model.fields() {
+            let ast_field = ast_model.find_field(field.name());
+
+            if model.is_ignored && field.is_ignored() {
+                let span = ast_field
+                    .directives
+                    .iter()
+                    .find(|directive| directive.name.name == "ignore")
+                    .map(|directive| directive.span)
+                    .unwrap_or(ast_field.span);
+
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "Fields on an already ignored Model do not need an `@ignore` annotation.",
+                    "ignore",
+                    span,
+                ));
+            }
+
+            if field.is_ignored() && (field.is_id() || model.id_fields.iter().any(|id_field| id_field == field.name()))
+            {
+                errors.push(DatamodelError::new_field_validation_error(
+                    "Fields that are part of the id of a model cannot be ignored. Use `@@ignore` on the model instead.",
+                    &model.name,
+                    field.name(),
+                    ast_field.span,
+                ));
+            }
+
+            if let dml::Field::RelationField(rf) = field {
+                let related_model_is_ignored = data_model
+                    .find_model(&rf.relation_info.to)
+                    .map(|related_model| related_model.is_ignored)
+                    .unwrap_or(false);
+
+                if related_model_is_ignored && !model.is_ignored && !rf.is_ignored {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!(
+                            "The relation field `{}` on Model `{}` must specify the `@ignore` attribute, because the model {} it is pointing to is marked ignored.",
+                            rf.name, model.name, rf.relation_info.to
+                        ),
+                        &model.name,
+                        &rf.name,
+                        ast_field.span,
+                    ));
+                }
+            }
+        }
+
+        errors.ok()
+    }
+
+    /// Composite types are stored as JSON in the fields using them, so they cannot have ids,
+    /// indexes, relations or attributes on their fields.
+    fn validate_composite_type(
+        &self,
+        data_model: &dml::Datamodel,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        if let Some(directive) = ast_model.directives.first() {
+            errors.push(DatamodelError::new_model_validation_error(
+                "Composite types cannot have block attributes.",
+                &model.name,
+                directive.span,
+            ));
+        }
+
+        for ast_field in &ast_model.fields {
+            if let Some(directive) = ast_field.directives.first() {
+                errors.push(DatamodelError::new_field_validation_error(
+                    "The fields of composite types cannot have attributes.",
+                    &model.name,
+                    &ast_field.name.name,
+                    directive.span,
+                ));
+            }
+        }
+
+        for field in model.relation_fields() {
+            errors.push(DatamodelError::new_field_validation_error(
+                &format!(
+                    "Composite types cannot have relation fields. `{}` is a model, not a composite type.",
+                    field.relation_info.to
+                ),
+                &model.name,
+                &field.name,
+                ast_model.find_field(&field.name).field_type.span,
+            ));
+        }
+
+        if let Some(path) = self.find_composite_type_cycle(data_model, model, &mut vec![model.name.as_str()]) {
+            errors.push(DatamodelError::new_model_validation_error(
+                &format!(
+                    "Composite types cannot contain themselves. Recursive path was: {}.",
+                    path.join(" -> ")
+                ),
+                &model.name,
+                ast_model.name.span,
+            ));
+        }
+
+        errors.ok()
+    }
+
+    fn find_composite_type_cycle<'b>(
+        &self,
+        data_model: &'b dml::Datamodel,
+        composite_type: &'b dml::Model,
+        path: &mut Vec<&'b str>,
+    ) -> Option<Vec<&'b str>> {
+        for field in composite_type.scalar_fields() {
+            let type_name = match &field.field_type {
+                FieldType::CompositeType(type_name) => type_name.as_str(),
+                _ => continue,
+            };
+
+            if type_name == path[0] {
+                let mut cycle = path.clone();
+                cycle.push(type_name);
+                return Some(cycle);
+            }
+
+            if path.contains(&type_name) {
+                continue;
+            }
+
+            if let Some(nested_type) = data_model.find_model(type_name) {
+                path.push(type_name);
+
+                if let Some(cycle) = self.find_composite_type_cycle(data_model, nested_type, path) {
+                    return Some(cycle);
+                }
+
+                path.pop();
+            }
+        }
+
+        None
+    }
+
+    /// Fields of composite types are stored as a single JSON value.
+    fn validate_composite_type_field(
+        &self,
+        ast_model: &ast::Model,
+        model: &dml::Model,
+        field: &dml::ScalarField,
+        type_name: &str,
+    ) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+        let span = ast_model.find_field(&field.name).span;
+
+        let supports_json_type = match self.source {
+            Some(source) => source.combined_connector.supports_json(),
+            None => false,
+        };
+
+        if !supports_json_type {
+            errors.push(DatamodelError::new_field_validation_error(
+                &format!("Field `{}` in model `{}` can't be of the composite type `{}`. Composite types are stored as JSON, and the current connector does not support the Json type.", &field.name, &model.name, type_name),
+                &model.name,
+                &field.name,
+                span,
+            ));
+        }
+
+        if field.is_list() {
+            errors.push(DatamodelError::new_field_validation_error(
+                &format!("Field `{}` in model `{}` can't be a list of the composite type `{}`. Lists of composite types are only supported inside other composite types.", &field.name, &model.name, type_name),
+                &model.name,
+                &field.name,
+                span,
+            ));
+        }
+
+        let is_in_an_index = model.indices.iter().any(|index| index.fields.contains(&field.name))
+            || model.id_fields.contains(&field.name);
+
+        if field.is_id || field.is_unique || is_in_an_index {
+            errors.push(DatamodelError::new_field_validation_error(
+                "Fields of composite types cannot be part of an id, unique constraint or index.",
+                &model.name,
+                &field.name,
+                span,
+            ));
+        }
+
+        if field.default_value.is_some() {
+            errors.push(DatamodelError::new_field_validation_error(
+                "Fields of composite types cannot have a default value.",
+                &model.name,
+                &field.name,
+                span,
+            ));
+        }
+
+        errors.ok()
+    }
+
     fn validate_enum_default_values(
         &self,
         data_model: &dml::Datamodel,
@@ -754,14 +1004,11 @@ impl<'a> Validator<'a> {
             // ONE TO MANY
             if field.is_singular() && related_field.is_list() {
                 if rel_info.fields.is_empty() {
-                    errors.push(DatamodelError::new_directive_validation_error(
-                            &format!(
-                                "The relation field `{}` on Model `{}` must specify the `fields` argument in the {} directive. {}",
-                                &field.name, &model.name, RELATION_DIRECTIVE_NAME_WITH_AT, PRISMA_FORMAT_HINT
-                            ),
-                            RELATION_DIRECTIVE_NAME,
-                            field_span,
-                        ));
+                    errors.push(DatamodelError::new_missing_relation_fields_argument_error(
+                        &model.name,
+                        &field.name,
+                        field_span,
+                    ));
                 }
 
                 if rel_info.to_fields.is_empty() {
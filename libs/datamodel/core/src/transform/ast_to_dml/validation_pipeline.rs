@@ -43,7 +43,15 @@ impl<'a> ValidationPipeline<'a> {
             return Err(all_errors);
         }
 
-        // Phase 3: Lift AST to DML.
+        // Phase 3: Lift AST to DML. All later phases see the fields of abstract models in the models extending them.
+        let ast_schema = &match self.lifter.expand_abstract_models(ast_schema) {
+            Err(mut err) => {
+                all_errors.append(&mut err);
+                return Err(all_errors);
+            }
+            Ok(expanded) => expanded,
+        };
+
         let mut schema = match self.lifter.lift(ast_schema) {
             Err(mut err) => {
                 // Cannot continue on lifter error.
@@ -0,0 +1,53 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::ast::Directive;
+use crate::error::DatamodelError;
+use crate::{dml, Datamodel};
+
+/// Prismas builtin `@ignore` and `@@ignore` directives. Ignored fields and models stay in the
+/// datamodel for migrations and relations, but are not exposed by the query engine.
+pub struct IgnoreDirectiveValidator {}
+
+const DIRECTIVE_NAME: &str = "ignore";
+
+impl DirectiveValidator<dml::Model> for IgnoreDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        DIRECTIVE_NAME
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_ignored = true;
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Model, _datamodel: &Datamodel) -> Result<Vec<Directive>, DatamodelError> {
+        if obj.is_ignored {
+            Ok(vec![Directive::new(self.directive_name(), Vec::new())])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
+
+impl DirectiveValidator<dml::Field> for IgnoreDirectiveValidator {
+    fn directive_name(&self) -> &str {
+        DIRECTIVE_NAME
+    }
+
+    fn validate_and_apply(&self, _args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        match obj {
+            dml::Field::ScalarField(sf) => sf.is_ignored = true,
+            dml::Field::RelationField(rf) => rf.is_ignored = true,
+        }
+
+        Ok(())
+    }
+
+    fn serialize(&self, obj: &dml::Field, _datamodel: &Datamodel) -> Result<Vec<Directive>, DatamodelError> {
+        if obj.is_ignored() {
+            Ok(vec![Directive::new(self.directive_name(), Vec::new())])
+        } else {
+            Ok(vec![])
+        }
+    }
+}
@@ -2,10 +2,12 @@ mod default;
 mod directive_list_validator;
 mod directive_validator;
 mod id;
+mod ignore;
 mod map;
 mod relation;
 mod unique_and_index;
 mod updated_at;
+mod validations;
 
 use crate::dml;
 use directive_list_validator::DirectiveListValidator;
@@ -39,6 +41,10 @@ fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
     validator.add(Box::new(default::DefaultDirectiveValidator {}));
     validator.add(Box::new(relation::RelationDirectiveValidator {}));
     validator.add(Box::new(updated_at::UpdatedAtDirectiveValidator {}));
+    validator.add(Box::new(ignore::IgnoreDirectiveValidator {}));
+    validator.add(Box::new(validations::LengthDirectiveValidator {}));
+    validator.add(Box::new(validations::PatternDirectiveValidator {}));
+    validator.add(Box::new(validations::RangeDirectiveValidator {}));
 
     validator
 }
@@ -50,6 +56,7 @@ fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(ignore::IgnoreDirectiveValidator {}));
 
     validator
 }
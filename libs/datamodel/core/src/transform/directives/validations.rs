@@ -0,0 +1,218 @@
+use super::{super::helpers::*, DirectiveValidator};
+use crate::error::DatamodelError;
+use crate::{ast, dml};
+use rust_decimal::prelude::ToPrimitive;
+
+/// Prismas builtin `@length` directive, constraining the number of characters of a String.
+pub struct LengthDirectiveValidator {}
+
+/// Prismas builtin `@pattern` directive, a regular expression a String has to match.
+pub struct PatternDirectiveValidator {}
+
+/// Prismas builtin `@range` directive, the inclusive bounds of a number.
+pub struct RangeDirectiveValidator {}
+
+impl DirectiveValidator<dml::Field> for LengthDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"length"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = string_field(self.directive_name(), args, obj)?;
+
+        let mut bound = |name: &str| -> Result<Option<u64>, DatamodelError> {
+            match args.optional_arg(name) {
+                Some(value) => match value.as_int()? {
+                    int if int < 0 => Err(DatamodelError::new_directive_validation_error(
+                        &format!("The `{}` length must not be negative.", name),
+                        self.directive_name(),
+                        value.span(),
+                    )),
+                    int => Ok(Some(int as u64)),
+                },
+                None => Ok(None),
+            }
+        };
+
+        let (min, max) = (bound("min")?, bound("max")?);
+        validate_bounds(
+            self.directive_name(),
+            min.map(|min| min as f64),
+            max.map(|max| max as f64),
+            args.span(),
+        )?;
+
+        sf.validations.push(dml::FieldValidation::Length { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        Ok(validations(field)
+            .filter_map(|validation| match validation {
+                dml::FieldValidation::Length { min, max } => Some(ast::Directive::new(
+                    self.directive_name(),
+                    lower_bounds(min.map(|min| min.to_string()), max.map(|max| max.to_string())),
+                )),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+impl DirectiveValidator<dml::Field> for PatternDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"pattern"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = string_field(self.directive_name(), args, obj)?;
+        let value = args.default_arg("regex")?;
+        let pattern = value.as_str()?;
+
+        let pattern = match dml::Pattern::new(&pattern) {
+            Ok(pattern) => pattern,
+            Err(err) => {
+                return self.new_directive_validation_error(
+                    &format!("The pattern is not a valid regular expression: {}", err),
+                    value.span(),
+                )
+            }
+        };
+
+        sf.validations.push(dml::FieldValidation::Pattern(pattern));
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        Ok(validations(field)
+            .filter_map(|validation| match validation {
+                dml::FieldValidation::Pattern(pattern) => Some(ast::Directive::new(
+                    self.directive_name(),
+                    vec![ast::Argument::new_string("", pattern.as_str())],
+                )),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+impl DirectiveValidator<dml::Field> for RangeDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"range"
+    }
+
+    fn validate_and_apply(&self, args: &mut Arguments, obj: &mut dml::Field) -> Result<(), DatamodelError> {
+        let sf = match obj {
+            dml::Field::ScalarField(sf) if is_numeric(sf) => sf,
+            _ => {
+                return self.new_directive_validation_error(
+                    "The `@range` attribute can only be used on fields of type Int, Float or Decimal.",
+                    args.span(),
+                )
+            }
+        };
+
+        let mut bound = |name: &str| -> Result<Option<f64>, DatamodelError> {
+            match args.optional_arg(name) {
+                Some(value) => Ok(value.as_float()?.to_f64()),
+                None => Ok(None),
+            }
+        };
+
+        let (min, max) = (bound("min")?, bound("max")?);
+        validate_bounds(self.directive_name(), min, max, args.span())?;
+
+        sf.validations.push(dml::FieldValidation::Range { min, max });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        field: &dml::Field,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        Ok(validations(field)
+            .filter_map(|validation| match validation {
+                dml::FieldValidation::Range { min, max } => Some(ast::Directive::new(
+                    self.directive_name(),
+                    lower_bounds(min.map(|min| min.to_string()), max.map(|max| max.to_string())),
+                )),
+                _ => None,
+            })
+            .collect())
+    }
+}
+
+/// Returns the scalar field the directive is on, if it is a String.
+fn string_field<'a>(
+    directive_name: &str,
+    args: &Arguments,
+    obj: &'a mut dml::Field,
+) -> Result<&'a mut dml::ScalarField, DatamodelError> {
+    match obj {
+        dml::Field::ScalarField(sf) if sf.field_type.scalar_type() == Some(dml::ScalarType::String) => Ok(sf),
+        _ => Err(DatamodelError::new_directive_validation_error(
+            &format!(
+                "The `@{}` attribute can only be used on fields of type String.",
+                directive_name
+            ),
+            directive_name,
+            args.span(),
+        )),
+    }
+}
+
+/// At least one bound has to be given, and the minimum must not be greater than the maximum.
+fn validate_bounds(
+    directive_name: &str,
+    min: Option<f64>,
+    max: Option<f64>,
+    span: ast::Span,
+) -> Result<(), DatamodelError> {
+    let message = match (min, max) {
+        (None, None) => "Either `min` or `max` must be specified.",
+        (Some(min), Some(max)) if min > max => "The `min` value must not be greater than the `max` value.",
+        _ => return Ok(()),
+    };
+
+    Err(DatamodelError::new_directive_validation_error(
+        message,
+        directive_name,
+        span,
+    ))
+}
+
+fn is_numeric(sf: &dml::ScalarField) -> bool {
+    matches!(
+        sf.field_type.scalar_type(),
+        Some(dml::ScalarType::Int) | Some(dml::ScalarType::Float) | Some(dml::ScalarType::Decimal)
+    )
+}
+
+fn validations(field: &dml::Field) -> impl Iterator<Item = &dml::FieldValidation> {
+    match field {
+        dml::Field::ScalarField(sf) => sf.validations.iter(),
+        dml::Field::RelationField(_) => [].iter(),
+    }
+}
+
+fn lower_bounds(min: Option<String>, max: Option<String>) -> Vec<ast::Argument> {
+    let bound =
+        |name: &str, value: String| ast::Argument::new(name, ast::Expression::NumericValue(value, ast::Span::empty()));
+
+    min.map(|min| bound("min", min))
+        .into_iter()
+        .chain(max.map(|max| bound("max", max)))
+        .collect()
+}
@@ -1,5 +1,5 @@
 use crate::ast;
-use crate::configuration::Datasource;
+use crate::configuration::{Datasource, StringFromEnvVar};
 
 pub struct DatasourceSerializer {}
 
@@ -21,14 +21,11 @@ impl DatasourceSerializer {
         let mut arguments: Vec<ast::Argument> = Vec::new();
 
         arguments.push(ast::Argument::new_string("provider", &source.active_provider));
-        match source.url.from_env_var {
-            Some(ref env_var) => {
-                let values = vec![ast::Expression::StringValue(env_var.to_string(), ast::Span::empty())];
-                arguments.push(ast::Argument::new_function("url", "env", values));
-            }
-            None => {
-                arguments.push(ast::Argument::new_string("url", &source.url.value));
-            }
+        arguments.push(ast::Argument::new("url", Self::lower_url(&source.url)));
+
+        if !source.replica_urls.is_empty() {
+            let urls = source.replica_urls.iter().map(Self::lower_url).collect();
+            arguments.push(ast::Argument::new_array("replicaUrls", urls));
         }
 
         if !&source.preview_features.is_empty() {
@@ -48,4 +45,19 @@ impl DatasourceSerializer {
             span: ast::Span::empty(),
         }
     }
+
+    fn lower_url(url: &StringFromEnvVar) -> ast::Expression {
+        match (&url.from_env_var, &url.template) {
+            (Some(env_var), _) => {
+                let values = std::iter::once(env_var)
+                    .chain(url.fallback.iter())
+                    .map(|value| ast::Expression::StringValue(value.to_string(), ast::Span::empty()))
+                    .collect();
+                ast::Expression::Function("env".to_owned(), values, ast::Span::empty())
+            }
+            // Render the placeholders, not the values of the environment variables.
+            (None, Some(template)) => ast::Expression::StringValue(template.to_owned(), ast::Span::empty()),
+            (None, None) => ast::Expression::StringValue(url.value.to_owned(), ast::Span::empty()),
+        }
+    }
 }
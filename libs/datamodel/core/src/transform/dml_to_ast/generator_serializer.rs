@@ -35,6 +35,16 @@ impl GeneratorSerializer {
             arguments.push(ast::Argument::new_array("previewFeatures", features));
         }
 
+        if !generator.lint_rules.is_empty() {
+            let lint_rules: Vec<ast::Expression> = generator
+                .lint_rules
+                .iter()
+                .map(|rule| ast::Expression::StringValue(rule.to_owned(), ast::Span::empty()))
+                .collect();
+
+            arguments.push(ast::Argument::new_array("lintRules", lint_rules));
+        }
+
         let platform_values: Vec<ast::Expression> = generator
             .binary_targets
             .iter()
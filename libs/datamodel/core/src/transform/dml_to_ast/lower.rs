@@ -23,6 +23,16 @@ impl<'a> LowerDmlToAst<'a> {
         let mut tops: Vec<ast::Top> = Vec::new();
         let mut errors = ErrorCollection::new();
 
+        for model in &datamodel.abstract_models {
+            match self.lower_model(model, datamodel) {
+                Ok(mut res) => {
+                    res.is_abstract = true;
+                    tops.push(ast::Top::Model(res))
+                }
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
         for model in datamodel.models() {
             if !model.is_generated {
                 match self.lower_model(model, datamodel) {
@@ -46,7 +56,10 @@ impl<'a> LowerDmlToAst<'a> {
         let mut errors = ErrorCollection::new();
         let mut fields: Vec<ast::Field> = Vec::new();
 
-        for field in model.fields() {
+        // Inherited fields are rendered on the abstract model defining them.
+        let inherited_fields: Vec<&str> = datamodel.inherited_fields(model).map(|field| field.name()).collect();
+
+        for field in model.fields().filter(|field| !inherited_fields.contains(&field.name())) {
             match self.lower_field(field, datamodel) {
                 Ok(ast_field) => fields.push(ast_field),
                 Err(mut err) => errors.append(&mut err),
@@ -64,6 +77,13 @@ impl<'a> LowerDmlToAst<'a> {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_embedded: model.is_embedded,
+            is_abstract: false,
+            extends: model
+                .extends
+                .iter()
+                .map(|parent| ast::Identifier::new(parent))
+                .collect(),
         })
     }
 
@@ -134,7 +154,8 @@ impl<'a> LowerDmlToAst<'a> {
                 ast::Identifier::new(&custom_type_name.as_ref().unwrap_or(&tpe.to_string()))
             }
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
-            dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::CompositeType(tpe) => ast::Identifier::new(&tpe.to_string()),
+            dml::FieldType::Unsupported(tpe) => ast::Identifier::new_unsupported(tpe),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
             dml::FieldType::NativeType(prisma_tpe, _native_tpe) => ast::Identifier::new(&prisma_tpe.to_string()),
         }
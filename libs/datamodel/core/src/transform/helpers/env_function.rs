@@ -4,6 +4,8 @@ use crate::transform::helpers::ValueValidator;
 
 pub struct EnvFunction {
     var_name: String,
+    /// Used when the variable is not set, e.g. `file:./dev.db` for `env("DATABASE_URL", "file:./dev.db")`.
+    fallback: Option<String>,
     span: ast::Span,
 }
 
@@ -25,17 +27,22 @@ impl EnvFunction {
             ));
         };
 
-        if args.len() != 1 {
+        if args.is_empty() || args.len() > 2 {
             return Err(DatamodelError::new_functional_evaluation_error(
-                "Exactly one string parameter must be passed to the env function.",
+                "The env function takes the name of an environment variable, and optionally a fallback value.",
                 expr.span(),
             ));
         }
 
-        let var_wrapped = &args[0];
-        let var_name = ValueValidator::new(var_wrapped).as_str()?;
+        let var_name = ValueValidator::new(&args[0]).as_str()?;
+        let fallback = match args.get(1) {
+            Some(fallback) => Some(ValueValidator::new(fallback).as_str()?),
+            None => None,
+        };
+
         Ok(Self {
             var_name,
+            fallback,
             span: expr.span(),
         })
     }
@@ -44,8 +51,13 @@ impl EnvFunction {
         &self.var_name
     }
 
+    pub fn fallback(&self) -> Option<&str> {
+        self.fallback.as_deref()
+    }
+
+    /// The value of the variable, or the fallback if the variable is not set.
     pub fn evaluate(&self) -> Result<ValueValidator, DatamodelError> {
-        if let Ok(var) = std::env::var(&self.var_name) {
+        if let Some(var) = std::env::var(&self.var_name).ok().or_else(|| self.fallback.clone()) {
             let value_validator = ValueValidator::new(&ast::Expression::StringValue(var, self.span));
             Ok(value_validator)
         } else {
@@ -10,19 +10,27 @@ use crate::{
 use datamodel_connector::NativeTypeInstance;
 use itertools::Itertools;
 
+/// Iterator to walk all the models in the schema. Composite types are skipped: they are not
+/// stored on their own, but as JSON in the fields using them.
 pub fn walk_models<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = ModelWalker<'a>> + 'a {
-    datamodel.models().map(move |model| ModelWalker { datamodel, model })
+    datamodel
+        .models()
+        .filter(|model| !model.is_embedded)
+        .map(move |model| ModelWalker { datamodel, model })
 }
 
 /// Iterator to walk all the scalar fields in the schema, associating them with their parent model.
 pub fn walk_scalar_fields<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = ScalarFieldWalker<'a>> + 'a {
-    datamodel.models().flat_map(move |model| {
-        model.scalar_fields().map(move |field| ScalarFieldWalker {
-            datamodel,
-            model,
-            field,
+    datamodel
+        .models()
+        .filter(|model| !model.is_embedded)
+        .flat_map(move |model| {
+            model.scalar_fields().map(move |field| ScalarFieldWalker {
+                datamodel,
+                model,
+                field,
+            })
         })
-    })
 }
 
 /// Iterator over all the relations in the schema. Each relation will only occur
@@ -167,6 +175,9 @@ impl<'a> ScalarFieldWalker<'a> {
             }),
             FieldType::Base(scalar_type, _) => TypeWalker::Base(*scalar_type),
             FieldType::NativeType(scalar_type, native_type) => TypeWalker::NativeType(*scalar_type, native_type),
+            // Composite types are stored as JSON.
+            FieldType::CompositeType(_) => TypeWalker::Base(ScalarType::Json),
+            FieldType::Unsupported(raw_type) => TypeWalker::Unsupported(raw_type),
             _ => TypeWalker::Other,
         }
     }
@@ -200,6 +211,8 @@ pub enum TypeWalker<'a> {
     Enum(EnumWalker<'a>),
     Base(ScalarType),
     NativeType(ScalarType, &'a NativeTypeInstance),
+    /// A type Prisma does not support, with the raw database type.
+    Unsupported(&'a str),
     Other,
 }
 
@@ -228,6 +241,10 @@ pub struct RelationFieldWalker<'a> {
 }
 
 impl<'a> RelationFieldWalker<'a> {
+    pub fn name(&self) -> &'a str {
+        &self.field.name
+    }
+
     pub fn arity(&self) -> FieldArity {
         self.field.arity
     }
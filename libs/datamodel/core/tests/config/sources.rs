@@ -244,10 +244,7 @@ fn must_succeed_if_env_var_is_missing_but_override_was_provided() {
     let data_source = config.datasources.first().unwrap();
 
     data_source.assert_name("ds");
-    data_source.assert_url(StringFromEnvVar {
-        from_env_var: None,
-        value: url.to_string(),
-    });
+    data_source.assert_url(StringFromEnvVar::new_literal(url.to_string()));
 }
 
 #[test]
@@ -267,10 +264,7 @@ fn must_succeed_if_env_var_exists_and_override_was_provided() {
     let data_source = config.datasources.first().unwrap();
 
     data_source.assert_name("ds");
-    data_source.assert_url(StringFromEnvVar {
-        from_env_var: None,
-        value: url.to_string(),
-    });
+    data_source.assert_url(StringFromEnvVar::new_literal(url.to_string()));
 }
 
 #[test]
@@ -289,12 +283,214 @@ fn must_succeed_with_overrides() {
     let data_source = config.datasources.first().unwrap();
 
     data_source.assert_name("ds");
+    data_source.assert_url(StringFromEnvVar::new_literal(url.to_string()));
+}
+
+#[test]
+#[serial]
+fn must_use_the_fallback_if_env_var_is_missing() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = env("FALLBACK_DATABASE_URL", "postgres://localhost")
+        }
+    "#;
+    std::env::remove_var("FALLBACK_DATABASE_URL");
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let data_source = config.datasources.first().unwrap();
+
+    data_source.assert_url(StringFromEnvVar {
+        from_env_var: Some("FALLBACK_DATABASE_URL".to_string()),
+        value: "postgres://localhost".to_string(),
+        fallback: Some("postgres://localhost".to_string()),
+        template: None,
+    });
+}
+
+#[test]
+#[serial]
+fn must_prefer_the_env_var_over_the_fallback() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = env("FALLBACK_DATABASE_URL", "postgres://localhost")
+        }
+    "#;
+    std::env::set_var("FALLBACK_DATABASE_URL", "postgres://hostfoo");
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let data_source = config.datasources.first().unwrap();
+
+    data_source.assert_url(StringFromEnvVar {
+        from_env_var: Some("FALLBACK_DATABASE_URL".to_string()),
+        value: "postgres://hostfoo".to_string(),
+        fallback: Some("postgres://localhost".to_string()),
+        template: None,
+    });
+}
+
+#[test]
+#[serial]
+fn must_error_if_env_function_has_too_many_arguments() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = env("DATABASE_URL", "postgres://localhost", "postgres://hostfoo")
+        }
+    "#;
+
+    let errors = datamodel::parse_configuration(schema).err().unwrap();
+
+    errors.assert_is(DatamodelError::new_functional_evaluation_error(
+        "The env function takes the name of an environment variable, and optionally a fallback value.",
+        Span::new(75, 140),
+    ));
+}
+
+#[test]
+#[serial]
+fn must_interpolate_env_vars_into_the_url() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://${INTERPOLATED_DB_USER}@${INTERPOLATED_DB_HOST}:5432/db"
+        }
+    "#;
+    std::env::set_var("INTERPOLATED_DB_USER", "prisma");
+    std::env::set_var("INTERPOLATED_DB_HOST", "hostfoo");
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let data_source = config.datasources.first().unwrap();
+
     data_source.assert_url(StringFromEnvVar {
         from_env_var: None,
-        value: url.to_string(),
+        value: "postgres://prisma@hostfoo:5432/db".to_string(),
+        fallback: None,
+        template: Some("postgres://${INTERPOLATED_DB_USER}@${INTERPOLATED_DB_HOST}:5432/db".to_string()),
     });
 }
 
+#[test]
+#[serial]
+fn must_error_if_interpolated_env_var_is_missing() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://${INTERPOLATED_DB_HOST}:5432/db"
+        }
+    "#;
+    std::env::remove_var("INTERPOLATED_DB_HOST");
+
+    let errors = datamodel::parse_configuration(schema).err().unwrap();
+
+    errors.assert_is(DatamodelError::new_environment_functional_evaluation_error(
+        "INTERPOLATED_DB_HOST",
+        Span::new(89, 109),
+    ));
+}
+
+#[test]
+#[serial]
+fn must_error_if_interpolated_value_is_not_an_env_var() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://${5432}/db"
+        }
+    "#;
+
+    let errors = datamodel::parse_configuration(schema).err().unwrap();
+
+    errors.assert_is(DatamodelError::new_functional_evaluation_error(
+        "Only environment variables can be interpolated into a datasource URL, e.g. `${DATABASE_HOST}`.",
+        Span::new(89, 93),
+    ));
+}
+
+#[test]
+#[serial]
+fn must_succeed_with_overrides_for_interpolated_urls() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgres://${INTERPOLATED_DB_HOST}:5432/db"
+        }
+    "#;
+    std::env::remove_var("INTERPOLATED_DB_HOST");
+
+    let url = "postgres://hostbar";
+    let overrides = vec![("ds".to_string(), url.to_string())];
+    let config = datamodel::parse_configuration_with_url_overrides(schema, overrides).unwrap();
+    let data_source = config.datasources.first().unwrap();
+
+    data_source.assert_url(StringFromEnvVar::new_literal(url.to_string()));
+}
+
+#[test]
+#[serial]
+fn must_load_replica_urls() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+          replicaUrls = ["postgresql://replica1", env("REPLICA_DATABASE_URL")]
+        }
+    "#;
+    std::env::set_var("REPLICA_DATABASE_URL", "postgresql://replica2");
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let data_source = config.datasources.first().unwrap();
+
+    assert_eq!(
+        data_source.replica_urls,
+        vec![
+            StringFromEnvVar::new_literal("postgresql://replica1".to_string()),
+            StringFromEnvVar {
+                from_env_var: Some("REPLICA_DATABASE_URL".to_string()),
+                value: "postgresql://replica2".to_string(),
+                fallback: None,
+                template: None,
+            },
+        ]
+    );
+}
+
+#[test]
+#[serial]
+fn must_not_require_replica_urls() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+
+    assert!(config.datasources.first().unwrap().replica_urls.is_empty());
+}
+
+#[test]
+#[serial]
+fn must_error_if_wrong_protocol_is_used_for_a_replica() {
+    let schema = r#"
+        datasource ds {
+          provider = "postgresql"
+          url = "postgresql://primary"
+          replicaUrls = ["postgresql://replica1", "mysql://replica2"]
+        }
+    "#;
+
+    let errors = datamodel::parse_configuration(schema).err().unwrap();
+
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The URL for datasource `ds` must start with the protocol `postgresql://`.",
+        "ds",
+        Span::new(148, 166),
+    ));
+}
+
 #[test]
 #[serial]
 fn fail_to_load_sources_for_invalid_source() {
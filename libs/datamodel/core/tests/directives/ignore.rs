@@ -0,0 +1,120 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError};
+
+#[test]
+fn ignored_models_do_not_need_a_unique_criteria() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[] @ignore
+    }
+
+    model Post {
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let post = datamodel.assert_has_model("Post");
+    assert!(post.is_ignored);
+
+    let user = datamodel.assert_has_model("User");
+    assert!(!user.is_ignored);
+    assert!(user.assert_has_relation_field("posts").is_ignored);
+}
+
+#[test]
+fn ignored_fields_must_be_flagged() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        internal String @ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user = datamodel.assert_has_model("User");
+
+    assert!(user.assert_has_scalar_field("internal").is_ignored);
+    assert!(!user.assert_has_scalar_field("id").is_ignored);
+}
+
+#[test]
+fn ignore_must_be_rendered() {
+    let dml = r#"model User {
+  id       Int    @id
+  internal String @ignore
+}
+
+model Log {
+  message String
+
+  @@ignore
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn ignore_on_fields_of_ignored_models_must_error() {
+    let dml = r#"
+    model Log {
+        message String @ignore
+
+        @@ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Fields on an already ignored Model do not need an `@ignore` annotation.",
+        "ignore",
+        Span::new(41, 47),
+    ));
+}
+
+#[test]
+fn relation_fields_pointing_to_ignored_models_must_be_ignored() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        userId Int
+        user   User @relation(fields: [userId], references: [id])
+
+        @@ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `posts` in model `User`: The relation field `posts` on Model `User` must specify the `@ignore` attribute, because the model Post it is pointing to is marked ignored.",
+    );
+}
+
+#[test]
+fn ignore_on_id_fields_must_error() {
+    let dml = r#"
+    model User {
+        id Int @id @ignore
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `id` in model `User`: Fields that are part of the id of a model cannot be ignored. Use `@@ignore` on the model instead.",
+    );
+}
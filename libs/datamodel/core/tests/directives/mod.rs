@@ -3,6 +3,7 @@ pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
 pub mod id_positive;
+pub mod ignore;
 pub mod index;
 pub mod map;
 pub mod relations_basic;
@@ -15,5 +16,6 @@ pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod validations;
 
 pub mod arg_parsing;
@@ -171,9 +171,9 @@ fn should_fail_on_conflicting_back_relation_field_name() {
 
     let errors = parse_error(dml);
 
-    errors.assert_is(DatamodelError::new_model_validation_error(
-        "Automatic related field generation would cause a naming conflict. Please add an explicit opposite relation field.",
+    errors.assert_is(DatamodelError::new_missing_opposite_relation_field_error(
         "User",
+        "more_posts",
         Span::new(90, 108),
     ));
 }
@@ -471,11 +471,7 @@ fn must_error_when_both_arguments_are_missing_for_one_to_many() {
     let errors = parse_error(dml);
     errors.assert_is_at(
         0,
-        DatamodelError::new_directive_validation_error(
-            "The relation field `user` on Model `Post` must specify the `fields` argument in the @relation directive.",
-            "relation",
-            Span::new(172, 183),
-        ),
+        DatamodelError::new_missing_relation_fields_argument_error("Post", "user", Span::new(172, 183)),
     );
     errors.assert_is_at(1, DatamodelError::new_directive_validation_error(
         "The relation field `user` on Model `Post` must specify the `references` argument in the @relation directive.",
@@ -0,0 +1,144 @@
+use crate::common::*;
+use datamodel::dml::{FieldValidation, Pattern};
+
+#[test]
+fn validation_attributes_must_be_parsed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        name  String @length(min: 1, max: 20) @pattern("^[a-z]+$")
+        email String @length(max: 255)
+        age   Int    @range(min: 0, max: 150)
+        score Float  @range(min: 0.5)
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user = datamodel.assert_has_model("User");
+
+    assert_eq!(
+        user.assert_has_scalar_field("name").validations,
+        vec![
+            FieldValidation::Length {
+                min: Some(1),
+                max: Some(20)
+            },
+            FieldValidation::Pattern(Pattern::new("^[a-z]+$").unwrap()),
+        ]
+    );
+    assert_eq!(
+        user.assert_has_scalar_field("email").validations,
+        vec![FieldValidation::Length {
+            min: None,
+            max: Some(255)
+        }]
+    );
+    assert_eq!(
+        user.assert_has_scalar_field("age").validations,
+        vec![FieldValidation::Range {
+            min: Some(0.0),
+            max: Some(150.0)
+        }]
+    );
+    assert_eq!(
+        user.assert_has_scalar_field("score").validations,
+        vec![FieldValidation::Range {
+            min: Some(0.5),
+            max: None
+        }]
+    );
+    assert!(user.assert_has_scalar_field("id").validations.is_empty());
+}
+
+#[test]
+fn validation_attributes_must_be_rendered() {
+    let dml = r#"model User {
+  id   Int    @id
+  name String @length(min: 1, max: 20) @pattern("^[a-z]+$")
+  age  Int    @range(min: 0, max: 150)
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn length_on_non_string_fields_must_error() {
+    let dml = r#"
+    model User {
+        id  Int @id
+        age Int @length(max: 3)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@length\": The `@length` attribute can only be used on fields of type String.",
+    );
+}
+
+#[test]
+fn range_on_non_numeric_fields_must_error() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @range(min: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@range\": The `@range` attribute can only be used on fields of type Int, Float or Decimal.",
+    );
+}
+
+#[test]
+fn validation_attributes_without_bounds_must_error() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @length
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Error parsing attribute \"@length\": Either `min` or `max` must be specified.");
+}
+
+#[test]
+fn min_greater_than_max_must_error() {
+    let dml = r#"
+    model User {
+        id  Int @id
+        age Int @range(min: 10, max: 1)
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error parsing attribute \"@range\": The `min` value must not be greater than the `max` value.",
+    );
+}
+
+#[test]
+fn invalid_patterns_must_error() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @pattern("[a-z")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(1);
+    assert!(errors.errors[0]
+        .description()
+        .starts_with("Error parsing attribute \"@pattern\": The pattern is not a valid regular expression"));
+}
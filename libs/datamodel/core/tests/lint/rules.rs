@@ -0,0 +1,201 @@
+use crate::common::ErrorAsserts;
+use datamodel::lint::{LintDiagnostic, Severity};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn models_and_fields_must_follow_the_naming_conventions() {
+    let dml = r#"
+    model user_profile {
+        id         Int    @id
+        First_Name String
+    }
+    "#;
+
+    let diagnostics = lint(dml);
+
+    assert_eq!(rules(&diagnostics), vec!["modelNamePascalCase", "fieldNameCamelCase"]);
+    assert_eq!(
+        diagnostics[0].message,
+        "The model name `user_profile` is not in PascalCase. Consider renaming it to `UserProfile` and using `@@map` to keep the table name."
+    );
+    assert_eq!(&dml[diagnostics[0].span.start..diagnostics[0].span.end], "user_profile");
+    assert_eq!(
+        diagnostics[1].message,
+        "The field name `First_Name` is not in camelCase. Consider renaming it to `firstName` and using `@map` to keep the column name."
+    );
+    assert_eq!(diagnostics[1].severity, Severity::Warning);
+}
+
+#[test]
+fn relation_scalar_fields_must_be_indexed() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#;
+
+    let diagnostics = lint(dml);
+
+    assert_eq!(rules(&diagnostics), vec!["relationScalarIndex"]);
+    assert_eq!(
+        diagnostics[0].message,
+        "The relation scalar fields of `author` are not indexed. Add `@@index([authorId])` to the model `Post` to avoid full table scans."
+    );
+    assert!(dml[diagnostics[0].span.start..diagnostics[0].span.end].starts_with("author   User"));
+}
+
+#[test]
+fn indexed_relation_scalar_fields_must_not_be_reported() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+
+        @@index([authorId, id])
+    }
+    "#;
+
+    assert_eq!(lint(dml), vec![]);
+}
+
+#[test]
+fn floats_must_not_be_used_for_money() {
+    let dml = r#"
+    model Product {
+        id        Int   @id
+        unitPrice Float
+        weight    Float
+    }
+    "#;
+
+    let diagnostics = lint(dml);
+
+    assert_eq!(rules(&diagnostics), vec!["floatForMoney"]);
+    assert_eq!(
+        diagnostics[0].message,
+        "The field `unitPrice` looks like it stores money, but Float can not represent amounts exactly. Use Decimal instead."
+    );
+}
+
+#[test]
+fn only_whole_money_words_must_be_reported() {
+    let dml = r#"
+    model Product {
+        id         Int   @id
+        coffee     Float
+        costume    Float
+        USDPrice   Float
+        total_fees Float
+    }
+    "#;
+
+    let diagnostics = lint(dml);
+    let messages: Vec<&str> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.rule == "floatForMoney")
+        .map(|diagnostic| diagnostic.message.as_str())
+        .collect();
+
+    assert_eq!(messages.len(), 2);
+    assert!(messages[0].contains("`USDPrice`"));
+    assert!(messages[1].contains("`total_fees`"));
+}
+
+#[test]
+fn floats_for_money_must_not_be_reported_on_ignored_models() {
+    let dml = r#"
+    model Product {
+        id    Int   @id
+        price Float
+
+        @@ignore
+    }
+    "#;
+
+    assert_eq!(rules(&lint(dml)), Vec::<&str>::new());
+}
+
+#[test]
+fn severities_must_be_configurable_in_the_generator() {
+    let dml = r#"
+    generator client {
+        provider  = "prisma-client-js"
+        lintRules = ["floatForMoney:off", "modelNamePascalCase:error", "relationScalarIndex:off", "optionalRelationMap:warning"]
+    }
+
+    model product {
+        id       Int    @id
+        price    Float
+        authorId Int?
+        author   Author? @relation(fields: [authorId], references: [id])
+    }
+
+    model Author {
+        id       Int       @id
+        products product[]
+    }
+    "#;
+
+    let diagnostics = lint(dml);
+
+    assert_eq!(rules(&diagnostics), vec!["modelNamePascalCase", "optionalRelationMap"]);
+    assert_eq!(diagnostics[0].severity, Severity::Error);
+    assert_eq!(
+        diagnostics[1].message,
+        "The foreign key field `authorId` of the optional relation `author` does not name its column with `@map`."
+    );
+    assert_eq!(diagnostics[1].severity, Severity::Warning);
+}
+
+#[test]
+fn invalid_lint_rule_configurations_must_error() {
+    let dml = r#"
+    generator client {
+        provider  = "prisma-client-js"
+        lintRules = ["floatForMoney:never"]
+    }
+    "#;
+
+    let errors = datamodel::parse_configuration(dml).err().unwrap();
+
+    errors.assert_is_message(
+        "Error validating: Unknown lint severity `never`. Expected one of `off`, `warning` or `error`.",
+    );
+}
+
+#[test]
+fn unknown_lint_rules_must_error() {
+    let dml = r#"
+    generator client {
+        provider  = "prisma-client-js"
+        lintRules = ["floatForMony:error"]
+    }
+    "#;
+
+    let errors = datamodel::parse_configuration(dml).err().unwrap();
+
+    errors.assert_is_message(
+        "Error validating: Unknown lint rule `floatForMony`. Expected one of `modelNamePascalCase`, `fieldNameCamelCase`, `relationScalarIndex`, `optionalRelationMap`, `floatForMoney`.",
+    );
+}
+
+fn lint(dml: &str) -> Vec<LintDiagnostic> {
+    datamodel::lint_datamodel(dml).unwrap()
+}
+
+fn rules(diagnostics: &[LintDiagnostic]) -> Vec<&'static str> {
+    diagnostics.iter().map(|diagnostic| diagnostic.rule).collect()
+}
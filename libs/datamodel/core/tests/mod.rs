@@ -4,7 +4,9 @@ pub mod common;
 pub mod config;
 pub mod directives;
 pub mod functions;
+pub mod lint;
 pub mod parsing;
+pub mod quick_fix;
 pub mod reformat;
 pub mod render_to_dmmf;
 pub mod renderer;
@@ -1,2 +1,3 @@
 pub mod literals;
+pub mod multiple_files;
 pub mod nice_errors;
@@ -0,0 +1,176 @@
+use crate::common::*;
+use datamodel::{
+    ast::{SchemaFiles, Span},
+    error::DatamodelError,
+};
+
+fn files(contents: &[(&str, &str)]) -> SchemaFiles {
+    let mut files = SchemaFiles::new();
+
+    for (name, file_contents) in contents {
+        files.add(*name, *file_contents);
+    }
+
+    files
+}
+
+#[test]
+fn models_can_reference_models_in_other_files() {
+    let files = files(&[
+        (
+            "user.prisma",
+            r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+    "#,
+        ),
+        (
+            "post.prisma",
+            r#"
+    model Post {
+        id       Int  @id
+        authorId Int
+        author   User @relation(fields: [authorId], references: [id])
+    }
+    "#,
+        ),
+    ]);
+
+    let datamodel = datamodel::parse_datamodel_from_files(&files).unwrap();
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_relation_field("posts")
+        .assert_relation_to("Post");
+    datamodel
+        .assert_has_model("Post")
+        .assert_has_relation_field("author")
+        .assert_relation_to("User");
+}
+
+#[test]
+fn validation_errors_point_to_the_right_file() {
+    let second_file = r#"
+    model User {
+        id Int @id
+    }
+    "#;
+    let files = files(&[
+        (
+            "a.prisma",
+            r#"
+    model User {
+        id Int @id
+    }
+    "#,
+        ),
+        ("b.prisma", second_file),
+    ]);
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+    let name_start = second_file.find("User").unwrap();
+
+    errors.assert_is(
+        DatamodelError::new_duplicate_top_error("User", "model", "model", Span::new(name_start, name_start + 4))
+            .in_file(1),
+    );
+
+    let pretty_errors = errors.to_pretty_string_in_files(&files);
+
+    assert!(pretty_errors.contains("b.prisma:2"), "{}", pretty_errors);
+}
+
+#[test]
+fn parser_errors_point_to_the_right_file() {
+    let files = files(&[
+        (
+            "a.prisma",
+            r#"
+    model User {
+        id Int @id
+    }
+    "#,
+        ),
+        (
+            "b.prisma",
+            r#"
+    Post {
+        id Int @id
+    }
+    "#,
+        ),
+    ]);
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+
+    errors.assert_is(
+        DatamodelError::new_validation_error(
+            "This block is invalid. It does not start with any known Prisma schema keyword.",
+            Span::new(5, 36),
+        )
+        .in_file(1),
+    );
+}
+
+#[test]
+fn models_can_extend_abstract_models_in_other_files() {
+    let files = files(&[
+        (
+            "base.prisma",
+            r#"
+    abstract model Base {
+        id Int @id
+    }
+    "#,
+        ),
+        (
+            "user.prisma",
+            r#"
+    model User extends Base {
+        name String
+    }
+    "#,
+        ),
+    ]);
+
+    let datamodel = datamodel::parse_datamodel_from_files(&files).unwrap();
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_scalar_field("id")
+        .assert_is_id();
+}
+
+#[test]
+fn extends_errors_point_to_the_right_file() {
+    let second_file = r#"
+    model User extends Base {
+        id Int @id
+    }
+    "#;
+    let files = files(&[
+        (
+            "a.prisma",
+            r#"
+    model Post {
+        id Int @id
+    }
+    "#,
+        ),
+        ("b.prisma", second_file),
+    ]);
+
+    let errors = datamodel::parse_datamodel_from_files(&files).unwrap_err();
+    let parent_start = second_file.find("Base").unwrap();
+
+    errors.assert_is(
+        DatamodelError::new_model_validation_error(
+            "There is no abstract model called `Base` to extend.",
+            "User",
+            Span::new(parent_start, parent_start + 4),
+        )
+        .in_file(1),
+    );
+}
@@ -158,21 +158,6 @@ fn nice_error_broken_field_type_legacy_required() {
     ));
 }
 
-#[test]
-fn nice_error_legacy_model_decl() {
-    let dml = r#"
-    type User {
-        id Int @id
-    }"#;
-
-    let error = parse_error(dml);
-
-    error.assert_is(DatamodelError::new_legacy_parser_error(
-        "Model declarations have to be indicated with the `model` keyword.",
-        Span::new(5, 9),
-    ));
-}
-
 #[test]
 fn optional_list_fields_must_error() {
     let dml = r#"
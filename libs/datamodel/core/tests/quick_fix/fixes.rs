@@ -0,0 +1,219 @@
+use crate::common::parse_error;
+use datamodel::error::DatamodelError;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn misspelled_field_attributes_must_be_replaced() {
+    let dml = r#"
+    model User {
+        id        Int      @id
+        email     String   @uniqe
+        updatedAt DateTime @updatedat
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    let (title, fixed) = apply_only_fix(dml, &errors.errors[0]);
+    assert_eq!(title, "Replace with `@unique`");
+    assert!(fixed.contains("email     String   @unique\n"));
+
+    let (title, fixed) = apply_only_fix(dml, &errors.errors[1]);
+    assert_eq!(title, "Replace with `@updatedAt`");
+    assert!(fixed.contains("updatedAt DateTime @updatedAt\n"));
+}
+
+#[test]
+fn misspelled_block_attributes_must_be_replaced() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String
+
+        @@indx([email])
+    }
+    "#;
+
+    let (title, fixed) = apply_only_fix(dml, &parse_error(dml).errors[0]);
+
+    assert_eq!(title, "Replace with `@@index`");
+    assert!(fixed.contains("@@index([email])"));
+}
+
+#[test]
+fn unknown_attributes_unlike_any_known_attribute_must_not_have_fixes() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        email String @something
+    }
+    "#;
+
+    assert!(parse_error(dml).errors[0].quick_fixes(dml).is_empty());
+}
+
+#[test]
+fn default_values_must_be_quoted_for_string_fields() {
+    let dml = r#"
+    model User {
+        id   Int    @id
+        name String @default(3)
+    }
+    "#;
+
+    let (title, fixed) = apply_only_fix(dml, &parse_error(dml).errors[0]);
+
+    assert_eq!(title, "Quote the default value");
+    assert!(fixed.contains(r#"name String @default("3")"#));
+}
+
+#[test]
+fn quotes_around_numbers_and_enum_values_must_be_removed() {
+    let dml = r#"
+    model User {
+        id     Int    @id
+        count  Int    @default("3")
+        status Status @default("ACTIVE")
+    }
+
+    enum Status {
+        ACTIVE
+        INACTIVE
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    let (title, fixed) = apply_only_fix(dml, &errors.errors[0]);
+    assert_eq!(title, "Remove the quotes around the default value");
+    assert!(fixed.contains("count  Int    @default(3)\n"));
+
+    let (_, fixed) = apply_only_fix(dml, &errors.errors[1]);
+    assert!(fixed.contains("status Status @default(ACTIVE)\n"));
+}
+
+#[test]
+fn conflicting_back_relation_fields_must_be_added_explicitly() {
+    let dml = r#"
+    model User {
+        id Int @id
+        posts Post[] @relation(name: "test")
+        more_posts Post[]
+    }
+
+    model Post {
+        post_id Int @id
+        User User @relation(name: "test")
+    }
+    "#;
+
+    let (title, fixed) = apply_only_fix(dml, &parse_error(dml).errors[0]);
+
+    assert_eq!(title, "Add the opposite relation field `user` to the model `Post`");
+    assert_eq!(
+        fixed,
+        r#"
+    model User {
+        id Int @id
+        posts Post[] @relation(name: "test")
+        more_posts Post[]
+    }
+
+    model Post {
+        post_id Int @id
+        User User @relation(name: "test")
+        user User? @relation(fields: [userId], references: [id])
+        userId Int?
+    }
+    "#
+    );
+}
+
+#[test]
+fn missing_fields_arguments_must_be_added_with_their_scalar_field() {
+    // The arguments are only missing when they were put on the wrong side of the relation.
+    let dml = r#"
+    datasource pg {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id      Int    @id
+        postIds Int[]
+        posts   Post[] @relation(fields: [postIds], references: [id])
+    }
+
+    model Post {
+        id   Int  @id
+        user User
+    }
+    "#;
+
+    let (title, fixed) = apply_only_fix(dml, &missing_fields_argument_error(dml));
+
+    assert_eq!(title, "Add `fields: [userId]` to the relation field `user`");
+    assert!(fixed.ends_with(
+        r#"
+    model Post {
+        id   Int  @id
+        user User @relation(fields: [userId], references: [id])
+        userId Int
+    }
+    "#
+    ));
+}
+
+#[test]
+fn missing_fields_arguments_must_be_added_to_existing_relation_attributes() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id      Int    @id
+        email   String @unique
+        postIds Int[]
+        posts   Post[] @relation("posts", fields: [postIds], references: [id])
+    }
+
+    model Post {
+        id          Int     @id
+        authorEmail String?
+        author      User?   @relation("posts", references: [email])
+    }
+    "#;
+
+    let (title, fixed) = apply_only_fix(dml, &missing_fields_argument_error(dml));
+
+    assert_eq!(title, "Add `fields: [authorEmail]` to the relation field `author`");
+    assert!(fixed.contains(r#"author      User?   @relation("posts", references: [email], fields: [authorEmail])"#));
+    assert_eq!(fixed.matches("authorEmail String?").count(), 1);
+}
+
+/// Applies the only quick fix of the error, and returns its title and the fixed schema.
+fn apply_only_fix(dml: &str, error: &DatamodelError) -> (String, String) {
+    let mut fixes = error.quick_fixes(dml);
+    assert_eq!(fixes.len(), 1, "Expected exactly one quick fix for {:?}", error);
+
+    let fix = fixes.remove(0);
+    let mut edits = fix.edits;
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.span.start));
+
+    let mut fixed = dml.to_owned();
+    for edit in edits {
+        fixed.replace_range(edit.span.start..edit.span.end, &edit.new_text);
+    }
+
+    (fix.title, fixed)
+}
+
+fn missing_fields_argument_error(dml: &str) -> DatamodelError {
+    parse_error(dml)
+        .errors
+        .into_iter()
+        .find(|error| matches!(error, DatamodelError::MissingRelationFieldsArgumentError { .. }))
+        .expect("Expected an error for the missing `fields` argument")
+}
@@ -682,6 +682,60 @@ model Bl
     assert_reformat(input, expected);
 }
 
+#[test]
+fn unsupported_types_must_be_preserved() {
+    let input = r#"
+        model User {
+          id Int @id
+          search    Unsupported( "tsvector" )?
+          points Unsupported("point")[]
+        }
+    "#;
+
+    let expected = r#"model User {
+  id     Int                      @id
+  search Unsupported("tsvector")?
+  points Unsupported("point")[]
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
+#[test]
+fn abstract_models_and_extends_must_be_preserved() {
+    let input = r#"
+        abstract model Base {
+          id Int @id
+          createdAt   DateTime @default(now())
+        }
+
+        abstract   model Named {
+          name String
+        }
+
+        model User extends   Base,Named {
+          email String @unique
+        }
+    "#;
+
+    let expected = r#"abstract model Base {
+  id        Int      @id
+  createdAt DateTime @default(now())
+}
+
+abstract model Named {
+  name String
+}
+
+model User extends Base, Named {
+  email String @unique
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
@@ -211,3 +211,53 @@ datasource db {
         assert_eq!(rendered, input_with_preview);
     }
 }
+
+#[test]
+fn env_var_fallbacks_and_interpolations_roundtrip() {
+    let input_with_fallback = r#"datasource db {
+  provider = "postgresql"
+  url      = env("RENDERER_DATABASE_URL", "postgresql://localhost")
+}
+"#;
+
+    let input_with_interpolation = r#"datasource db {
+  provider = "postgresql"
+  url      = "postgresql://${RENDERER_DATABASE_HOST}:5432/db"
+}
+"#;
+
+    std::env::set_var("RENDERER_DATABASE_HOST", "localhost");
+
+    for input in &[input_with_fallback, input_with_interpolation] {
+        let config = datamodel::parse_configuration(input).unwrap();
+        let rendered = datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config).unwrap();
+        assert_eq!(&rendered, input);
+    }
+}
+
+#[test]
+fn replica_urls_roundtrip() {
+    let input = r#"datasource db {
+  provider    = "postgresql"
+  url         = env("RENDERER_DATABASE_URL", "postgresql://primary")
+  replicaUrls = ["postgresql://replica", env("RENDERER_REPLICA_URL", "postgresql://localhost")]
+}
+"#;
+
+    let config = datamodel::parse_configuration(input).unwrap();
+    let rendered = datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config).unwrap();
+    assert_eq!(rendered, input);
+}
+
+#[test]
+fn lint_rules_roundtrip() {
+    let input = r#"generator client {
+  provider  = "prisma-client-js"
+  lintRules = ["floatForMoney:off", "modelNamePascalCase:error"]
+}
+"#;
+
+    let config = datamodel::parse_configuration(input).unwrap();
+    let rendered = datamodel::render_datamodel_and_config_to_string(&Datamodel::new(), &config).unwrap();
+    assert_eq!(rendered, input);
+}
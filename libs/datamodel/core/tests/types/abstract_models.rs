@@ -0,0 +1,165 @@
+use crate::common::*;
+use datamodel::{ast::Span, dml, error::DatamodelError};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn models_must_inherit_the_fields_of_abstract_models() {
+    let dml = r#"
+    abstract model Base {
+        id        Int      @id @default(autoincrement())
+        createdAt DateTime @default(now())
+    }
+
+    abstract model Named {
+        name String
+    }
+
+    model User extends Base, Named {
+        email String @unique
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user = datamodel.assert_has_model("User");
+    let field_names: Vec<&str> = user.fields().map(|field| field.name()).collect();
+
+    assert_eq!(field_names, vec!["id", "createdAt", "name", "email"]);
+    assert_eq!(user.extends, vec!["Base", "Named"]);
+    user.assert_has_scalar_field("id")
+        .assert_base_type(&dml::ScalarType::Int)
+        .assert_is_id();
+    user.assert_has_scalar_field("createdAt")
+        .assert_base_type(&dml::ScalarType::DateTime);
+
+    assert!(datamodel.find_model("Base").is_none());
+    assert_eq!(datamodel.abstract_models.len(), 2);
+}
+
+#[test]
+fn abstract_models_must_render_with_the_models_extending_them() {
+    let dml = r#"abstract model Base {
+  id        Int      @id @default(autoincrement())
+  createdAt DateTime @default(now())
+}
+
+model User extends Base {
+  email String @unique
+}
+"#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert_eq!(rendered, dml);
+}
+
+#[test]
+fn errors_in_inherited_fields_must_point_to_the_abstract_model() {
+    let dml = r#"
+    abstract model Base {
+        id Int @id @default("one")
+    }
+
+    model User extends Base {
+        email String @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_length(1);
+    let span = errors.errors[0].span();
+    assert!(span.end < dml.find("model User").unwrap());
+}
+
+#[test]
+fn extending_an_unknown_model_must_error() {
+    let dml = r#"
+    model User extends Base {
+        id Int @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "There is no abstract model called `Base` to extend.",
+        "User",
+        Span::new(24, 28),
+    ));
+}
+
+#[test]
+fn extending_a_model_that_is_not_abstract_must_error() {
+    let dml = r#"
+    model Base {
+        id Int @id
+    }
+
+    model User extends Base {
+        email String @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating model \"User\": The model `Base` can not be extended, because it is not abstract.",
+    );
+}
+
+#[test]
+fn fields_clashing_with_inherited_fields_must_error() {
+    let dml = r#"
+    abstract model Base {
+        id Int @id
+    }
+
+    model User extends Base {
+        id String @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message("Field \"id\" is already defined on model \"User\".");
+}
+
+#[test]
+fn abstract_models_must_not_contain_relation_fields() {
+    let dml = r#"
+    abstract model Base {
+        id     Int  @id
+        author User
+    }
+
+    model User extends Base {
+        email String @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `author` in model `Base`: Abstract models can only contain scalar and enum fields.",
+    );
+}
+
+#[test]
+fn abstract_models_must_not_be_used_as_field_types() {
+    let dml = r#"
+    abstract model Base {
+        id Int @id
+    }
+
+    model User {
+        id   Int  @id
+        base Base
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating: The abstract model `Base` can not be used as a field type. Use `extends Base` on the model instead.",
+    );
+}
@@ -0,0 +1,166 @@
+use crate::common::*;
+use datamodel::dml;
+
+const POSTGRES_SOURCE: &str = r#"
+    datasource db {
+        provider = "postgres"
+        url = "postgresql://localhost:5432"
+    }
+"#;
+
+#[test]
+fn composite_types_must_be_stored_in_fields_of_models() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id      Int      @id
+        address Address
+        billing Address?
+    }}
+
+    type Address {{
+        street String
+        zip    String?
+        tags   String[]
+        geo    Point[]
+    }}
+
+    type Point {{
+        lat Float
+        lng Float
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let datamodel = parse(&dml);
+
+    datamodel.assert_has_model("Address").assert_is_embedded(true);
+    datamodel.assert_has_model("Point").assert_is_embedded(true);
+
+    let user = datamodel.assert_has_model("User").assert_is_embedded(false);
+
+    let address = user.assert_has_scalar_field("address");
+    assert_eq!(address.field_type, dml::FieldType::CompositeType("Address".to_owned()));
+    address.assert_arity(&dml::FieldArity::Required);
+
+    user.assert_has_scalar_field("billing")
+        .assert_arity(&dml::FieldArity::Optional);
+}
+
+#[test]
+fn composite_types_must_render_with_the_type_keyword() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id      Int     @id
+        address Address
+    }}
+
+    type Address {{
+        street String
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(&dml)).unwrap();
+
+    assert!(rendered.contains("type Address {"));
+    assert!(rendered.contains("address Address"));
+}
+
+#[test]
+fn lists_of_composite_types_must_error_on_models() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id        Int       @id
+        addresses Address[]
+    }}
+
+    type Address {{
+        street String
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message(
+        "Error validating field `addresses` in model `User`: Field `addresses` in model `User` can't be a list of the composite type `Address`. Lists of composite types are only supported inside other composite types.",
+    );
+}
+
+#[test]
+fn attributes_in_composite_types_must_error() {
+    let dml = format!(
+        r#"{}
+    model User {{
+        id      Int     @id
+        address Address
+    }}
+
+    type Address {{
+        street String @unique
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message(
+        "Error validating field `street` in model `Address`: The fields of composite types cannot have attributes.",
+    );
+}
+
+#[test]
+fn composite_types_containing_themselves_must_error() {
+    let dml = format!(
+        r#"{}
+    model Graph {{
+        id   Int  @id
+        root Node
+    }}
+
+    type Node {{
+        value Int
+        next  Node?
+    }}
+    "#,
+        POSTGRES_SOURCE
+    );
+
+    let errors = parse_error(&dml);
+
+    errors.assert_is_message(
+        "Error validating model \"Node\": Composite types cannot contain themselves. Recursive path was: Node -> Node.",
+    );
+}
+
+#[test]
+fn composite_types_must_error_without_json_support() {
+    let dml = r#"
+    datasource db {
+        provider = "sqlite"
+        url = "file:dev.db"
+    }
+
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `address` in model `User`: Field `address` in model `User` can't be of the composite type `Address`. Composite types are stored as JSON, and the current connector does not support the Json type.",
+    );
+}
@@ -1,2 +1,5 @@
+pub mod abstract_models;
+pub mod composite_types;
 pub mod negative;
 pub mod positive;
+pub mod unsupported_types;
@@ -0,0 +1,77 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn unsupported_types_must_keep_their_raw_type() {
+    let dml = r#"
+    model User {
+        id       Int                         @id
+        search   Unsupported("tsvector")
+        location Unsupported("point")?
+        prices   Unsupported("money")[]
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user = datamodel.assert_has_model("User");
+
+    let search = user.assert_has_scalar_field("search");
+    assert_eq!(search.field_type, dml::FieldType::Unsupported("tsvector".to_owned()));
+    search.assert_arity(&dml::FieldArity::Required);
+
+    let location = user.assert_has_scalar_field("location");
+    assert_eq!(location.field_type, dml::FieldType::Unsupported("point".to_owned()));
+    location.assert_arity(&dml::FieldArity::Optional);
+
+    let prices = user.assert_has_scalar_field("prices");
+    assert_eq!(prices.field_type, dml::FieldType::Unsupported("money".to_owned()));
+    prices.assert_arity(&dml::FieldArity::List);
+}
+
+#[test]
+fn unsupported_types_must_render_verbatim() {
+    let dml = r#"
+    model User {
+        id     Int                     @id
+        search Unsupported("tsvector")?
+    }
+    "#;
+
+    let rendered = datamodel::render_datamodel_to_string(&parse(dml)).unwrap();
+
+    assert!(rendered.contains(r#"search Unsupported("tsvector")?"#));
+}
+
+#[test]
+fn unsupported_types_must_not_be_part_of_the_id() {
+    let dml = r#"
+    model User {
+        email  String                  @unique
+        search Unsupported("tsvector") @id
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is_message(
+        "Error validating field `search` in model `User`: Field `search` in model `User` can't be part of the id, because its type is not supported by Prisma. Use `@@ignore` on the model instead.",
+    );
+}
+
+#[test]
+fn unsupported_types_may_be_part_of_the_id_of_ignored_models() {
+    let dml = r#"
+    model User {
+        search Unsupported("tsvector") @id
+
+        @@ignore
+    }
+    "#;
+
+    let datamodel = parse(dml);
+
+    datamodel
+        .assert_has_model("User")
+        .assert_has_scalar_field("search")
+        .assert_is_id();
+}
@@ -56,7 +56,15 @@ macro_rules! flags {
 // `connectOrCreate`: `connectOrCreate` nested query in the QE.
 // `insensitiveFilters`: Case insensitive scalar filters for supported DBs.
 // `atomicNumberOperations`: New and expanded number operations for updates.
-flags!(transaction, connectOrCreate, insensitiveFilters, atomicNumberOperations);
+// `findOneBatching`: Batching of concurrent `findOne` reads into a single query. Every lookup opening a batch
+//                    waits for the batch window, even if no other lookup joins it.
+flags!(
+    transaction,
+    connectOrCreate,
+    insensitiveFilters,
+    atomicNumberOperations,
+    findOneBatching
+);
 
 /// Initializes the feature flags with given flags.
 /// Noop if already initialized.
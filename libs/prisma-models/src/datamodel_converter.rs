@@ -55,6 +55,7 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
+            .filter(|model| !model.is_ignored)
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
@@ -70,6 +71,10 @@ impl<'a> DatamodelConverter<'a> {
     fn convert_fields(&self, model: &dml::Model) -> Vec<FieldTemplate> {
         model
             .fields()
+            .filter(|field| match field {
+                dml::Field::RelationField(rf) => !is_ignored_relation_field(self.datamodel, model, rf),
+                dml::Field::ScalarField(sf) => !sf.is_ignored && !sf.field_type.is_unsupported(),
+            })
             .map(|field| match field {
                 dml::Field::RelationField(rf) => {
                     let relation = self
@@ -103,6 +108,8 @@ impl<'a> DatamodelConverter<'a> {
                     db_name: sf.database_name.clone(),
                     arity: sf.arity,
                     default_value: sf.default_value.clone(),
+                    composite_type: sf.composite_type(),
+                    validations: sf.validations.clone(),
                 }),
             })
             .collect()
@@ -127,6 +134,16 @@ impl<'a> DatamodelConverter<'a> {
             .indices
             .iter()
             .filter(|i| i.fields.len() > 1) // @@unique for 1 field are transformed to is_unique instead
+            .filter(|i| {
+                let hidden_field = |f: &String| {
+                    model
+                        .find_scalar_field(f)
+                        .map(|sf| sf.is_ignored || sf.field_type.is_unsupported())
+                        .unwrap_or(false)
+                };
+
+                !i.fields.iter().any(hidden_field)
+            })
             .map(|i| IndexTemplate {
                 name: i.name.clone(),
                 fields: i.fields.clone(),
@@ -141,7 +158,10 @@ impl<'a> DatamodelConverter<'a> {
     pub fn calculate_relations(datamodel: &dml::Datamodel) -> Vec<TempRelationHolder> {
         let mut result = Vec::new();
         for model in datamodel.models() {
-            for field in model.relation_fields() {
+            for field in model
+                .relation_fields()
+                .filter(|field| !is_ignored_relation_field(datamodel, model, field))
+            {
                 let dml::RelationInfo {
                     to, to_fields, name, ..
                 } = &field.relation_info;
@@ -328,6 +348,22 @@ impl TempRelationHolder {
     }
 }
 
+/// Relations are not exposed by the query engine if one of their sides is ignored, through `@ignore`
+/// on the field or `@@ignore` on the model.
+fn is_ignored_relation_field(datamodel: &dml::Datamodel, model: &dml::Model, field: &dml::RelationField) -> bool {
+    let related_field_is_ignored = datamodel
+        .find_related_field(field)
+        .map(|related_field| related_field.is_ignored)
+        .unwrap_or(false);
+
+    let related_model_is_ignored = datamodel
+        .find_model(&field.relation_info.to)
+        .map(|related_model| related_model.is_ignored)
+        .unwrap_or(false);
+
+    model.is_ignored || field.is_ignored || related_field_is_ignored || related_model_is_ignored
+}
+
 trait DatamodelFieldExtensions {
     fn type_identifier(&self) -> TypeIdentifier;
     fn is_unique(&self, model: &dml::Model) -> bool;
@@ -335,6 +371,7 @@ trait DatamodelFieldExtensions {
     fn is_auto_generated_int_id(&self) -> bool;
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
+    fn composite_type(&self) -> Option<String>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
@@ -344,6 +381,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
+            dml::FieldType::CompositeType(_) => TypeIdentifier::Json,
             dml::FieldType::Base(scalar, _) => match scalar {
                 dml::ScalarType::Boolean => TypeIdentifier::Boolean,
                 dml::ScalarType::DateTime => TypeIdentifier::DateTime,
@@ -353,7 +391,7 @@ impl DatamodelFieldExtensions for dml::ScalarField {
                 dml::ScalarType::Json => TypeIdentifier::Json,
                 _ => todo!(),
             },
-            dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
+            dml::FieldType::Unsupported(_) => panic!("Fields of unsupported types are never converted"),
             dml::FieldType::NativeType(prisma_tpe, _native_type) => TypeIdentifier::from(*prisma_tpe),
         }
     }
@@ -408,6 +446,13 @@ impl DatamodelFieldExtensions for dml::ScalarField {
         }
     }
 
+    fn composite_type(&self) -> Option<String> {
+        match self.field_type {
+            dml::FieldType::CompositeType(ref name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue {
         InternalEnumValue {
             name: enum_value.name.clone(),
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use datamodel::{DefaultValue, FieldArity};
+use datamodel::{DefaultValue, FieldArity, FieldValidation};
 use once_cell::sync::OnceCell;
 use std::{
     fmt::Debug,
@@ -30,6 +30,8 @@ pub struct ScalarFieldTemplate {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    pub composite_type: Option<String>,
+    pub validations: Vec<FieldValidation>,
 }
 
 pub struct ScalarField {
@@ -45,6 +47,10 @@ pub struct ScalarField {
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
+    /// The name of the composite type stored in this field, if any. The value is stored as JSON.
+    pub composite_type: Option<String>,
+    /// Constraints on the written values (`@length`, `@pattern`, `@range`), checked before every write.
+    pub validations: Vec<FieldValidation>,
 
     pub model: ModelWeakRef,
     pub(crate) is_unique: bool,
@@ -66,6 +72,8 @@ impl Debug for ScalarField {
             .field("arity", &self.arity)
             .field("db_name", &self.db_name)
             .field("default_value", &self.default_value)
+            .field("composite_type", &self.composite_type)
+            .field("validations", &self.validations)
             .field("model", &"#ModelWeakRef#")
             .field("is_unique", &self.is_unique)
             .field("read_only", &self.read_only)
@@ -89,6 +97,7 @@ impl Hash for ScalarField {
         self.model().hash(state);
         self.arity.hash(state);
         self.db_name.hash(state);
+        self.composite_type.hash(state);
     }
 }
 
@@ -107,6 +116,8 @@ impl PartialEq for ScalarField {
             && self.model() == other.model()
             && self.arity == other.arity
             && self.db_name == other.db_name
+            && self.composite_type == other.composite_type
+            && self.validations == other.validations
     }
 }
 
@@ -140,6 +151,8 @@ impl ScalarFieldTemplate {
             arity: self.arity,
             db_name: self.db_name,
             default_value: self.default_value,
+            composite_type: self.composite_type,
+            validations: self.validations,
             model,
         };
 
@@ -202,6 +215,13 @@ impl ScalarField {
         (self.type_identifier.clone(), self.arity)
     }
 
+    /// The composite type stored in this field, if any.
+    pub fn composite_type(&self) -> Option<ModelRef> {
+        self.composite_type
+            .as_ref()
+            .and_then(|name| self.internal_data_model().find_model(name).ok())
+    }
+
     pub fn is_read_only(&self) -> bool {
         self.read_only.get_or_init(|| false).clone()
     }
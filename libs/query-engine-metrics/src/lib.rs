@@ -0,0 +1,118 @@
+//! Metrics of the query engine, exported in the Prometheus text format on the `/metrics` endpoint
+//! of the query engine server.
+//!
+//! The metrics are process-wide statics, so the crates recording them don't need to pass a registry
+//! around:
+//! - Record a value with e.g. `query_engine_metrics::REQUESTS.inc(&["findOne", "User"])`. Label values
+//!   are given in the order of the label names of the metric.
+//! - New metrics must be added to `render` to be exported.
+
+mod metric;
+
+pub use metric::{Counter, Gauge, GaugeGuard, Histogram, Metric};
+
+use once_cell::sync::Lazy;
+
+/// Upper bounds of the buckets of durations, in seconds.
+const DURATION_BUCKETS: &[f64] = &[0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Upper bounds of the buckets of query graph sizes.
+const NODE_COUNT_BUCKETS: &[f64] = &[1.0, 2.0, 4.0, 8.0, 16.0, 32.0, 64.0, 128.0];
+
+pub static REQUESTS: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "prisma_requests_total",
+        "Operations executed by the query engine, by operation and model.",
+        &["operation", "model"],
+    )
+});
+
+pub static REQUEST_DURATION: Lazy<Histogram> = Lazy::new(|| {
+    Histogram::new(
+        "prisma_request_duration_seconds",
+        "Time it took to execute operations, by operation and model.",
+        &["operation", "model"],
+        DURATION_BUCKETS,
+    )
+});
+
+pub static QUERY_GRAPH_NODES: Lazy<Histogram> = Lazy::new(|| {
+    Histogram::new(
+        "prisma_query_graph_nodes",
+        "Nodes of the query graphs built for operations, by operation and model.",
+        &["operation", "model"],
+        NODE_COUNT_BUCKETS,
+    )
+});
+
+pub static QUERY_CACHE_HITS: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "prisma_query_cache_hits_total",
+        "Operations answered from the query cache, by operation and model.",
+        &["operation", "model"],
+    )
+});
+
+pub static QUERY_CACHE_MISSES: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "prisma_query_cache_misses_total",
+        "Cacheable operations that had to be executed, by operation and model.",
+        &["operation", "model"],
+    )
+});
+
+pub static POOL_CONNECTIONS_BUSY: Lazy<Gauge> = Lazy::new(|| {
+    Gauge::new(
+        "prisma_pool_connections_busy",
+        "Connections currently checked out of the connection pool.",
+        &[],
+    )
+});
+
+pub static POOL_CHECKOUT_DURATION: Lazy<Histogram> = Lazy::new(|| {
+    Histogram::new(
+        "prisma_pool_checkout_duration_seconds",
+        "Time spent waiting for a connection from the connection pool.",
+        &[],
+        DURATION_BUCKETS,
+    )
+});
+
+pub static TRANSACTIONS: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "prisma_transactions_total",
+        "Finished transactions, by whether they were committed or rolled back.",
+        &["outcome"],
+    )
+});
+
+pub static ERRORS: Lazy<Counter> = Lazy::new(|| {
+    Counter::new(
+        "prisma_errors_total",
+        "Errors returned to clients, by user facing error code.",
+        &["code"],
+    )
+});
+
+/// Renders all metrics in the Prometheus text exposition format.
+pub fn render() -> String {
+    let metrics: [&dyn Metric; 9] = [
+        &*REQUESTS,
+        &*REQUEST_DURATION,
+        &*QUERY_GRAPH_NODES,
+        &*QUERY_CACHE_HITS,
+        &*QUERY_CACHE_MISSES,
+        &*POOL_CONNECTIONS_BUSY,
+        &*POOL_CHECKOUT_DURATION,
+        &*TRANSACTIONS,
+        &*ERRORS,
+    ];
+
+    let mut out = String::new();
+
+    for metric in metrics.iter() {
+        metric.render(&mut out);
+    }
+
+    out
+}
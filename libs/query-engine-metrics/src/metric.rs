@@ -0,0 +1,341 @@
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex};
+
+/// The values of the labels of a series, in the order of the label names of its metric.
+type LabelValues = Vec<String>;
+
+/// A metric that can be rendered in the Prometheus text exposition format.
+pub trait Metric: Send + Sync {
+    /// Appends the `HELP` and `TYPE` lines, and a line for each sample of the metric.
+    fn render(&self, out: &mut String);
+}
+
+/// A value that only ever goes up, like the number of handled requests.
+pub struct Counter {
+    name: &'static str,
+    help: &'static str,
+    label_names: &'static [&'static str],
+    values: Mutex<BTreeMap<LabelValues, u64>>,
+}
+
+impl Counter {
+    pub fn new(name: &'static str, help: &'static str, label_names: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            help,
+            label_names,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn inc(&self, label_values: &[&str]) {
+        self.inc_by(label_values, 1);
+    }
+
+    pub fn inc_by(&self, label_values: &[&str], value: u64) {
+        let mut values = self.values.lock().unwrap();
+        *values.entry(series(self.label_names, label_values)).or_insert(0) += value;
+    }
+
+    pub fn get(&self, label_values: &[&str]) -> u64 {
+        let values = self.values.lock().unwrap();
+        values
+            .get(&series(self.label_names, label_values))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+impl Metric for Counter {
+    fn render(&self, out: &mut String) {
+        render_header(out, self.name, self.help, "counter");
+
+        let values = self.values.lock().unwrap();
+
+        if values.is_empty() && self.label_names.is_empty() {
+            render_sample(out, self.name, &[], &[], None, 0);
+        }
+
+        for (label_values, value) in values.iter() {
+            render_sample(out, self.name, self.label_names, label_values, None, value);
+        }
+    }
+}
+
+/// A value that goes up and down, like the number of open connections.
+pub struct Gauge {
+    name: &'static str,
+    help: &'static str,
+    label_names: &'static [&'static str],
+    values: Mutex<BTreeMap<LabelValues, i64>>,
+}
+
+impl Gauge {
+    pub fn new(name: &'static str, help: &'static str, label_names: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            help,
+            label_names,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn set(&self, label_values: &[&str], value: i64) {
+        let mut values = self.values.lock().unwrap();
+        values.insert(series(self.label_names, label_values), value);
+    }
+
+    pub fn add(&self, label_values: &[&str], delta: i64) {
+        let mut values = self.values.lock().unwrap();
+        *values.entry(series(self.label_names, label_values)).or_insert(0) += delta;
+    }
+
+    pub fn get(&self, label_values: &[&str]) -> i64 {
+        let values = self.values.lock().unwrap();
+        values
+            .get(&series(self.label_names, label_values))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Increments the gauge until the returned guard is dropped, e.g. to count the resources in use.
+    pub fn track(&'static self, label_values: &[&str]) -> GaugeGuard {
+        self.add(label_values, 1);
+
+        GaugeGuard {
+            gauge: self,
+            label_values: label_values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+}
+
+impl Metric for Gauge {
+    fn render(&self, out: &mut String) {
+        render_header(out, self.name, self.help, "gauge");
+
+        let values = self.values.lock().unwrap();
+
+        if values.is_empty() && self.label_names.is_empty() {
+            render_sample(out, self.name, &[], &[], None, 0);
+        }
+
+        for (label_values, value) in values.iter() {
+            render_sample(out, self.name, self.label_names, label_values, None, value);
+        }
+    }
+}
+
+/// Decrements the gauge it was created by when dropped.
+pub struct GaugeGuard {
+    gauge: &'static Gauge,
+    label_values: LabelValues,
+}
+
+impl Drop for GaugeGuard {
+    fn drop(&mut self) {
+        let label_values: Vec<&str> = self.label_values.iter().map(String::as_str).collect();
+        self.gauge.add(&label_values, -1);
+    }
+}
+
+/// Counts observed values, like request durations, in buckets with fixed upper bounds.
+pub struct Histogram {
+    name: &'static str,
+    help: &'static str,
+    label_names: &'static [&'static str],
+    /// The upper bounds of the buckets, in ascending order. The `+Inf` bucket is implicit.
+    buckets: &'static [f64],
+    values: Mutex<BTreeMap<LabelValues, HistogramValue>>,
+}
+
+struct HistogramValue {
+    /// The number of observations less than or equal to the upper bound of each bucket.
+    bucket_counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    pub fn new(
+        name: &'static str,
+        help: &'static str,
+        label_names: &'static [&'static str],
+        buckets: &'static [f64],
+    ) -> Self {
+        Self {
+            name,
+            help,
+            label_names,
+            buckets,
+            values: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn observe(&self, label_values: &[&str], value: f64) {
+        let mut values = self.values.lock().unwrap();
+        let histogram = values
+            .entry(series(self.label_names, label_values))
+            .or_insert_with(|| HistogramValue {
+                bucket_counts: vec![0; self.buckets.len()],
+                sum: 0.0,
+                count: 0,
+            });
+
+        for (bucket_count, upper_bound) in histogram.bucket_counts.iter_mut().zip(self.buckets) {
+            if value <= *upper_bound {
+                *bucket_count += 1;
+            }
+        }
+
+        histogram.sum += value;
+        histogram.count += 1;
+    }
+
+    /// The number of observations.
+    pub fn count(&self, label_values: &[&str]) -> u64 {
+        let values = self.values.lock().unwrap();
+        values
+            .get(&series(self.label_names, label_values))
+            .map(|histogram| histogram.count)
+            .unwrap_or(0)
+    }
+}
+
+impl Metric for Histogram {
+    fn render(&self, out: &mut String) {
+        render_header(out, self.name, self.help, "histogram");
+
+        let values = self.values.lock().unwrap();
+        let bucket_name = format!("{}_bucket", self.name);
+        let sum_name = format!("{}_sum", self.name);
+        let count_name = format!("{}_count", self.name);
+
+        for (label_values, histogram) in values.iter() {
+            for (bucket_count, upper_bound) in histogram.bucket_counts.iter().zip(self.buckets) {
+                let le = upper_bound.to_string();
+                render_sample(
+                    out,
+                    &bucket_name,
+                    self.label_names,
+                    label_values,
+                    Some(&le),
+                    bucket_count,
+                );
+            }
+
+            render_sample(
+                out,
+                &bucket_name,
+                self.label_names,
+                label_values,
+                Some("+Inf"),
+                histogram.count,
+            );
+            render_sample(out, &sum_name, self.label_names, label_values, None, histogram.sum);
+            render_sample(out, &count_name, self.label_names, label_values, None, histogram.count);
+        }
+    }
+}
+
+fn series(label_names: &[&str], label_values: &[&str]) -> LabelValues {
+    debug_assert_eq!(
+        label_names.len(),
+        label_values.len(),
+        "Expected values for the labels {:?}",
+        label_names
+    );
+
+    label_values.iter().map(|value| value.to_string()).collect()
+}
+
+fn render_header(out: &mut String, name: &str, help: &str, metric_type: &str) {
+    writeln!(out, "# HELP {} {}", name, help).unwrap();
+    writeln!(out, "# TYPE {} {}", name, metric_type).unwrap();
+}
+
+fn render_sample(
+    out: &mut String,
+    name: &str,
+    label_names: &[&str],
+    label_values: &[String],
+    le: Option<&str>,
+    value: impl std::fmt::Display,
+) {
+    let mut labels: Vec<String> = label_names
+        .iter()
+        .zip(label_values)
+        .map(|(name, value)| format!("{}=\"{}\"", name, escape_label_value(value)))
+        .collect();
+
+    if let Some(le) = le {
+        labels.push(format!("le=\"{}\"", le));
+    }
+
+    if labels.is_empty() {
+        writeln!(out, "{} {}", name, value).unwrap();
+    } else {
+        writeln!(out, "{}{{{}}} {}", name, labels.join(","), value).unwrap();
+    }
+}
+
+fn escape_label_value(value: &str) -> String {
+    value.replace('\\', r"\\").replace('"', r#"\""#).replace('\n', r"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_must_render_a_sample_per_series() {
+        let counter = Counter::new("requests_total", "Requests.", &["operation", "model"]);
+        counter.inc(&["findOne", "User"]);
+        counter.inc(&["findOne", "User"]);
+        counter.inc(&["createOne", "Post \"draft\""]);
+
+        let mut out = String::new();
+        counter.render(&mut out);
+
+        assert_eq!(
+            out,
+            "# HELP requests_total Requests.\n\
+             # TYPE requests_total counter\n\
+             requests_total{operation=\"createOne\",model=\"Post \\\"draft\\\"\"} 1\n\
+             requests_total{operation=\"findOne\",model=\"User\"} 2\n"
+        );
+    }
+
+    #[test]
+    fn metrics_without_labels_must_render_zero_before_the_first_update() {
+        let gauge = Gauge::new("connections_busy", "Busy connections.", &[]);
+
+        let mut out = String::new();
+        gauge.render(&mut out);
+
+        assert_eq!(
+            out,
+            "# HELP connections_busy Busy connections.\n# TYPE connections_busy gauge\nconnections_busy 0\n"
+        );
+    }
+
+    #[test]
+    fn histograms_must_render_cumulative_buckets() {
+        let histogram = Histogram::new("duration_seconds", "Durations.", &[], &[0.25, 1.0]);
+        histogram.observe(&[], 0.25);
+        histogram.observe(&[], 0.5);
+        histogram.observe(&[], 2.0);
+
+        let mut out = String::new();
+        histogram.render(&mut out);
+
+        assert_eq!(
+            out,
+            "# HELP duration_seconds Durations.\n\
+             # TYPE duration_seconds histogram\n\
+             duration_seconds_bucket{le=\"0.25\"} 1\n\
+             duration_seconds_bucket{le=\"1\"} 2\n\
+             duration_seconds_bucket{le=\"+Inf\"} 3\n\
+             duration_seconds_sum 2.75\n\
+             duration_seconds_count 3\n"
+        );
+    }
+}
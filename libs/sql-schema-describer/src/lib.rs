@@ -356,6 +356,10 @@ impl ColumnArity {
     pub fn is_nullable(&self) -> bool {
         matches!(self, ColumnArity::Nullable)
     }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, ColumnArity::List)
+    }
 }
 
 /// Foreign key action types (for ON DELETE|ON UPDATE).
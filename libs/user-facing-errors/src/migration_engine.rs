@@ -44,6 +44,16 @@ pub struct MigrateSystemDatabase {
     pub database_name: String,
 }
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3005",
+    message = "Timed out trying to acquire the migration lock after ${timeout_seconds} seconds. Another migration is probably running against the `${database_name}` database. Please wait for it to finish and try again."
+)]
+pub struct MigrationLockTimeout {
+    pub database_name: String,
+    pub timeout_seconds: u64,
+}
+
 // Tests
 
 #[cfg(test)]
@@ -235,3 +235,30 @@ pub struct ValueOutOfRange {
 pub struct TableDoesNotExist {
     pub table: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2022",
+    message = "The value for the field `${field_name}` on `${model_name}` is not valid. ${details}"
+)]
+pub struct FieldValidationFailed {
+    pub field_name: String,
+    pub model_name: String,
+    pub details: String,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2023",
+    message = "The query did not finish within the timeout of ${timeout_milliseconds} ms and was cancelled."
+)]
+pub struct QueryTimeout {
+    pub timeout_milliseconds: u64,
+}
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2024",
+    message = "The query engine is shutting down. The query was not executed, or rolled back if it was running."
+)]
+pub struct ShuttingDown;
@@ -4,7 +4,8 @@ mod tests;
 
 use error::CliError;
 use futures::FutureExt;
-use migration_core::migration_api;
+use migration_core::{commands::RenderMigrationScriptInput, migration_api};
+use std::path::{Path, PathBuf};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -51,6 +52,11 @@ impl Cli {
                 qe_setup(&self.datasource).await?;
                 Ok(String::new())
             }
+            CliCommand::RenderMigrationScript { schema } => {
+                let script = render_migration_script(&self.datasource, &schema).await?;
+                println!("{}", script);
+                Ok(String::new())
+            }
         }
     }
 }
@@ -63,6 +69,12 @@ enum CliCommand {
     CanConnectToDatabase,
     /// Set up the database for connector-test-kit.
     QeSetup,
+    /// Render the migration to the given schema as an annotated SQL script, without applying it.
+    RenderMigrationScript {
+        /// Path to the prisma schema to migrate to
+        #[structopt(long, parse(from_os_str))]
+        schema: PathBuf,
+    },
 }
 
 async fn connect_to_database(database_str: &str) -> Result<String, CliError> {
@@ -86,6 +98,23 @@ async fn qe_setup(database_str: &str) -> Result<(), CliError> {
     Ok(())
 }
 
+async fn render_migration_script(database_str: &str, schema_path: &Path) -> Result<String, CliError> {
+    let schema = std::fs::read_to_string(schema_path).map_err(|err| {
+        CliError::Other(anyhow::anyhow!(
+            "Could not read the schema at {}: {}",
+            schema_path.display(),
+            err
+        ))
+    })?;
+    let datamodel = datasource_from_database_str(database_str)?;
+    let api = migration_api(&datamodel).await?;
+    let output = api
+        .render_migration_script(&RenderMigrationScriptInput { schema })
+        .await?;
+
+    Ok(output.script)
+}
+
 fn datasource_from_database_str(database_str: &str) -> Result<String, CliError> {
     let provider = match database_str.split(':').next() {
         Some("postgres") => "postgresql",
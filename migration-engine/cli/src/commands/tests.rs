@@ -158,3 +158,75 @@ async fn test_create_sqlite_database() {
 
     assert!(sqlite_path.exists());
 }
+
+#[tokio::test]
+async fn test_render_migration_script_on_sqlite() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let sqlite_path = base_dir.path().join("test_render_migration_script.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+
+    run(&["--datasource", &url, "create-database"]).await.unwrap();
+
+    let schema_path = base_dir.path().join("schema.prisma");
+    let schema = r#"
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    std::fs::write(&schema_path, schema).unwrap();
+
+    let script = super::render_migration_script(&url, &schema_path).await.unwrap();
+
+    assert!(script.contains("-- Locked tables (estimated): none"));
+    assert!(script.contains("-- Step 1: CreateTable"));
+    assert!(script.contains("CREATE TABLE"));
+    assert!(!script.contains("BEGIN;"));
+
+    // Nothing was applied.
+    let conn = Quaint::new(&url).await.unwrap();
+    let tables = conn
+        .query_raw(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name = 'Cat'",
+            &[],
+        )
+        .await
+        .unwrap();
+
+    assert!(tables.is_empty());
+}
+
+#[tokio::test]
+async fn test_render_migration_script_places_warnings_next_to_their_step() {
+    let base_dir = tempfile::tempdir().unwrap();
+    let sqlite_path = base_dir.path().join("test_render_migration_script_warnings.db");
+    let url = format!("file:{}", sqlite_path.to_string_lossy());
+
+    run(&["--datasource", &url, "create-database"]).await.unwrap();
+
+    let conn = Quaint::new(&url).await.unwrap();
+    conn.raw_cmd("CREATE TABLE Cat (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")
+        .await
+        .unwrap();
+    conn.raw_cmd("INSERT INTO Cat (id, name) VALUES (1, 'Felix')")
+        .await
+        .unwrap();
+
+    let schema_path = base_dir.path().join("schema.prisma");
+    let schema = r#"
+        model Dog {
+            id Int @id
+            name String
+        }
+    "#;
+
+    std::fs::write(&schema_path, schema).unwrap();
+
+    let script = super::render_migration_script(&url, &schema_path).await.unwrap();
+
+    assert!(script.contains(
+        "DropTable\n-- Locks: `Cat`\n-- WARNING: You are about to drop the `Cat` table, which is not empty (1 rows).\n"
+    ));
+    assert_eq!(script.matches("-- WARNING").count(), 1);
+}
@@ -11,6 +11,14 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
 
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<PrettyDatabaseMigrationStep>>;
+
+    /// Render the whole migration as a single SQL script for review, with the destructive change
+    /// diagnostics as comments next to the statements they concern.
+    fn render_script(
+        &self,
+        database_migration: &T,
+        diagnostics: &DestructiveChangeDiagnostics,
+    ) -> ConnectorResult<DatabaseMigrationScript>;
 }
 
 /// A helper struct to serialize a database migration with an additional `raw` field containing the
@@ -20,3 +28,14 @@ pub struct PrettyDatabaseMigrationStep {
     pub step: serde_json::Value,
     pub raw: String,
 }
+
+/// A database migration rendered as a single script, as returned by
+/// [render_script](trait.DatabaseMigrationStepApplier.html#tymethod.render_script).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DatabaseMigrationScript {
+    /// The annotated script.
+    pub script: String,
+    /// The existing tables that are expected to be locked while the migration runs.
+    pub locked_tables: Vec<String>,
+}
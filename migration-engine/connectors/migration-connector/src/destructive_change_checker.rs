@@ -27,6 +27,11 @@ pub struct DestructiveChangeDiagnostics {
     pub errors: Vec<MigrationError>,
     pub warnings: Vec<MigrationWarning>,
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
+    /// The index of the migration step each warning was emitted for, in the order of `warnings`.
+    pub warning_steps: Vec<Option<usize>>,
+    /// The index of the migration step each unexecutable migration was emitted for, in the order of
+    /// `unexecutable_migrations`.
+    pub unexecutable_migration_steps: Vec<Option<usize>>,
 }
 
 impl DestructiveChangeDiagnostics {
@@ -34,9 +39,24 @@ impl DestructiveChangeDiagnostics {
         Default::default()
     }
 
+    /// The index of the migration step the warning at `warning_index` was emitted for, if any.
+    pub fn warning_step(&self, warning_index: usize) -> Option<usize> {
+        self.warning_steps.get(warning_index).copied().flatten()
+    }
+
+    /// The index of the migration step the unexecutable migration at `unexecutable_index` was emitted
+    /// for, if any.
+    pub fn unexecutable_migration_step(&self, unexecutable_index: usize) -> Option<usize> {
+        self.unexecutable_migration_steps
+            .get(unexecutable_index)
+            .copied()
+            .flatten()
+    }
+
     pub fn add_warning<T: Into<Option<MigrationWarning>>>(&mut self, warning: T) {
         if let Some(warning) = warning.into() {
-            self.warnings.push(warning)
+            self.warnings.push(warning);
+            self.warning_steps.push(None);
         }
     }
 
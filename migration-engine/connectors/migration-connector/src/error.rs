@@ -1,7 +1,7 @@
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 use thiserror::Error;
 use tracing_error::SpanTrace;
-use user_facing_errors::KnownError;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, KnownError};
 
 #[derive(Debug, Error)]
 #[error("{}\n{}", kind, context)]
@@ -30,6 +30,19 @@ impl ConnectorError {
             context: SpanTrace::capture(),
         }
     }
+
+    pub fn migration_lock_timeout(database_name: &str, timeout: Duration) -> Self {
+        let user_facing = MigrationLockTimeout {
+            database_name: database_name.to_owned(),
+            timeout_seconds: timeout.as_secs(),
+        };
+
+        ConnectorError {
+            user_facing_error: KnownError::new(user_facing).ok(),
+            kind: ErrorKind::MigrationLockTimeout { timeout },
+            context: SpanTrace::capture(),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -71,6 +84,9 @@ pub enum ErrorKind {
     #[error("Operation timed out")]
     Timeout,
 
+    #[error("Timed out after {:?} waiting for the migration lock", timeout)]
+    MigrationLockTimeout { timeout: Duration },
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
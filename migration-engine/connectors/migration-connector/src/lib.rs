@@ -20,7 +20,7 @@ pub use migration_applier::*;
 pub use migration_persistence::*;
 pub use steps::MigrationStep;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 
 /// The top-level trait for connectors. This is the abstraction the migration engine core relies on to
 /// interface with different database backends.
@@ -45,6 +45,27 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Acquire the database-level lock that prevents concurrent migrations, waiting at most
+    /// `timeout` for another migration engine instance to release it. Connectors without a locking
+    /// mechanism can rely on the default no-op implementation.
+    async fn acquire_lock(&self, _timeout: Duration) -> ConnectorResult<()> {
+        Ok(())
+    }
+
+    /// Release the lock taken with [acquire_lock](trait.MigrationConnector.html#method.acquire_lock).
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        Ok(())
+    }
+
+    /// Introspect the current database schema into a datamodel, ignoring the migrations table.
+    /// This is used to baseline databases that were not created by the migration engine.
+    async fn introspect_datamodel(&self) -> ConnectorResult<datamodel::Datamodel> {
+        Err(ConnectorError::from_kind(ErrorKind::Generic(anyhow::anyhow!(
+            "The {} connector cannot introspect the database.",
+            self.connector_type()
+        ))))
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -32,6 +32,15 @@ pub enum MigrationStep {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateModel {
     pub model: String,
+    /// Whether the model is a composite type, declared with the `type` keyword.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub embedded: bool,
+    /// Whether the model is abstract, declared with the `abstract` keyword.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub r#abstract: bool,
+    /// The abstract models the model extends.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extends: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
@@ -41,11 +50,17 @@ pub struct UpdateModel {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub new_name: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#abstract: Option<bool>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extends: Option<Vec<String>>,
 }
 
 impl UpdateModel {
     pub fn is_any_option_set(&self) -> bool {
-        self.new_name.is_some()
+        self.new_name.is_some() || self.r#abstract.is_some() || self.extends.is_some()
     }
 }
 
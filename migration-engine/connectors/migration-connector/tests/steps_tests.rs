@@ -7,6 +7,33 @@ fn full_CreateModel_must_work() {
     let json = r#"{"tag":"CreateModel","model":"Blog"}"#;
     let expected_struct = MigrationStep::CreateModel(CreateModel {
         model: "Blog".to_string(),
+        embedded: false,
+        r#abstract: false,
+        extends: vec![],
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn abstract_CreateModel_must_work() {
+    let json = r#"{"tag":"CreateModel","model":"Timestamps","abstract":true}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        model: "Timestamps".to_string(),
+        embedded: false,
+        r#abstract: true,
+        extends: vec![],
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn extending_CreateModel_must_work() {
+    let json = r#"{"tag":"CreateModel","model":"Blog","extends":["Timestamps"]}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        model: "Blog".to_string(),
+        embedded: false,
+        r#abstract: false,
+        extends: vec!["Timestamps".to_string()],
     });
     assert_symmetric_serde(json, expected_struct);
 }
@@ -17,16 +44,20 @@ fn minimal_UpdateModel_must_work() {
     let expected_struct = MigrationStep::UpdateModel(UpdateModel {
         model: "Blog".to_string(),
         new_name: None,
+        r#abstract: None,
+        extends: None,
     });
     assert_symmetric_serde(json, expected_struct);
 }
 
 #[test]
 fn full_UpdateModel_must_work() {
-    let json = r#"{"tag":"UpdateModel","model":"Blog","newName":"MyBlog"}"#;
+    let json = r#"{"tag":"UpdateModel","model":"Blog","newName":"MyBlog","abstract":false,"extends":["Timestamps"]}"#;
     let expected_struct = MigrationStep::UpdateModel(UpdateModel {
         model: "Blog".to_string(),
         new_name: Some("MyBlog".to_string()),
+        r#abstract: Some(false),
+        extends: Some(vec!["Timestamps".to_string()]),
     });
     assert_symmetric_serde(json, expected_struct);
 }
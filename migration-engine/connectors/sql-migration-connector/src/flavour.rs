@@ -12,23 +12,43 @@ use crate::{
     sql_schema_differ::SqlSchemaDifferFlavour,
     SqlError, SqlResult,
 };
-use futures::future::TryFutureExt;
+use futures::future::{Future, TryFutureExt};
 use migration_connector::{ConnectorError, ConnectorResult};
 use once_cell::sync::Lazy;
 use quaint::{
+    ast::Value,
     connector::{ConnectionInfo, MysqlUrl, PostgresUrl, Queryable},
     prelude::SqlFamily,
     single::Quaint,
 };
 use regex::RegexSet;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
-use std::{collections::HashMap, fmt::Debug, path::Path, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    path::Path,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use url::Url;
 
-pub(crate) fn from_connection_info(connection_info: &ConnectionInfo) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
+/// The key of the PostgreSQL advisory lock held during migrations.
+const POSTGRES_MIGRATION_LOCK_KEY: i64 = 72_707_369;
+
+/// How long to wait between two attempts at acquiring the migration lock on databases that do not
+/// offer a blocking lock with a timeout.
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+pub(crate) fn from_connection_info(
+    connection_info: &ConnectionInfo,
+    online_migrations: bool,
+) -> Box<dyn SqlFlavour + Send + Sync + 'static> {
     match connection_info {
         ConnectionInfo::Mysql(url) => Box::new(MysqlFlavour(url.clone())),
-        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour(url.clone())),
+        ConnectionInfo::Postgres(url) => Box::new(PostgresFlavour {
+            url: url.clone(),
+            online_migrations,
+        }),
         ConnectionInfo::Sqlite { file_path, db_name } => Box::new(SqliteFlavour {
             file_path: file_path.clone(),
             attached_name: db_name.clone(),
@@ -63,6 +83,25 @@ pub(crate) trait SqlFlavour:
         schema_name: &'a str,
         conn: Arc<dyn Queryable + Send + Sync>,
     ) -> SqlResult<SqlSchema>;
+
+    /// Acquire the lock preventing concurrent migrations on the connection, waiting at most
+    /// `timeout`. Returns `false` if the lock could not be obtained in time.
+    async fn acquire_lock(&self, conn: &dyn Queryable, timeout: Duration) -> SqlResult<bool>;
+
+    /// Release the lock taken with `acquire_lock`.
+    async fn release_lock(&self, conn: &dyn Queryable) -> SqlResult<()>;
+
+    /// Whether a rendered migration script can be wrapped in a single transaction. MySQL commits
+    /// implicitly after each DDL statement, and SQLite ignores the foreign key pragmas we need
+    /// inside of transactions.
+    fn supports_transactional_ddl(&self) -> bool {
+        false
+    }
+
+    /// Whether creating or dropping an index locks the table against writes.
+    fn index_changes_lock_table(&self) -> bool {
+        true
+    }
 }
 
 #[derive(Debug)]
@@ -72,6 +111,11 @@ impl MysqlFlavour {
     pub(crate) fn schema_name(&self) -> &str {
         self.0.dbname()
     }
+
+    /// `GET_LOCK` locks are server-wide, so the name is scoped to the database.
+    fn migration_lock_name(&self) -> String {
+        format!("prisma_migrate_{}", self.0.dbname())
+    }
 }
 
 #[async_trait::async_trait]
@@ -142,6 +186,35 @@ impl SqlFlavour for MysqlFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, conn: &dyn Queryable, timeout: Duration) -> SqlResult<bool> {
+        let timeout_seconds = timeout.as_secs() as i64;
+        let result_set = conn
+            .query_raw(
+                "SELECT GET_LOCK(?, ?)",
+                &[self.migration_lock_name().into(), Value::integer(timeout_seconds)],
+            )
+            .await?;
+
+        // GET_LOCK returns 1 on success, 0 on timeout and NULL on error.
+        match result_set
+            .first()
+            .and_then(|row| row.at(0).and_then(|value| value.as_i64()))
+        {
+            Some(1) => Ok(true),
+            Some(_) => Ok(false),
+            None => Err(SqlError::Generic(anyhow::anyhow!(
+                "GET_LOCK failed to acquire the migration lock."
+            ))),
+        }
+    }
+
+    async fn release_lock(&self, conn: &dyn Queryable) -> SqlResult<()> {
+        conn.query_raw("SELECT RELEASE_LOCK(?)", &[self.migration_lock_name().into()])
+            .await?;
+
+        Ok(())
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Mysql
     }
@@ -198,17 +271,57 @@ impl SqlFlavour for SqliteFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, conn: &dyn Queryable, timeout: Duration) -> SqlResult<bool> {
+        // In exclusive locking mode, the lock taken by the exclusive transaction is kept after it
+        // ends, until the locking mode is set back to normal. This lets the migration steps run
+        // outside of a transaction (they need to toggle foreign keys) while holding the lock.
+        conn.raw_cmd("PRAGMA locking_mode = EXCLUSIVE").await?;
+
+        let acquired = poll_lock(timeout, move || async move {
+            match conn.raw_cmd("BEGIN EXCLUSIVE").await {
+                Ok(()) => {
+                    conn.raw_cmd("COMMIT").await?;
+                    Ok(true)
+                }
+                Err(err) if is_sqlite_locked_error(&err) => Ok(false),
+                Err(err) => Err(err.into()),
+            }
+        })
+        .await?;
+
+        if !acquired {
+            conn.raw_cmd("PRAGMA locking_mode = NORMAL").await?;
+        }
+
+        Ok(acquired)
+    }
+
+    async fn release_lock(&self, conn: &dyn Queryable) -> SqlResult<()> {
+        conn.raw_cmd("PRAGMA locking_mode = NORMAL").await?;
+
+        // The lock is only released the next time the database file is accessed.
+        let sql = format!("SELECT COUNT(*) FROM \"{}\".sqlite_master", self.attached_name);
+        conn.query_raw(&sql, &[]).await?;
+
+        Ok(())
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Sqlite
     }
 }
 
 #[derive(Debug)]
-pub(crate) struct PostgresFlavour(pub(crate) PostgresUrl);
+pub(crate) struct PostgresFlavour {
+    pub(crate) url: PostgresUrl,
+    /// Render migrations that avoid holding long locks on existing tables. See the
+    /// `onlineMigrations` datasource preview feature.
+    pub(crate) online_migrations: bool,
+}
 
 impl PostgresFlavour {
     pub(crate) fn schema_name(&self) -> &str {
-        self.0.schema()
+        self.url.schema()
     }
 }
 
@@ -216,7 +329,7 @@ impl PostgresFlavour {
 impl SqlFlavour for PostgresFlavour {
     async fn create_database(&self, database_str: &str) -> ConnectorResult<String> {
         let mut url = Url::parse(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let db_name = self.0.dbname();
+        let db_name = self.url.dbname();
 
         strip_schema_param_from_url(&mut url);
 
@@ -268,8 +381,8 @@ impl SqlFlavour for PostgresFlavour {
 
         strip_schema_param_from_url(&mut url);
         let (conn, _) = create_postgres_admin_conn(url.clone()).await?;
-        let schema = self.0.schema();
-        let db_name = self.0.dbname();
+        let schema = self.url.schema();
+        let db_name = self.url.dbname();
 
         let query = format!("CREATE DATABASE \"{}\"", db_name);
         catch(conn.connection_info(), conn.raw_cmd(&query).map_err(SqlError::from))
@@ -294,9 +407,80 @@ impl SqlFlavour for PostgresFlavour {
         Ok(())
     }
 
+    async fn acquire_lock(&self, conn: &dyn Queryable, timeout: Duration) -> SqlResult<bool> {
+        // pg_advisory_lock() would block without a timeout, so we poll the non-blocking variant.
+        poll_lock(timeout, move || async move {
+            let result_set = conn
+                .query_raw(
+                    "SELECT pg_try_advisory_lock($1)",
+                    &[Value::integer(POSTGRES_MIGRATION_LOCK_KEY)],
+                )
+                .await?;
+
+            Ok(result_set
+                .first()
+                .and_then(|row| row.at(0).and_then(|value| value.as_bool()))
+                .unwrap_or(false))
+        })
+        .await
+    }
+
+    async fn release_lock(&self, conn: &dyn Queryable) -> SqlResult<()> {
+        conn.query_raw(
+            "SELECT pg_advisory_unlock($1)",
+            &[Value::integer(POSTGRES_MIGRATION_LOCK_KEY)],
+        )
+        .await?;
+
+        Ok(())
+    }
+
     fn sql_family(&self) -> SqlFamily {
         SqlFamily::Postgres
     }
+
+    fn supports_transactional_ddl(&self) -> bool {
+        // CREATE INDEX CONCURRENTLY cannot run inside a transaction block.
+        !self.online_migrations
+    }
+
+    fn index_changes_lock_table(&self) -> bool {
+        !self.online_migrations
+    }
+}
+
+/// Repeatedly call `try_lock` until it returns `true` or `timeout` has elapsed.
+async fn poll_lock<F, Fut>(timeout: Duration, try_lock: F) -> SqlResult<bool>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = SqlResult<bool>>,
+{
+    let start = Instant::now();
+
+    loop {
+        if try_lock().await? {
+            return Ok(true);
+        }
+
+        if start.elapsed() >= timeout {
+            return Ok(false);
+        }
+
+        tokio::time::delay_for(LOCK_POLL_INTERVAL).await;
+    }
+}
+
+/// Whether the SQLite error means the database is locked by another connection, i.e. its primary result
+/// code is `SQLITE_BUSY` or `SQLITE_LOCKED`.
+fn is_sqlite_locked_error(err: &quaint::error::Error) -> bool {
+    const SQLITE_BUSY: i32 = 5;
+    const SQLITE_LOCKED: i32 = 6;
+
+    // Extended result codes keep the primary result code in their lowest byte.
+    match err.original_code().and_then(|code| code.parse::<i32>().ok()) {
+        Some(code) => code & 0xff == SQLITE_BUSY || code & 0xff == SQLITE_LOCKED,
+        None => false,
+    }
 }
 
 fn strip_schema_param_from_url(url: &mut Url) {
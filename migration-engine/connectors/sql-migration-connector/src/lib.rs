@@ -46,8 +46,15 @@ pub struct SqlMigrationConnector {
 
 impl SqlMigrationConnector {
     pub async fn new(database_str: &str) -> ConnectorResult<Self> {
+        Self::new_with_online_migrations(database_str, false).await
+    }
+
+    /// Construct a connector that renders online migrations when `online_migrations` is true:
+    /// on PostgreSQL, indexes are created concurrently, foreign keys are validated in a separate
+    /// step and required columns are added, backfilled and only then made required.
+    pub async fn new_with_online_migrations(database_str: &str, online_migrations: bool) -> ConnectorResult<Self> {
         let (connection, database_info) = connect(database_str).await?;
-        let flavour = flavour::from_connection_info(database_info.connection_info());
+        let flavour = flavour::from_connection_info(database_info.connection_info(), online_migrations);
         flavour.check_database_info(&database_info)?;
 
         Ok(Self {
@@ -60,7 +67,7 @@ impl SqlMigrationConnector {
     pub async fn create_database(database_str: &str) -> ConnectorResult<String> {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, false);
         flavour.create_database(database_str).await
     }
 
@@ -68,7 +75,7 @@ impl SqlMigrationConnector {
         let connection_info =
             ConnectionInfo::from_url(database_str).map_err(|err| ConnectorError::url_parse_error(err, database_str))?;
 
-        let flavour = flavour::from_connection_info(&connection_info);
+        let flavour = flavour::from_connection_info(&connection_info, false);
 
         flavour.qe_setup(database_str).await
     }
@@ -103,6 +110,36 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn acquire_lock(&self, timeout: Duration) -> ConnectorResult<()> {
+        let acquired = catch(
+            self.connection_info(),
+            self.flavour.acquire_lock(self.conn(), timeout),
+        )
+        .await?;
+
+        if !acquired {
+            return Err(ConnectorError::migration_lock_timeout(self.schema_name(), timeout));
+        }
+
+        Ok(())
+    }
+
+    async fn release_lock(&self) -> ConnectorResult<()> {
+        catch(self.connection_info(), self.flavour.release_lock(self.conn())).await
+    }
+
+    async fn introspect_datamodel(&self) -> ConnectorResult<datamodel::Datamodel> {
+        let sql_schema = catch(self.connection_info(), self.describe_schema()).await?;
+        let introspection_result = sql_introspection_connector::calculate_datamodel::calculate_datamodel(
+            &sql_schema,
+            &self.database_info.sql_family(),
+            &datamodel::Datamodel::new(),
+        )
+        .map_err(|err| ConnectorError::from_kind(ErrorKind::Generic(err.into())))?;
+
+        Ok(introspection_result.data_model)
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(&self, datamodel: &datamodel::dml::Datamodel) -> Vec<MigrationError> {
@@ -4,7 +4,10 @@ use crate::{
     sql_schema_differ::SqlSchemaDiffer,
     Component, SqlError, SqlFlavour, SqlResult,
 };
-use migration_connector::{ConnectorError, ConnectorResult, DatabaseMigrationStepApplier, PrettyDatabaseMigrationStep};
+use migration_connector::{
+    ConnectorError, ConnectorResult, DatabaseMigrationScript, DatabaseMigrationStepApplier,
+    DestructiveChangeDiagnostics, PrettyDatabaseMigrationStep,
+};
 use sql_schema_describer::{walkers::SqlSchemaExt, SqlSchema};
 use tracing_futures::Instrument;
 
@@ -46,6 +49,14 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
             &database_migration.after,
         )
     }
+
+    fn render_script(
+        &self,
+        database_migration: &SqlMigration,
+        diagnostics: &DestructiveChangeDiagnostics,
+    ) -> ConnectorResult<DatabaseMigrationScript> {
+        render_script(database_migration, self.flavour(), self.database_info(), diagnostics)
+    }
 }
 
 impl SqlDatabaseStepApplier<'_> {
@@ -103,6 +114,195 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+fn render_script(
+    database_migration: &SqlMigration,
+    renderer: &(dyn SqlFlavour + Send + Sync),
+    database_info: &DatabaseInfo,
+    diagnostics: &DestructiveChangeDiagnostics,
+) -> ConnectorResult<DatabaseMigrationScript> {
+    // Diagnostics go next to the step they were emitted for. Errors and diagnostics without a
+    // rendered step go in the header.
+    let mut annotations: Vec<(&str, &str, Option<usize>)> = diagnostics
+        .errors
+        .iter()
+        .map(|error| ("ERROR", error.description.as_str(), None))
+        .chain(
+            diagnostics
+                .unexecutable_migrations
+                .iter()
+                .enumerate()
+                .map(|(idx, unexecutable)| {
+                    (
+                        "UNEXECUTABLE",
+                        unexecutable.description.as_str(),
+                        diagnostics.unexecutable_migration_step(idx),
+                    )
+                }),
+        )
+        .chain(
+            diagnostics
+                .warnings
+                .iter()
+                .enumerate()
+                .map(|(idx, warning)| ("WARNING", warning.description.as_str(), diagnostics.warning_step(idx))),
+        )
+        .collect();
+    let mut locked_tables: Vec<String> = Vec::new();
+    let mut body = String::new();
+
+    for (idx, step) in database_migration.steps.iter().enumerate() {
+        let statements = render_raw_sql(
+            step,
+            renderer,
+            database_info,
+            &database_migration.before,
+            &database_migration.after,
+        )
+        .map_err(|err: anyhow::Error| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?;
+
+        if statements.is_empty() {
+            continue;
+        }
+
+        let step_locked_tables = step_locked_tables(step, renderer);
+
+        body.push_str(&format!("-- Step {}: {}\n", idx + 1, step_name(step)));
+
+        if !step_locked_tables.is_empty() {
+            body.push_str(&format!("-- Locks: {}\n", quote_names(&step_locked_tables)));
+        }
+
+        annotations.retain(|(level, description, step_index)| {
+            let concerns_step = *step_index == Some(idx);
+
+            if concerns_step {
+                body.push_str(&render_annotation(level, description));
+            }
+
+            !concerns_step
+        });
+
+        for statement in statements {
+            body.push_str(&statement);
+            body.push_str(";\n");
+        }
+
+        body.push('\n');
+
+        for table in step_locked_tables {
+            if !locked_tables.iter().any(|locked| locked == table) {
+                locked_tables.push(table.to_owned());
+            }
+        }
+    }
+
+    let mut script = String::from("-- This script was rendered by the migration engine and has not been applied.\n");
+
+    if locked_tables.is_empty() {
+        script.push_str("-- Locked tables (estimated): none\n");
+    } else {
+        script.push_str(&format!(
+            "-- Locked tables (estimated): {}\n",
+            quote_names(&locked_tables)
+        ));
+    }
+
+    for (level, description, _) in annotations {
+        script.push_str(&render_annotation(level, description));
+    }
+
+    script.push('\n');
+
+    if renderer.supports_transactional_ddl() {
+        script.push_str("BEGIN;\n\n");
+        script.push_str(&body);
+        script.push_str("COMMIT;\n");
+    } else {
+        script.push_str("-- The statements below are not run in a transaction on this database.\n\n");
+        script.push_str(&body);
+    }
+
+    Ok(DatabaseMigrationScript { script, locked_tables })
+}
+
+fn render_annotation(level: &str, description: &str) -> String {
+    description
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| match idx {
+            0 => format!("-- {}: {}\n", level, line),
+            _ => format!("--   {}\n", line),
+        })
+        .collect()
+}
+
+fn quote_names<S: AsRef<str>>(names: &[S]) -> String {
+    names
+        .iter()
+        .map(|name| format!("`{}`", name.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn step_name(step: &SqlMigrationStep) -> &'static str {
+    match step {
+        SqlMigrationStep::AddForeignKey(_) => "AddForeignKey",
+        SqlMigrationStep::CreateTable(_) => "CreateTable",
+        SqlMigrationStep::AlterTable(_) => "AlterTable",
+        SqlMigrationStep::DropForeignKey(_) => "DropForeignKey",
+        SqlMigrationStep::DropTable(_) => "DropTable",
+        SqlMigrationStep::RenameTable { .. } => "RenameTable",
+        SqlMigrationStep::RedefineTables { .. } => "RedefineTables",
+        SqlMigrationStep::CreateIndex(_) => "CreateIndex",
+        SqlMigrationStep::DropIndex(_) => "DropIndex",
+        SqlMigrationStep::AlterIndex(_) => "AlterIndex",
+        SqlMigrationStep::CreateEnum(_) => "CreateEnum",
+        SqlMigrationStep::DropEnum(_) => "DropEnum",
+        SqlMigrationStep::AlterEnum(_) => "AlterEnum",
+    }
+}
+
+/// The names of the tables and enums a step touches.
+fn step_objects(step: &SqlMigrationStep) -> Vec<&str> {
+    match step {
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => vec![
+            add_foreign_key.table.as_str(),
+            add_foreign_key.foreign_key.referenced_table.as_str(),
+        ],
+        SqlMigrationStep::CreateTable(CreateTable { table }) => vec![table.name.as_str()],
+        SqlMigrationStep::AlterTable(alter_table) => vec![alter_table.table.name.as_str()],
+        SqlMigrationStep::DropForeignKey(drop_foreign_key) => vec![drop_foreign_key.table.as_str()],
+        SqlMigrationStep::DropTable(DropTable { name }) => vec![name.as_str()],
+        SqlMigrationStep::RenameTable { name, new_name } => vec![name.as_str(), new_name.as_str()],
+        SqlMigrationStep::RedefineTables { names } => names.iter().map(String::as_str).collect(),
+        SqlMigrationStep::CreateIndex(create_index) => vec![create_index.table.as_str()],
+        SqlMigrationStep::DropIndex(drop_index) => vec![drop_index.table.as_str()],
+        SqlMigrationStep::AlterIndex(alter_index) => vec![alter_index.table.as_str()],
+        SqlMigrationStep::CreateEnum(create_enum) => vec![create_enum.name.as_str()],
+        SqlMigrationStep::DropEnum(drop_enum) => vec![drop_enum.name.as_str()],
+        SqlMigrationStep::AlterEnum(alter_enum) => vec![alter_enum.name.as_str()],
+    }
+}
+
+/// An estimate of the existing tables locked while the step runs. New tables and enums are not
+/// included.
+fn step_locked_tables<'a>(step: &'a SqlMigrationStep, flavour: &(dyn SqlFlavour + Send + Sync)) -> Vec<&'a str> {
+    match step {
+        SqlMigrationStep::CreateTable(_)
+        | SqlMigrationStep::CreateEnum(_)
+        | SqlMigrationStep::DropEnum(_)
+        | SqlMigrationStep::AlterEnum(_) => Vec::new(),
+        SqlMigrationStep::CreateIndex(create_index)
+            if create_index.caused_by_create_table || !flavour.index_changes_lock_table() =>
+        {
+            Vec::new()
+        }
+        SqlMigrationStep::DropIndex(_) if !flavour.index_changes_lock_table() => Vec::new(),
+        SqlMigrationStep::RenameTable { name, .. } => vec![name.as_str()],
+        _ => step_objects(step),
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlFlavour + Send + Sync),
@@ -131,7 +331,7 @@ fn render_raw_sql(
         }
         SqlMigrationStep::DropTable(DropTable { name }) => Ok(renderer.render_drop_table(name)),
         SqlMigrationStep::RenameTable { name, new_name } => Ok(vec![renderer.render_rename_table(name, new_name)]),
-        SqlMigrationStep::AddForeignKey(add_foreign_key) => Ok(vec![renderer.render_add_foreign_key(add_foreign_key)]),
+        SqlMigrationStep::AddForeignKey(add_foreign_key) => Ok(renderer.render_add_foreign_key(add_foreign_key)),
         SqlMigrationStep::DropForeignKey(drop_foreign_key) => {
             Ok(vec![renderer.render_drop_foreign_key(drop_foreign_key)])
         }
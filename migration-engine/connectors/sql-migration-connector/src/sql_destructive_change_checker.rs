@@ -2,6 +2,7 @@ mod check;
 mod database_inspection_results;
 mod destructive_change_checker_flavour;
 mod destructive_check_plan;
+mod incompatible_values;
 mod unexecutable_step_check;
 mod warning_check;
 
@@ -13,6 +14,7 @@ use crate::{
     Component, SqlMigration, SqlResult,
 };
 use destructive_check_plan::DestructiveCheckPlan;
+use incompatible_values::Incompatibility;
 use migration_connector::{ConnectorResult, DestructiveChangeChecker, DestructiveChangeDiagnostics};
 use sql_schema_describer::{
     walkers::{find_column, ColumnWalker, SqlSchemaExt},
@@ -78,10 +80,49 @@ impl SqlDestructiveChangeChecker<'_> {
         plan.push_unexecutable(typed_unexecutable);
     }
 
+    /// Type changes that existing values may not survive are checked against the data: values that
+    /// cannot be converted make the step unexecutable, values that would be altered produce a
+    /// warning.
+    fn check_type_change(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan) {
+        let incompatibility = match Incompatibility::for_columns(columns) {
+            Some(incompatibility) => incompatibility,
+            None => return,
+        };
+
+        let table = columns.previous.table().name();
+        let column = columns.previous.name();
+
+        let query = match self
+            .flavour()
+            .render_incompatible_values_query(table, column, &incompatibility)
+        {
+            Some(query) => query,
+            None => return,
+        };
+
+        match incompatibility.lossy_conversion() {
+            Some(conversion) => plan.push_warning(SqlMigrationWarningCheck::LossyTypeChange {
+                table: table.to_owned(),
+                column: column.to_owned(),
+                incompatibility,
+                conversion,
+                query,
+            }),
+            None => plan.push_unexecutable(UnexecutableStepCheck::IncompatibleTypeChange {
+                table: table.to_owned(),
+                column: column.to_owned(),
+                incompatibility,
+                query,
+            }),
+        }
+    }
+
     fn plan(&self, steps: &[SqlMigrationStep], before: &SqlSchema, after: &SqlSchema) -> DestructiveCheckPlan {
         let mut plan = DestructiveCheckPlan::new();
 
-        for step in steps {
+        for (step_index, step) in steps.iter().enumerate() {
+            plan.start_step(step_index);
+
             match step {
                 SqlMigrationStep::AlterTable(alter_table) => {
                     // The table in alter_table is the updated table, but we want to
@@ -113,7 +154,8 @@ impl SqlDestructiveChangeChecker<'_> {
                                         flavour: self.flavour(),
                                     };
 
-                                    self.flavour().check_alter_column(&differ, &mut plan)
+                                    self.flavour().check_alter_column(&differ, &mut plan);
+                                    self.check_type_change(&differ, &mut plan);
                                 }
                                 TableChange::AddColumn(ref add_column) => {
                                     let column = find_column(after, after_table.name(), &add_column.column.name)
@@ -156,6 +198,7 @@ impl SqlDestructiveChangeChecker<'_> {
 
                         for columns in differ.column_pairs() {
                             self.flavour().check_alter_column(&columns, &mut plan);
+                            self.check_type_change(&columns, &mut plan);
                         }
                     }
                 }
@@ -14,6 +14,13 @@ pub(super) trait Check {
         None
     }
 
+    /// Indicates that the values returned by the query should be counted and sampled for the
+    /// returned table and column. The query selects the values of the column that would not survive
+    /// a type change, cast to text.
+    fn needed_incompatible_values(&self) -> Option<(&str, &str, &str)> {
+        None
+    }
+
     /// This function will always be called for every check in a migration. Each change must check
     /// for the data it needs in the database inspection results. If there is no data, it should
     /// assume the current state of the database could not be inspected and warn with a best effort
@@ -1,3 +1,4 @@
+use super::incompatible_values::IncompatibleValues;
 use std::{borrow::Cow, collections::HashMap};
 
 /// The information about the current state of the database gathered by the destructive change checker.
@@ -7,6 +8,8 @@ pub(super) struct DatabaseInspectionResults {
     row_counts: HashMap<String, i64>,
     /// HashMap from (table name, column name) to non-null values count.
     value_counts: HashMap<(Cow<'static, str>, Cow<'static, str>), i64>,
+    /// HashMap from (table name, column name) to the values that would not survive a type change.
+    incompatible_values: HashMap<(String, String), IncompatibleValues>,
 }
 
 impl DatabaseInspectionResults {
@@ -30,4 +33,13 @@ impl DatabaseInspectionResults {
     pub(super) fn set_value_count(&mut self, table: Cow<'static, str>, column: Cow<'static, str>, count: i64) {
         self.value_counts.insert((table, column), count);
     }
+
+    pub(super) fn get_incompatible_values(&self, table: &str, column: &str) -> Option<&IncompatibleValues> {
+        self.incompatible_values
+            .get(&(table.to_owned(), column.to_owned()))
+    }
+
+    pub(super) fn set_incompatible_values(&mut self, table: String, column: String, values: IncompatibleValues) {
+        self.incompatible_values.insert((table, column), values);
+    }
 }
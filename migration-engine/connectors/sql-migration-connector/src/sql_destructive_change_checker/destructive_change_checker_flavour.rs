@@ -2,11 +2,22 @@ mod mysql;
 mod postgres;
 mod sqlite;
 
-use super::DestructiveCheckPlan;
+use super::{incompatible_values::Incompatibility, DestructiveCheckPlan};
 use crate::sql_schema_differ::ColumnDiffer;
 
 /// Flavour-specific destructive change checks.
 pub(crate) trait DestructiveChangeCheckerFlavour {
     /// Check for potential destructive or unexecutable alter column steps.
     fn check_alter_column(&self, columns: &ColumnDiffer<'_>, plan: &mut DestructiveCheckPlan);
+
+    /// Render a query selecting the existing values of the column, cast to text, that are
+    /// incompatible with its new type. `None` means the flavour cannot inspect the data.
+    fn render_incompatible_values_query(
+        &self,
+        _table: &str,
+        _column: &str,
+        _incompatibility: &Incompatibility,
+    ) -> Option<String> {
+        None
+    }
 }
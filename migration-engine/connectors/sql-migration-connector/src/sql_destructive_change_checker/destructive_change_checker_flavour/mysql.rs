@@ -2,10 +2,11 @@ use super::DestructiveChangeCheckerFlavour;
 use crate::{
     flavour::MysqlFlavour,
     sql_destructive_change_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        destructive_check_plan::DestructiveCheckPlan, incompatible_values::Incompatibility,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::expanded_alter_column::{expand_mysql_alter_column, MysqlAlterColumn},
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
 
@@ -41,6 +42,40 @@ impl DestructiveChangeCheckerFlavour for MysqlFlavour {
             }
         }
     }
+
+    fn render_incompatible_values_query(
+        &self,
+        table: &str,
+        column: &str,
+        incompatibility: &Incompatibility,
+    ) -> Option<String> {
+        let quoted_column = self.quote(column);
+
+        let predicate = match incompatibility {
+            Incompatibility::TooLong { max_length } => {
+                format!("CHAR_LENGTH(CAST({} AS CHAR)) > {}", quoted_column, max_length)
+            }
+            // The values are only cast once they are known to be integer literals.
+            Incompatibility::NotAnInteger { min, max } => format!(
+                "CASE WHEN CAST({col} AS CHAR) REGEXP '^[[:space:]]*[+-]?[0-9]+[[:space:]]*$' THEN CAST(TRIM(CAST({col} AS CHAR)) AS DECIMAL(65, 0)) NOT BETWEEN {min} AND {max} ELSE TRUE END",
+                col = quoted_column,
+                min = min,
+                max = max,
+            ),
+            Incompatibility::NotANumber => format!(
+                "CAST({} AS CHAR) NOT REGEXP '^[[:space:]]*[+-]?([0-9]+[.]?[0-9]*|[.][0-9]+)([eE][+-]?[0-9]+)?[[:space:]]*$'",
+                quoted_column
+            ),
+            Incompatibility::HasFractionalPart => format!("{col} <> TRUNCATE({col}, 0)", col = quoted_column),
+        };
+
+        Some(format!(
+            "SELECT CAST({column} AS CHAR) FROM {table} WHERE {column} IS NOT NULL AND {predicate}",
+            column = quoted_column,
+            table = self.quote_with_schema(table),
+            predicate = predicate,
+        ))
+    }
 }
 
 /// If the type change is an enum change, diagnose it, and return whether it _was_ an enum change.
@@ -2,10 +2,11 @@ use super::DestructiveChangeCheckerFlavour;
 use crate::{
     flavour::PostgresFlavour,
     sql_destructive_change_checker::{
-        destructive_check_plan::DestructiveCheckPlan, unexecutable_step_check::UnexecutableStepCheck,
-        warning_check::SqlMigrationWarningCheck,
+        destructive_check_plan::DestructiveCheckPlan, incompatible_values::Incompatibility,
+        unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
     },
     sql_migration::expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
+    sql_renderer::SqlRenderer,
     sql_schema_differ::ColumnDiffer,
 };
 use sql_schema_describer::{ColumnArity, DefaultValue};
@@ -39,7 +40,9 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
                             });
                         }
                     }
-                    PostgresAlterColumn::SetDefault(_)
+                    // The existing values are checked against the new type in `check_type_change`.
+                    PostgresAlterColumn::CastType(_)
+                    | PostgresAlterColumn::SetDefault(_)
                     | PostgresAlterColumn::AddSequence
                     | PostgresAlterColumn::DropDefault
                     | PostgresAlterColumn::DropNotNull => (),
@@ -65,6 +68,40 @@ impl DestructiveChangeCheckerFlavour for PostgresFlavour {
             }
         }
     }
+
+    fn render_incompatible_values_query(
+        &self,
+        table: &str,
+        column: &str,
+        incompatibility: &Incompatibility,
+    ) -> Option<String> {
+        let quoted_column = self.quote(column);
+
+        let predicate = match incompatibility {
+            Incompatibility::TooLong { max_length } => {
+                format!("char_length({}::text) > {}", quoted_column, max_length)
+            }
+            // The values are only cast once they are known to be integer literals.
+            Incompatibility::NotAnInteger { min, max } => format!(
+                r"CASE WHEN {col}::text ~ '^\s*[+-]?[0-9]+\s*$' THEN trim({col}::text)::numeric NOT BETWEEN {min} AND {max} ELSE true END",
+                col = quoted_column,
+                min = min,
+                max = max,
+            ),
+            Incompatibility::NotANumber => format!(
+                r"{}::text !~* '^\s*[+-]?([0-9]+\.?[0-9]*|\.[0-9]+)(e[+-]?[0-9]+)?\s*$'",
+                quoted_column
+            ),
+            Incompatibility::HasFractionalPart => format!("{col} <> trunc({col})", col = quoted_column),
+        };
+
+        Some(format!(
+            "SELECT {column}::text FROM {table} WHERE {column} IS NOT NULL AND {predicate}",
+            column = quoted_column,
+            table = self.quote_with_schema(table),
+            predicate = predicate,
+        ))
+    }
 }
 
 fn default_can_be_rendered(default: Option<&DefaultValue>) -> bool {
@@ -1,6 +1,9 @@
 use super::{
-    check::Check, database_inspection_results::DatabaseInspectionResults,
-    unexecutable_step_check::UnexecutableStepCheck, warning_check::SqlMigrationWarningCheck,
+    check::Check,
+    database_inspection_results::DatabaseInspectionResults,
+    incompatible_values::{IncompatibleValues, INCOMPATIBLE_VALUES_SAMPLE_SIZE},
+    unexecutable_step_check::UnexecutableStepCheck,
+    warning_check::SqlMigrationWarningCheck,
 };
 use crate::{SqlError, SqlResult};
 use migration_connector::{DestructiveChangeDiagnostics, MigrationWarning, UnexecutableMigration};
@@ -13,10 +16,14 @@ const DESTRUCTIVE_TIMEOUT_DURATION: Duration = Duration::from_secs(60);
 /// A DestructiveCheckPlan is the collection of destructive change checks
 /// ([Check](trait.Check.html)) for a given migration. It has an `execute` method that performs
 /// database inspection and renders user-facing messages based on the checks.
+///
+/// Every check is stored with the index of the migration step it was pushed for, so the rendered
+/// diagnostics can point to their step.
 #[derive(Debug)]
 pub(crate) struct DestructiveCheckPlan {
-    warnings: Vec<SqlMigrationWarningCheck>,
-    unexecutable_migrations: Vec<UnexecutableStepCheck>,
+    warnings: Vec<(SqlMigrationWarningCheck, Option<usize>)>,
+    unexecutable_migrations: Vec<(UnexecutableStepCheck, Option<usize>)>,
+    current_step: Option<usize>,
 }
 
 impl DestructiveCheckPlan {
@@ -24,15 +31,22 @@ impl DestructiveCheckPlan {
         DestructiveCheckPlan {
             warnings: Vec::new(),
             unexecutable_migrations: Vec::new(),
+            current_step: None,
         }
     }
 
+    /// Attribute the checks pushed from now on to the migration step at `step_index`.
+    pub(super) fn start_step(&mut self, step_index: usize) {
+        self.current_step = Some(step_index);
+    }
+
     pub(super) fn push_warning(&mut self, warning: SqlMigrationWarningCheck) {
-        self.warnings.push(warning)
+        self.warnings.push((warning, self.current_step))
     }
 
     pub(super) fn push_unexecutable(&mut self, unexecutable_migration: UnexecutableStepCheck) {
-        self.unexecutable_migrations.push(unexecutable_migration)
+        self.unexecutable_migrations
+            .push((unexecutable_migration, self.current_step))
     }
 
     /// Inspect the current database state to qualify and render destructive change warnings and
@@ -48,12 +62,12 @@ impl DestructiveCheckPlan {
         let mut results = DatabaseInspectionResults::default();
 
         let inspection = async {
-            for unexecutable in &self.unexecutable_migrations {
+            for (unexecutable, _) in &self.unexecutable_migrations {
                 self.inspect_for_check(unexecutable, &mut results, schema_name, conn)
                     .await?;
             }
 
-            for warning in &self.warnings {
+            for (warning, _) in &self.warnings {
                 self.inspect_for_check(warning, &mut results, schema_name, conn).await?;
             }
 
@@ -66,23 +80,7 @@ impl DestructiveCheckPlan {
             Ok(Err(err)) => return Err(err),
         };
 
-        let mut diagnostics = DestructiveChangeDiagnostics::new();
-
-        for unexecutable in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
-                diagnostics
-                    .unexecutable_migrations
-                    .push(UnexecutableMigration { description: message })
-            }
-        }
-
-        for warning in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
-                diagnostics.warnings.push(MigrationWarning { description: message })
-            }
-        }
-
-        Ok(diagnostics)
+        Ok(self.render_diagnostics(&results))
     }
 
     /// Perform the database inspection for a given [`Check`](trait.Check.html).
@@ -107,6 +105,13 @@ impl DestructiveCheckPlan {
             }
         }
 
+        if let Some((table, column, query)) = check.needed_incompatible_values() {
+            if results.get_incompatible_values(table, column).is_none() {
+                let values = find_incompatible_values(query, conn).await?;
+                results.set_incompatible_values(table.to_owned(), column.to_owned(), values);
+            }
+        }
+
         Ok(())
     }
 
@@ -116,20 +121,27 @@ impl DestructiveCheckPlan {
     /// warnings about the production database, when creating a migration in
     /// development.
     pub(super) fn pure_check(&self) -> DestructiveChangeDiagnostics {
-        let results = DatabaseInspectionResults::default();
+        self.render_diagnostics(&DatabaseInspectionResults::default())
+    }
+
+    /// Render the messages of the checks that apply given the inspection results, with the steps
+    /// they were pushed for.
+    fn render_diagnostics(&self, results: &DatabaseInspectionResults) -> DestructiveChangeDiagnostics {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
 
-        for unexecutable in &self.unexecutable_migrations {
-            if let Some(message) = unexecutable.evaluate(&results) {
+        for (unexecutable, step_index) in &self.unexecutable_migrations {
+            if let Some(message) = unexecutable.evaluate(results) {
                 diagnostics
                     .unexecutable_migrations
-                    .push(UnexecutableMigration { description: message })
+                    .push(UnexecutableMigration { description: message });
+                diagnostics.unexecutable_migration_steps.push(*step_index);
             }
         }
 
-        for warning in &self.warnings {
-            if let Some(message) = warning.evaluate(&results) {
-                diagnostics.warnings.push(MigrationWarning { description: message })
+        for (warning, step_index) in &self.warnings {
+            if let Some(message) = warning.evaluate(results) {
+                diagnostics.warnings.push(MigrationWarning { description: message });
+                diagnostics.warning_steps.push(*step_index);
             }
         }
 
@@ -193,3 +205,38 @@ async fn count_values_in_column(
 
     Ok(values_count)
 }
+
+/// Count and sample the values returned by a query rendered by
+/// `DestructiveChangeCheckerFlavour::render_incompatible_values_query`.
+async fn find_incompatible_values(query: &str, conn: &dyn Queryable) -> SqlResult<IncompatibleValues> {
+    let count_query = format!("SELECT COUNT(*) FROM ({}) AS incompatible_values", query);
+
+    let count = conn
+        .query_raw(&count_query, &[])
+        .await?
+        .first()
+        .and_then(|row| row.at(0).and_then(|count| count.as_i64()))
+        .ok_or_else(|| {
+            SqlError::Generic(anyhow::anyhow!(
+                "Unexpected result set shape when counting incompatible values."
+            ))
+        })?;
+
+    if count == 0 {
+        return Ok(IncompatibleValues {
+            count,
+            samples: Vec::new(),
+        });
+    }
+
+    let samples_query = format!("{} LIMIT {}", query, INCOMPATIBLE_VALUES_SAMPLE_SIZE);
+
+    let samples = conn
+        .query_raw(&samples_query, &[])
+        .await?
+        .into_iter()
+        .filter_map(|row| row.at(0).and_then(|value| value.as_str()).map(String::from))
+        .collect();
+
+    Ok(IncompatibleValues { count, samples })
+}
@@ -0,0 +1,122 @@
+use crate::sql_schema_differ::{character_maximum_length, ColumnDiffer};
+use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily};
+use std::fmt;
+
+/// The number of offending values quoted in destructive change messages.
+pub(super) const INCOMPATIBLE_VALUES_SAMPLE_SIZE: usize = 5;
+
+/// A reason why existing values in a column would not survive a change of the column type.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Incompatibility {
+    /// Values longer than the new maximum length would be truncated or rejected.
+    TooLong { max_length: i64 },
+    /// Values that are not integer literals, or that are out of the range of the new integer type, cannot be
+    /// cast to it.
+    NotAnInteger { min: i64, max: i64 },
+    /// Values that are not number literals cannot be cast to a numeric type.
+    NotANumber,
+    /// Values with a fractional part would be rounded when cast to an integer type.
+    HasFractionalPart,
+}
+
+impl Incompatibility {
+    /// Determine whether changing the type of a column could make some of its existing values fail
+    /// the conversion, or alter them.
+    pub(super) fn for_columns(columns: &ColumnDiffer<'_>) -> Option<Self> {
+        if !columns.all_changes().type_changed() {
+            return None;
+        }
+
+        match (columns.previous.column_type_family(), columns.next.column_type_family()) {
+            (ColumnTypeFamily::String, ColumnTypeFamily::Int) => {
+                let (min, max) = integer_range(&columns.next);
+
+                Some(Incompatibility::NotAnInteger { min, max })
+            }
+            (ColumnTypeFamily::String, ColumnTypeFamily::Float) => Some(Incompatibility::NotANumber),
+            (ColumnTypeFamily::Float, ColumnTypeFamily::Int) => Some(Incompatibility::HasFractionalPart),
+            (ColumnTypeFamily::String, ColumnTypeFamily::String) => {
+                let max_length = character_maximum_length(&columns.next)?;
+
+                match character_maximum_length(&columns.previous) {
+                    Some(previous_max_length) if previous_max_length <= max_length => None,
+                    _ => Some(Incompatibility::TooLong { max_length }),
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// What happens to incompatible values when the migration silently alters them, e.g. "rounded". `None` if
+    /// the migration fails instead.
+    pub(super) fn lossy_conversion(&self) -> Option<&'static str> {
+        match self {
+            Incompatibility::HasFractionalPart => Some("rounded"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Incompatibility::TooLong { max_length } => write!(f, "are longer than {} characters", max_length),
+            Incompatibility::NotAnInteger { min, max } => {
+                write!(f, "are not valid integers between {} and {}", min, max)
+            }
+            Incompatibility::NotANumber => f.write_str("are not valid numbers"),
+            Incompatibility::HasFractionalPart => f.write_str("have a fractional part"),
+        }
+    }
+}
+
+/// The existing values of a column that would not survive a type change.
+#[derive(Debug, Clone)]
+pub(super) struct IncompatibleValues {
+    /// The total number of incompatible values.
+    pub(super) count: i64,
+    /// A few of the incompatible values, rendered as text.
+    pub(super) samples: Vec<String>,
+}
+
+impl IncompatibleValues {
+    /// Render the count and the sample values for user-facing messages.
+    pub(super) fn describe(&self, incompatibility: &Incompatibility) -> String {
+        let samples = self
+            .samples
+            .iter()
+            .map(|value| format!("`{}`", value))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        format!(
+            "{count} existing values {incompatibility}, for example {samples}.",
+            count = self.count,
+            incompatibility = incompatibility,
+            samples = samples,
+        )
+    }
+}
+
+/// The range of the integer type of a column. Integer fields are 32-bit integers unless their native type says
+/// otherwise.
+fn integer_range(column: &ColumnWalker<'_>) -> (i64, i64) {
+    let column_type = column.column_type();
+    let data_type = if column_type.full_data_type.is_empty() {
+        column_type.data_type.to_lowercase()
+    } else {
+        column_type.full_data_type.to_lowercase()
+    };
+
+    if data_type.starts_with("bigint") || data_type.starts_with("int8") {
+        (i64::MIN, i64::MAX)
+    } else if data_type.starts_with("mediumint") {
+        (-8_388_608, 8_388_607)
+    } else if data_type.starts_with("smallint") || data_type.starts_with("int2") {
+        (i16::MIN.into(), i16::MAX.into())
+    } else if data_type.starts_with("tinyint") {
+        (i8::MIN.into(), i8::MAX.into())
+    } else {
+        (i32::MIN.into(), i32::MAX.into())
+    }
+}
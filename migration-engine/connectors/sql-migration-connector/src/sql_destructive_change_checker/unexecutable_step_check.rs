@@ -1,10 +1,13 @@
-use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+use super::{
+    check::Check, database_inspection_results::DatabaseInspectionResults, incompatible_values::Incompatibility,
+};
 
 #[derive(Debug)]
 pub(crate) enum UnexecutableStepCheck {
     AddedRequiredFieldToTable { table: String, column: String },
     MadeOptionalFieldRequired { table: String, column: String },
     MadeScalarFieldIntoArrayField { table: String, column: String },
+    IncompatibleTypeChange { table: String, column: String, incompatibility: Incompatibility, query: String },
     // TODO:
     // AddedUnimplementableUniqueConstraint {
     //     table: String,
@@ -26,6 +29,7 @@ impl Check for UnexecutableStepCheck {
             UnexecutableStepCheck::MadeOptionalFieldRequired { table, column: _ }
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column: _ }
             | UnexecutableStepCheck::AddedRequiredFieldToTable { table, column: _ } => Some(table),
+            UnexecutableStepCheck::IncompatibleTypeChange { .. } => None,
         }
     }
 
@@ -33,7 +37,17 @@ impl Check for UnexecutableStepCheck {
         match self {
             UnexecutableStepCheck::MadeOptionalFieldRequired { table, column }
             | UnexecutableStepCheck::MadeScalarFieldIntoArrayField { table, column } => Some((table, column)),
-            UnexecutableStepCheck::AddedRequiredFieldToTable { .. } => None,
+            UnexecutableStepCheck::AddedRequiredFieldToTable { .. }
+            | UnexecutableStepCheck::IncompatibleTypeChange { .. } => None,
+        }
+    }
+
+    fn needed_incompatible_values(&self) -> Option<(&str, &str, &str)> {
+        match self {
+            UnexecutableStepCheck::IncompatibleTypeChange { table, column, query, .. } => {
+                Some((table, column, query))
+            }
+            _ => None,
         }
     }
 
@@ -99,6 +113,23 @@ impl Check for UnexecutableStepCheck {
 
                 }
             }
+            UnexecutableStepCheck::IncompatibleTypeChange { table, column, incompatibility, .. } => {
+                match database_checks.get_incompatible_values(table, column) {
+                    Some(values) if values.count == 0 => None,
+                    Some(values) => Some(format!(
+                        "Changed the type of the column `{column}` on the `{table}` table, but {details} This migration step cannot be executed.",
+                        column = column,
+                        table = table,
+                        details = values.describe(incompatibility),
+                    )),
+                    None => Some(format!(
+                        "Changed the type of the column `{column}` on the `{table}` table. The migration will fail if existing values {incompatibility}.",
+                        column = column,
+                        table = table,
+                        incompatibility = incompatibility,
+                    )),
+                }
+            }
             // TODO
             //
             // SqlUnexecutableMigration::AddedUnimplementableUniqueConstraint { table, constrained_columns } => write!(f, "Added a unique constraint that would not hold given existing data on `{table}`.{constrained_columns:?}", table = table, constrained_columns = constrained_columns)?,
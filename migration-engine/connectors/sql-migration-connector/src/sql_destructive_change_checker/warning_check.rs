@@ -1,4 +1,6 @@
-use super::{check::Check, database_inspection_results::DatabaseInspectionResults};
+use super::{
+    check::Check, database_inspection_results::DatabaseInspectionResults, incompatible_values::Incompatibility,
+};
 
 #[derive(Debug)]
 pub(super) enum SqlMigrationWarningCheck {
@@ -8,6 +10,13 @@ pub(super) enum SqlMigrationWarningCheck {
     PrimaryKeyChange { table: String },
     UniqueConstraintAddition { table: String, columns: Vec<String> },
     EnumValueRemoval { enm: String, values: Vec<String> },
+    LossyTypeChange {
+        table: String,
+        column: String,
+        incompatibility: Incompatibility,
+        conversion: &'static str,
+        query: String,
+    },
 }
 
 impl Check for SqlMigrationWarningCheck {
@@ -32,6 +41,13 @@ impl Check for SqlMigrationWarningCheck {
         }
     }
 
+    fn needed_incompatible_values(&self) -> Option<(&str, &str, &str)> {
+        match self {
+            SqlMigrationWarningCheck::LossyTypeChange { table, column, query, .. } => Some((table, column, query)),
+            _ => None,
+        }
+    }
+
     fn evaluate(&self, database_check_results: &DatabaseInspectionResults) -> Option<String> {
         match self {
             SqlMigrationWarningCheck::NonEmptyTableDrop { table } => match database_check_results.get_row_count(table) {
@@ -58,6 +74,11 @@ impl Check for SqlMigrationWarningCheck {
             },
             SqlMigrationWarningCheck::UniqueConstraintAddition { table, columns } =>  Some(format!("The migration will add a unique constraint covering the columns `{columns}` on the table `{table}`. If there are existing duplicate values, the migration will fail.", table = table, columns = format!("[{}]",columns.join(",")))),
             SqlMigrationWarningCheck::EnumValueRemoval { enm, values } =>  Some(format!("The migration will remove the values {values} on the enum `{enm}`. If these variants are still used in the database, the migration will fail.", enm = enm, values = format!("[{}]",values.join(",")))),
+            SqlMigrationWarningCheck::LossyTypeChange { table, column, incompatibility, conversion, .. } => match database_check_results.get_incompatible_values(table, column) {
+                Some(values) if values.count == 0 => None,
+                Some(values) => Some(format!("You are about to change the type of the column `{column_name}` on the `{table_name}` table. {details} They will be {conversion}.", column_name = column, table_name = table, details = values.describe(incompatibility), conversion = conversion)),
+                None => Some(format!("You are about to change the type of the column `{column_name}` on the `{table_name}` table. Existing values that {incompatibility} will be {conversion}.", column_name = column, table_name = table, incompatibility = incompatibility, conversion = conversion)),
+            },
 
         }
     }
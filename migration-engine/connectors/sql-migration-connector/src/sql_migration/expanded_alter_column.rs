@@ -64,6 +64,19 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer<'_>) -> Option
                 (ColumnTypeFamily::Int, ColumnTypeFamily::String) => {
                     changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
                 }
+                // Character lengths: values that are too long make the step fail.
+                (ColumnTypeFamily::String, ColumnTypeFamily::String) => {
+                    changes.push(PostgresAlterColumn::SetType(columns.next.column_type().clone()))
+                }
+                // Text and floats are converted with an explicit cast, so the existing values are kept
+                // or the step fails, instead of the column being dropped and recreated.
+                (ColumnTypeFamily::String, ColumnTypeFamily::Int)
+                | (ColumnTypeFamily::String, ColumnTypeFamily::Float)
+                | (ColumnTypeFamily::Float, ColumnTypeFamily::Int)
+                    if !columns.previous.arity().is_list() && !columns.next.arity().is_list() =>
+                {
+                    changes.push(PostgresAlterColumn::CastType(columns.next.column_type().clone()))
+                }
                 _ => return None,
             },
             ColumnChange::Sequence => {
@@ -89,6 +102,8 @@ pub(crate) enum PostgresAlterColumn {
     DropDefault,
     DropNotNull,
     SetType(ColumnType),
+    /// Change the type, converting the existing values with an explicit cast (`USING`).
+    CastType(ColumnType),
     SetNotNull,
     /// Add an auto-incrementing sequence as a default on the column.
     AddSequence,
@@ -23,8 +23,19 @@ pub(crate) trait SqlRenderer {
 
     fn quote_with_schema<'a, 'b>(&'a self, name: &'b str) -> QuotedWithSchema<'a, &'b str>;
 
-    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> String {
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> Vec<String> {
         let AddForeignKey { foreign_key, table } = add_foreign_key;
+
+        vec![self.render_foreign_key_constraint(table, foreign_key, foreign_key.constraint_name.as_deref())]
+    }
+
+    /// Render the `ALTER TABLE ... ADD CONSTRAINT ... FOREIGN KEY` statement for a foreign key.
+    fn render_foreign_key_constraint(
+        &self,
+        table: &str,
+        foreign_key: &ForeignKey,
+        constraint_name: Option<&str>,
+    ) -> String {
         let mut add_constraint = String::with_capacity(120);
 
         write!(
@@ -34,7 +45,7 @@ pub(crate) trait SqlRenderer {
         )
         .unwrap();
 
-        if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
+        if let Some(constraint_name) = constraint_name {
             write!(add_constraint, "CONSTRAINT {} ", self.quote(constraint_name)).unwrap();
         }
 
@@ -50,6 +61,14 @@ pub(crate) trait SqlRenderer {
         add_constraint
     }
 
+    /// Render a column added to an existing table. The default is a single `ADD COLUMN` clause.
+    fn render_add_column(&self, column: ColumnWalker<'_>) -> RenderedAlterColumn {
+        RenderedAlterColumn {
+            alter_columns: vec![format!("ADD COLUMN {}", self.render_column(column))],
+            ..Default::default()
+        }
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>>;
 
     fn render_column(&self, column: ColumnWalker<'_>) -> String;
@@ -108,8 +127,15 @@ pub(crate) trait SqlRenderer {
                         schema: differ.next,
                         column,
                     };
-                    let col_sql = self.render_column(column);
-                    lines.push(format!("ADD COLUMN {}", col_sql));
+                    let RenderedAlterColumn {
+                        alter_columns,
+                        before,
+                        after,
+                    } = self.render_add_column(column);
+
+                    lines.extend(alter_columns);
+                    before_statements.extend(before);
+                    after_statements.extend(after);
                 }
                 TableChange::DropColumn(DropColumn { name }) => {
                     let name = self.quote(&name);
@@ -127,17 +153,9 @@ pub(crate) trait SqlRenderer {
                             before,
                             after,
                         }) => {
-                            for statement in alter_columns {
-                                lines.push(statement);
-                            }
-
-                            if let Some(before) = before {
-                                before_statements.push(before);
-                            }
-
-                            if let Some(after) = after {
-                                after_statements.push(after);
-                            }
+                            lines.extend(alter_columns);
+                            before_statements.extend(before);
+                            after_statements.extend(after);
                         }
                         None => {
                             let name = self.quote(&name);
@@ -204,7 +222,7 @@ pub(crate) struct RenderedAlterColumn {
     /// The statements that will be included in the ALTER TABLE
     pub(crate) alter_columns: Vec<String>,
     /// The statements to be run before the ALTER TABLE.
-    pub(crate) before: Option<String>,
+    pub(crate) before: Vec<String>,
     /// The statements to be run after the ALTER TABLE.
-    pub(crate) after: Option<String>,
+    pub(crate) after: Vec<String>,
 }
@@ -158,8 +158,8 @@ impl SqlRenderer for MysqlFlavour {
 
         Some(RenderedAlterColumn {
             alter_columns: sql,
-            before: None,
-            after: None,
+            before: Vec::new(),
+            after: Vec::new(),
         })
     }
 
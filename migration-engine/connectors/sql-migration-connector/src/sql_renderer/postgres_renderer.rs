@@ -4,7 +4,7 @@ use crate::{
     flavour::{PostgresFlavour, SqlFlavour},
     sql_migration::{
         expanded_alter_column::{expand_postgres_alter_column, PostgresAlterColumn},
-        AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
+        AddForeignKey, AlterEnum, AlterIndex, CreateEnum, CreateIndex, DropEnum, DropForeignKey, DropIndex,
     },
     sql_schema_differ::{ColumnDiffer, SqlSchemaDiffer},
 };
@@ -27,6 +27,85 @@ impl SqlRenderer for PostgresFlavour {
         }
     }
 
+    fn render_add_foreign_key(&self, add_foreign_key: &AddForeignKey) -> Vec<String> {
+        let AddForeignKey { foreign_key, table } = add_foreign_key;
+
+        if !self.online_migrations {
+            return vec![self.render_foreign_key_constraint(table, foreign_key, foreign_key.constraint_name.as_deref())];
+        }
+
+        // Adding the constraint as NOT VALID only takes a brief lock. The existing rows are then
+        // validated without blocking writes to the table.
+        let constraint_name = foreign_key
+            .constraint_name
+            .clone()
+            .unwrap_or_else(|| format!("{}_{}_fkey", table, foreign_key.columns.join("_")));
+
+        vec![
+            format!(
+                "{} NOT VALID",
+                self.render_foreign_key_constraint(table, foreign_key, Some(&constraint_name))
+            ),
+            format!(
+                "ALTER TABLE {table} VALIDATE CONSTRAINT {constraint_name}",
+                table = self.quote_with_schema(table),
+                constraint_name = self.quote(&constraint_name),
+            ),
+        ]
+    }
+
+    fn render_add_column(&self, column: ColumnWalker<'_>) -> RenderedAlterColumn {
+        let backfill_default = column
+            .default()
+            .filter(|default| matches!(default, DefaultValue::VALUE(_) | DefaultValue::NOW))
+            .filter(|_| self.online_migrations && column.arity().is_required());
+
+        let default = match backfill_default {
+            Some(default) => self.render_default(default, column.column_type_family()),
+            None => {
+                return RenderedAlterColumn {
+                    alter_columns: vec![format!("ADD COLUMN {}", self.render_column(column))],
+                    ..Default::default()
+                }
+            }
+        };
+
+        // Adding the column as nullable without a default does not rewrite the table. The default
+        // only applies to new rows, the existing rows are backfilled before the column is made
+        // required.
+        let mut nullable_column = column.column.clone();
+        nullable_column.tpe.arity = ColumnArity::Nullable;
+        nullable_column.default = None;
+
+        let nullable_column = ColumnWalker {
+            column: &nullable_column,
+            ..column
+        };
+        let table_name = self.quote_with_schema(column.table().name());
+        let column_name = self.quote(column.name());
+
+        RenderedAlterColumn {
+            alter_columns: vec![
+                format!("ADD COLUMN {}", self.render_column(nullable_column)),
+                format!("ALTER COLUMN {} SET DEFAULT {}", column_name, default),
+            ],
+            before: Vec::new(),
+            after: vec![
+                format!(
+                    "UPDATE {table_name} SET {column_name} = {default} WHERE {column_name} IS NULL",
+                    table_name = table_name,
+                    column_name = column_name,
+                    default = default,
+                ),
+                format!(
+                    "ALTER TABLE {table_name} ALTER COLUMN {column_name} SET NOT NULL",
+                    table_name = table_name,
+                    column_name = column_name,
+                ),
+            ],
+        }
+    }
+
     fn render_alter_enum(&self, alter_enum: &AlterEnum, differ: &SqlSchemaDiffer<'_>) -> anyhow::Result<Vec<String>> {
         if alter_enum.dropped_variants.is_empty() {
             let stmts: Vec<String> = alter_enum
@@ -227,8 +306,9 @@ impl SqlRenderer for PostgresFlavour {
                         let sequence_is_still_used = walk_columns(differ.next.schema()).any(|column| matches!(column.default(), Some(DefaultValue::SEQUENCE(other_sequence)) if other_sequence == sequence_expression) && !column.is_same_column(&differ.next));
 
                         if !sequence_is_still_used {
-                            rendered_steps.after =
-                                Some(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
+                            rendered_steps
+                                .after
+                                .push(format!("DROP SEQUENCE {}", Quoted::postgres_ident(sequence_name)));
                         }
                     }
                 }
@@ -248,6 +328,12 @@ impl SqlRenderer for PostgresFlavour {
                     &alter_column_prefix,
                     render_column_type(&ty)
                 )),
+                PostgresAlterColumn::CastType(ty) => rendered_steps.alter_columns.push(format!(
+                    "{prefix} SET DATA TYPE {ty} USING {column}::{ty}",
+                    prefix = alter_column_prefix,
+                    ty = render_column_type(&ty).trim_end(),
+                    column = column_name,
+                )),
                 PostgresAlterColumn::AddSequence => {
                     // We imitate the sequence that would be automatically created on a `SERIAL` column.
                     //
@@ -269,14 +355,14 @@ impl SqlRenderer for PostgresFlavour {
                     let alter_sequence = format!(
                         "ALTER SEQUENCE {sequence_name} OWNED BY {schema_name}.{table_name}.{column_name}",
                         sequence_name = Quoted::postgres_ident(sequence_name),
-                        schema_name = Quoted::postgres_ident(self.0.schema()),
+                        schema_name = Quoted::postgres_ident(self.url.schema()),
                         table_name = table_name,
                         column_name = column_name,
                     );
 
                     rendered_steps.alter_columns.push(set_default);
-                    rendered_steps.before = Some(create_sequence);
-                    rendered_steps.after = Some(alter_sequence);
+                    rendered_steps.before.push(create_sequence);
+                    rendered_steps.after.push(alter_sequence);
                 }
             }
         }
@@ -288,7 +374,7 @@ impl SqlRenderer for PostgresFlavour {
         let sql = format!(
             r#"CREATE TYPE {enum_name} AS ENUM ({variants})"#,
             enum_name = QuotedWithSchema {
-                schema_name: &self.0.schema(),
+                schema_name: &self.url.schema(),
                 name: Quoted::postgres_ident(&create_enum.name)
             },
             variants = create_enum.variants.iter().map(Quoted::postgres_string).join(", "),
@@ -298,7 +384,24 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_create_index(&self, create_index: &CreateIndex) -> String {
-        render_create_index(self, &create_index.table, &create_index.index, self.sql_family())
+        if !self.online_migrations {
+            return render_create_index(self, &create_index.table, &create_index.index, self.sql_family());
+        }
+
+        // Concurrent index creation does not block writes, but it cannot run inside a transaction.
+        let Index { name, columns, tpe } = &create_index.index;
+        let index_type = match tpe {
+            IndexType::Unique => "UNIQUE ",
+            IndexType::Normal => "",
+        };
+
+        format!(
+            "CREATE {index_type}INDEX CONCURRENTLY {index_name} ON {table_reference}({columns})",
+            index_type = index_type,
+            index_name = self.quote(name),
+            table_reference = self.quote_with_schema(&create_index.table),
+            columns = columns.iter().map(|c| self.quote(c)).join(", "),
+        )
     }
 
     fn render_create_table(&self, table: &TableWalker<'_>) -> anyhow::Result<String> {
@@ -338,7 +441,9 @@ impl SqlRenderer for PostgresFlavour {
     }
 
     fn render_drop_index(&self, drop_index: &DropIndex) -> String {
-        format!("DROP INDEX {}", self.quote_with_schema(&drop_index.name))
+        let concurrently = if self.online_migrations { "CONCURRENTLY " } else { "" };
+
+        format!("DROP INDEX {}{}", concurrently, self.quote_with_schema(&drop_index.name))
     }
 
     fn render_redefine_tables(&self, _names: &[String], _differ: SqlSchemaDiffer<'_>) -> Vec<String> {
@@ -100,7 +100,7 @@ impl SqlRenderer for SqliteFlavour {
         }
     }
 
-    fn render_add_foreign_key(&self, _add_foreign_key: &AddForeignKey) -> String {
+    fn render_add_foreign_key(&self, _add_foreign_key: &AddForeignKey) -> Vec<String> {
         unreachable!("AddForeignKey on SQLite")
     }
 
@@ -284,13 +284,14 @@ impl SqlRenderer for SqliteFlavour {
     }
 }
 
-fn render_column_type(t: &ColumnType) -> &'static str {
+fn render_column_type(t: &ColumnType) -> &str {
     match &t.family {
         ColumnTypeFamily::Boolean => "BOOLEAN",
         ColumnTypeFamily::DateTime => "DATETIME",
         ColumnTypeFamily::Float => "REAL",
         ColumnTypeFamily::Int => "INTEGER",
         ColumnTypeFamily::String => "TEXT",
+        ColumnTypeFamily::Unsupported(raw_type) => raw_type,
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
@@ -92,6 +92,12 @@ impl<'a> SqlSchemaCalculator<'a> {
                             auto_increment: has_auto_increment_default || is_sqlite_integer_primary_key
                         })
                     } ,
+                    TypeWalker::Unsupported(raw_type) => Some(sql::Column {
+                        name: f.db_name().to_owned(),
+                        tpe: unsupported_column_type(&f, raw_type),
+                        default: migration_value_new(&f),
+                        auto_increment: false,
+                    }),
                     _ => None,
                 })
                 .collect();
@@ -304,6 +310,17 @@ fn column_type(field: &ScalarFieldWalker<'_>) -> sql::ColumnType {
     column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
 }
 
+/// Columns of types Prisma does not support are rendered verbatim, and diffed by their raw type.
+fn unsupported_column_type(field: &ScalarFieldWalker<'_>, raw_type: &str) -> sql::ColumnType {
+    sql::ColumnType {
+        data_type: raw_type.to_owned(),
+        full_data_type: raw_type.to_owned(),
+        character_maximum_length: None,
+        family: sql::ColumnTypeFamily::Unsupported(raw_type.to_owned()),
+        arity: column_arity(field.arity()),
+    }
+}
+
 fn scalar_type_for_field(field: &ScalarFieldWalker<'_>) -> ScalarType {
     match field.field_type() {
         TypeWalker::Base(ref scalar) => *scalar,
@@ -4,7 +4,7 @@ mod index;
 mod sql_schema_differ_flavour;
 mod table;
 
-pub(crate) use column::{ColumnChange, ColumnChanges, ColumnDiffer};
+pub(crate) use column::{character_maximum_length, ColumnChange, ColumnChanges, ColumnDiffer};
 pub(crate) use sql_schema_differ_flavour::SqlSchemaDifferFlavour;
 pub(crate) use table::TableDiffer;
 
@@ -1,6 +1,8 @@
 use crate::{database_info::DatabaseInfo, flavour::SqlFlavour};
+use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
-use sql_schema_describer::{walkers::ColumnWalker, DefaultValue};
+use regex::Regex;
+use sql_schema_describer::{walkers::ColumnWalker, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -61,6 +63,30 @@ impl<'a> ColumnDiffer<'a> {
         self.flavour.column_type_changed(self)
     }
 
+    /// Columns of unsupported types are compared by their raw database type, since the describer
+    /// can put the same type in another family. `None` when neither column is of an unsupported type.
+    pub(crate) fn unsupported_type_changed(&self) -> Option<bool> {
+        match (self.previous.column_type_family(), self.next.column_type_family()) {
+            (ColumnTypeFamily::Unsupported(_), _) | (_, ColumnTypeFamily::Unsupported(_)) => {
+                Some(!raw_element_type(&self.previous).eq_ignore_ascii_case(raw_element_type(&self.next)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether the next column has a maximum length, and the previous column a different or no
+    /// maximum length. A next column without maximum length is not compared, since the calculated
+    /// schema does not know the length the database picks for plain Prisma types.
+    pub(crate) fn character_maximum_length_changed(&self) -> bool {
+        match (
+            character_maximum_length(&self.previous),
+            character_maximum_length(&self.next),
+        ) {
+            (_, None) => false,
+            (previous, Some(next)) => previous != Some(next),
+        }
+    }
+
     /// There are workarounds to cope with current migration and introspection limitations.
     ///
     /// - We bail on a number of cases that are too complex to deal with right now or underspecified.
@@ -115,6 +141,34 @@ impl<'a> ColumnDiffer<'a> {
     }
 }
 
+/// The raw type of the column. Postgres describes the type of array columns as the element type with an
+/// underscore prefix, e.g. `_money`.
+fn raw_element_type<'a>(column: &ColumnWalker<'a>) -> &'a str {
+    let full_data_type = column.column_type().full_data_type.as_str();
+
+    if column.arity().is_list() {
+        full_data_type.trim_start_matches('_')
+    } else {
+        full_data_type
+    }
+}
+
+/// The maximum length of a character column, taken from the describer or parsed from the full data
+/// type for columns coming from native types.
+pub(crate) fn character_maximum_length(column: &ColumnWalker<'_>) -> Option<i64> {
+    static CHARACTER_TYPE_LENGTH_RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?i)^\s*(?:var)?char(?:acter)?(?: varying)?\s*\(\s*(\d+)\s*\)").unwrap());
+
+    let column_type = column.column_type();
+
+    column_type.character_maximum_length.or_else(|| {
+        CHARACTER_TYPE_LENGTH_RE
+            .captures(&column_type.full_data_type)
+            .and_then(|captures| captures.get(1))
+            .and_then(|length| length.as_str().parse().ok())
+    })
+}
+
 fn json_defaults_match(previous: &str, next: &str) -> bool {
     serde_json::from_str::<serde_json::Value>(previous)
         .and_then(|previous| serde_json::from_str::<serde_json::Value>(next).map(|next| (previous, next)))
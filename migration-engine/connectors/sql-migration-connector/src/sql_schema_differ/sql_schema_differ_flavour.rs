@@ -15,7 +15,12 @@ pub(crate) trait SqlSchemaDifferFlavour {
 
     /// Return whether a column's type needs to be migrated.
     fn column_type_changed(&self, differ: &ColumnDiffer<'_>) -> bool {
+        if let Some(changed) = differ.unsupported_type_changed() {
+            return changed;
+        }
+
         differ.previous.column_type_family() != differ.next.column_type_family()
+            || differ.character_maximum_length_changed()
     }
 
     /// Whether `AddForeignKey` steps should be generated for created tables.
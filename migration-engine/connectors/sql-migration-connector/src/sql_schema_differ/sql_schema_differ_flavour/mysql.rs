@@ -7,6 +7,10 @@ const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnT
 
 impl SqlSchemaDifferFlavour for MysqlFlavour {
     fn column_type_changed(&self, differ: &ColumnDiffer<'_>) -> bool {
+        if let Some(changed) = differ.unsupported_type_changed() {
+            return changed;
+        }
+
         if differ.database_info.is_mariadb()
             && MARIADB_ALIASES.contains(&differ.previous.column_type_family())
             && MARIADB_ALIASES.contains(&differ.next.column_type_family())
@@ -14,7 +18,9 @@ impl SqlSchemaDifferFlavour for MysqlFlavour {
             return false;
         }
 
-        if differ.previous.column_type_family() != differ.next.column_type_family() {
+        if differ.previous.column_type_family() != differ.next.column_type_family()
+            || differ.character_maximum_length_changed()
+        {
             return true;
         }
 
@@ -5,15 +5,24 @@ pub use error_rendering::render_error;
 pub use rpc::*;
 
 use crate::{commands::*, migration_engine::MigrationEngine, CoreResult};
+use futures::Future;
 use migration_connector::*;
+use std::time::Duration;
 use tracing_futures::Instrument;
 
+/// How long commands modifying the database wait for the migration lock by default.
+const DEFAULT_MIGRATION_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The environment variable overriding the migration lock timeout, in seconds.
+const MIGRATION_LOCK_TIMEOUT_ENV_VAR: &str = "MIGRATION_LOCK_TIMEOUT";
+
 pub struct MigrationApi<C, D>
 where
     C: MigrationConnector<DatabaseMigration = D>,
     D: DatabaseMigrationMarker + 'static,
 {
     engine: MigrationEngine<C, D>,
+    lock_timeout: Duration,
 }
 
 impl<C, D> MigrationApi<C, D>
@@ -23,8 +32,13 @@ where
 {
     pub async fn new(connector: C) -> CoreResult<Self> {
         let engine = MigrationEngine::new(connector).await?;
+        let lock_timeout = std::env::var(MIGRATION_LOCK_TIMEOUT_ENV_VAR)
+            .ok()
+            .and_then(|seconds| seconds.parse().ok())
+            .map(Duration::from_secs)
+            .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT);
 
-        Ok(Self { engine })
+        Ok(Self { engine, lock_timeout })
     }
 
     pub async fn handle_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
@@ -37,6 +51,24 @@ where
     pub fn connector(&self) -> &C {
         self.engine.connector()
     }
+
+    /// Run a command while holding the database-level migration lock, so concurrent migration
+    /// engine instances cannot modify the database at the same time.
+    async fn with_migration_lock<T>(&self, command: impl Future<Output = CoreResult<T>>) -> CoreResult<T> {
+        let connector = self.engine.connector();
+
+        connector.acquire_lock(self.lock_timeout).await?;
+        let result = command.await;
+
+        match (connector.release_lock().await, &result) {
+            (Ok(()), _) => (),
+            (Err(err), Ok(_)) => return Err(err.into()),
+            // The error of the command is more useful than the failure to release the lock.
+            (Err(err), Err(_)) => tracing::warn!("Failed to release the migration lock: {}", err),
+        }
+
+        result
+    }
 }
 
 // This is here only to get rid of the generic type parameters due to neon not
@@ -44,6 +76,7 @@ where
 #[async_trait::async_trait]
 pub trait GenericApi: Send + Sync + 'static {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput>;
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput>;
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -53,6 +86,10 @@ pub trait GenericApi: Send + Sync + 'static {
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
+    async fn render_migration_script(
+        &self,
+        input: &RenderMigrationScriptInput,
+    ) -> CoreResult<RenderMigrationScriptOutput>;
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value>;
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
@@ -75,7 +112,7 @@ where
     D: DatabaseMigrationMarker + Send + Sync + 'static,
 {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput> {
-        self.handle_command::<ApplyMigrationCommand<'_>>(input)
+        self.with_migration_lock(self.handle_command::<ApplyMigrationCommand<'_>>(input))
             .instrument(tracing::info_span!(
                 "ApplyMigration",
                 migration_id = input.migration_id.as_str()
@@ -83,6 +120,15 @@ where
             .await
     }
 
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput> {
+        self.with_migration_lock(self.handle_command::<BaselineCommand<'_>>(input))
+            .instrument(tracing::info_span!(
+                "Baseline",
+                migration_id = input.migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
@@ -128,6 +174,15 @@ where
             .await
     }
 
+    async fn render_migration_script(
+        &self,
+        input: &RenderMigrationScriptInput,
+    ) -> CoreResult<RenderMigrationScriptOutput> {
+        self.handle_command::<RenderMigrationScriptCommand<'_>>(input)
+            .instrument(tracing::info_span!("RenderMigrationScript"))
+            .await
+    }
+
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value> {
         self.handle_command::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
@@ -135,13 +190,13 @@ where
     }
 
     async fn schema_push(&self, input: &SchemaPushInput) -> CoreResult<SchemaPushOutput> {
-        self.handle_command::<SchemaPushCommand<'_>>(input)
+        self.with_migration_lock(self.handle_command::<SchemaPushCommand<'_>>(input))
             .instrument(tracing::info_span!("SchemaPush"))
             .await
     }
 
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
-        self.handle_command::<UnapplyMigrationCommand<'_>>(input)
+        self.with_migration_lock(self.handle_command::<UnapplyMigrationCommand<'_>>(input))
             .instrument(tracing::info_span!("UnapplyMigration"))
             .await
     }
@@ -23,6 +23,8 @@ enum RpcCommand {
     SchemaPush,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    RenderMigrationScript,
+    Baseline,
 }
 
 impl RpcCommand {
@@ -38,6 +40,8 @@ impl RpcCommand {
             RpcCommand::SchemaPush => "schemaPush",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::RenderMigrationScript => "renderMigrationScript",
+            RpcCommand::Baseline => "baseline",
         }
     }
 }
@@ -53,6 +57,8 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::SchemaPush,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::RenderMigrationScript,
+    RpcCommand::Baseline,
 ];
 
 impl RpcApi {
@@ -157,6 +163,14 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 render(executor.calculate_database_steps(&input).await?)
             }
+            RpcCommand::RenderMigrationScript => {
+                let input: RenderMigrationScriptInput = params.clone().parse()?;
+                render(executor.render_migration_script(&input).await?)
+            }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                render(executor.baseline(&input).await?)
+            }
         }
     }
 }
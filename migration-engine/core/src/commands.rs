@@ -1,4 +1,5 @@
 mod apply_migration;
+mod baseline;
 mod calculate_database_steps;
 mod calculate_datamodel;
 mod command;
@@ -6,11 +7,13 @@ mod debug_panic;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
+mod render_migration_script;
 mod reset;
 mod schema_push;
 mod unapply_migration;
 
 pub use apply_migration::*;
+pub use baseline::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::*;
@@ -18,6 +21,7 @@ pub use debug_panic::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
+pub use render_migration_script::*;
 pub use reset::*;
 pub use schema_push::*;
 pub use unapply_migration::*;
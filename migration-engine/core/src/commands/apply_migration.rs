@@ -175,6 +175,7 @@ impl<'a> ApplyMigrationCommand<'a> {
             warnings,
             errors,
             unexecutable_migrations,
+            ..
         } = diagnostics;
 
         Ok(MigrationStepsResultOutput {
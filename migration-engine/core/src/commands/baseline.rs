@@ -0,0 +1,98 @@
+//! The Baseline RPC method.
+//!
+//! Its purpose is to bring a database that was not created by the migration engine under its
+//! control. The current database schema is introspected and recorded as an initial migration that
+//! is already applied, without executing anything against the database.
+
+use super::MigrationCommand;
+use crate::{commands::command::*, migration_engine::MigrationEngine};
+use datamodel::ast::{parser::parse_schema, SchemaAst};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+pub struct BaselineCommand<'a> {
+    pub input: &'a BaselineInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand<'a> {
+    type Input = BaselineInput;
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+
+        if !migration_persistence.load_all().await?.is_empty() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The database already has a migration history, it cannot be baselined."
+            )));
+        }
+
+        let datamodel = connector.introspect_datamodel().await?;
+        let datamodel_string =
+            datamodel::render_datamodel_to_string(&datamodel).map_err(CommandError::DatamodelRenderingError)?;
+        let datamodel_ast = parse_schema(&datamodel_string).map_err(CommandError::ProducedBadDatamodel)?;
+        let datamodel = datamodel::lift_ast_to_datamodel(&datamodel_ast).map_err(CommandError::ProducedBadDatamodel)?;
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&SchemaAst::empty(), &datamodel_ast);
+
+        // The introspected datamodel must describe the database exactly, otherwise the next migration
+        // would try to "fix" the parts of the schema it does not capture.
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer(&datamodel, &datamodel, &datamodel_steps)
+            .await?;
+        let remaining_steps = connector
+            .database_migration_step_applier()
+            .render_steps_pretty(&database_migration)?;
+
+        if !remaining_steps.is_empty() {
+            let remaining_sql: Vec<String> = remaining_steps.into_iter().map(|step| step.raw).collect();
+
+            return Err(CommandError::Generic(anyhow::anyhow!(
+                "The introspected schema does not match the database, so it cannot be used as a baseline. Migrating to it would run:\n{}",
+                remaining_sql.join(";\n")
+            )));
+        }
+
+        let mut migration = Migration::new(NewMigration {
+            name: input.migration_id.clone(),
+            datamodel_string: datamodel_string.clone(),
+            datamodel_steps: datamodel_steps.clone(),
+            database_migration: database_migration.serialize(),
+        });
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.finished_at = Some(Migration::timestamp_without_nanos());
+
+        migration_persistence.create(migration).await?;
+
+        Ok(BaselineOutput {
+            migration_id: input.migration_id.clone(),
+            datamodel: datamodel_string,
+            datamodel_steps,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The name of the baseline migration.
+    pub migration_id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    pub migration_id: String,
+    /// The introspected datamodel, recorded as the state of the database after the baseline migration.
+    pub datamodel: String,
+    pub datamodel_steps: Vec<MigrationStep>,
+}
@@ -55,6 +55,7 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             warnings,
             errors: _,
             unexecutable_migrations,
+            ..
         } = connector
             .destructive_change_checker()
             .check(&database_migration)
@@ -67,6 +67,7 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             warnings,
             errors: _,
             unexecutable_migrations,
+            ..
         } = connector
             .destructive_change_checker()
             .check(&database_migration)
@@ -0,0 +1,75 @@
+//! The RenderMigrationScript RPC method.
+//!
+//! Its purpose is to render the migration from the current database schema to a prisma schema as a
+//! single annotated SQL script, for review before the migration is applied. Nothing is executed
+//! against the database apart from the destructive change checks.
+
+use super::MigrationCommand;
+use crate::parse_datamodel;
+use migration_connector::{DatabaseMigrationMarker, DatabaseMigrationScript, MigrationConnector};
+use serde::{Deserialize, Serialize};
+
+pub struct RenderMigrationScriptCommand<'a> {
+    pub input: &'a RenderMigrationScriptInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for RenderMigrationScriptCommand<'a> {
+    type Input = RenderMigrationScriptInput;
+    type Output = RenderMigrationScriptOutput;
+
+    async fn execute<C, D>(
+        input: &Self::Input,
+        engine: &crate::migration_engine::MigrationEngine<C, D>,
+    ) -> super::CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let schema = parse_datamodel(&input.schema)?;
+        let inferrer = connector.database_migration_inferrer();
+        let applier = connector.database_migration_step_applier();
+        let checker = connector.destructive_change_checker();
+
+        let database_migration = inferrer.infer(&schema, &schema, &[]).await?;
+
+        let diagnostics = checker.check(&database_migration).await?;
+
+        let DatabaseMigrationScript { script, locked_tables } =
+            applier.render_script(&database_migration, &diagnostics)?;
+
+        Ok(RenderMigrationScriptOutput {
+            script,
+            locked_tables,
+            warnings: diagnostics
+                .warnings
+                .into_iter()
+                .map(|warning| warning.description)
+                .collect(),
+            unexecutable: diagnostics
+                .unexecutable_migrations
+                .into_iter()
+                .map(|unexecutable| unexecutable.description)
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderMigrationScriptInput {
+    /// The prisma schema.
+    pub schema: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderMigrationScriptOutput {
+    /// The SQL script, with diagnostics as comments.
+    pub script: String,
+    /// The existing tables expected to be locked while the migration runs.
+    pub locked_tables: Vec<String>,
+    pub warnings: Vec<String>,
+    pub unexecutable: Vec<String>,
+}
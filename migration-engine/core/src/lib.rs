@@ -21,6 +21,9 @@ use migration_connector::ConnectorError;
 use sql_migration_connector::SqlMigrationConnector;
 use std::sync::Arc;
 
+/// The datasource preview feature enabling online migrations on PostgreSQL.
+const ONLINE_MIGRATIONS_PREVIEW_FEATURE: &str = "onlineMigrations";
+
 pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericApi>> {
     let config = datamodel::parse_configuration(datamodel)?;
 
@@ -52,7 +55,12 @@ pub async fn migration_api(datamodel: &str) -> CoreResult<Arc<dyn api::GenericAp
                 u.query_pairs_mut().append_pair("statement_cache_size", "0");
             }
 
-            SqlMigrationConnector::new(u.as_str()).await?
+            let online_migrations = source
+                .preview_features
+                .iter()
+                .any(|feature| feature == ONLINE_MIGRATIONS_PREVIEW_FEATURE);
+
+            SqlMigrationConnector::new_with_online_migrations(u.as_str(), online_migrations).await?
         }
         #[cfg(feature = "sql")]
         provider if [MYSQL_SOURCE_NAME, SQLITE_SOURCE_NAME].contains(&provider.as_str()) => {
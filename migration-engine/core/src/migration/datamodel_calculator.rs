@@ -198,6 +198,9 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         fields: vec![],
         directives: vec![],
         commented_out: false,
+        is_embedded: step.embedded,
+        is_abstract: step.r#abstract,
+        extends: step.extends.iter().cloned().map(new_ident).collect(),
     };
 
     datamodel.tops.push(ast::Top::Model(model));
@@ -214,6 +217,8 @@ fn apply_update_model(datamodel: &mut ast::SchemaAst, step: &steps::UpdateModel)
     })?;
 
     apply_model_update(model, &step.new_name, update_model_name);
+    apply_model_update(model, &step.r#abstract, update_model_abstract);
+    apply_model_update(model, &step.extends, update_model_extends);
 
     Ok(())
 }
@@ -228,6 +233,14 @@ fn update_model_name(model: &mut ast::Model, new_name: &String) {
     model.name = new_ident(new_name.clone());
 }
 
+fn update_model_abstract(model: &mut ast::Model, is_abstract: &bool) {
+    model.is_abstract = *is_abstract;
+}
+
+fn update_model_extends(model: &mut ast::Model, extends: &Vec<String>) {
+    model.extends = extends.iter().cloned().map(new_ident).collect();
+}
+
 fn apply_delete_model(datamodel: &mut ast::SchemaAst, step: &steps::DeleteModel) -> Result<(), CalculatorError> {
     datamodel.find_model(&step.model).ok_or_else(|| {
         format_err!(
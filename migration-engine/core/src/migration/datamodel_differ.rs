@@ -278,6 +278,9 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
         let create_model_step = steps::CreateModel {
             model: created_model.name.name.clone(),
+            embedded: created_model.is_embedded,
+            r#abstract: created_model.is_abstract,
+            extends: extended_model_names(created_model),
         };
 
         steps.push(MigrationStep::CreateModel(create_model_step));
@@ -311,6 +314,17 @@ fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = Model
     models.for_each(|model| {
         let model_name = &model.previous.name.name;
 
+        let update_model_step = steps::UpdateModel {
+            model: model_name.clone(),
+            new_name: None,
+            r#abstract: diff_value(&model.previous.is_abstract, &model.next.is_abstract),
+            extends: diff_value(&extended_model_names(model.previous), &extended_model_names(model.next)),
+        };
+
+        if update_model_step.is_any_option_set() {
+            steps.push(MigrationStep::UpdateModel(update_model_step));
+        }
+
         push_created_fields(steps, model_name, model.created_fields());
         push_deleted_fields(steps, model_name, model.deleted_fields());
         push_updated_fields(steps, model_name, model.field_pairs());
@@ -334,6 +348,10 @@ fn push_updated_models<'a>(steps: &mut Steps, models: impl Iterator<Item = Model
     });
 }
 
+fn extended_model_names(model: &ast::Model) -> Vec<String> {
+    model.extends.iter().map(|parent| parent.name.clone()).collect()
+}
+
 fn push_created_fields<'a>(steps: &mut Steps, model_name: &'a str, fields: impl Iterator<Item = &'a ast::Field>) {
     for field in fields {
         let create_field_step = steps::CreateField {
@@ -417,6 +417,17 @@ impl<'a> ColumnAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_is_list(self) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.0.tpe.arity.is_list(),
+            "Assertion failed. Expected column `{}` to be a list, got {:?}",
+            self.0.name,
+            self.0.tpe.arity,
+        );
+
+        Ok(self)
+    }
 }
 
 pub struct PrimaryKeyAssertion<'a> {
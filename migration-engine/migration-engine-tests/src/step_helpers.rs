@@ -31,5 +31,8 @@ pub fn create_id_directive_step(model: &str, field: &str) -> MigrationStep {
 pub fn create_model_step(model: &str) -> MigrationStep {
     MigrationStep::CreateModel(CreateModel {
         model: model.to_string(),
+        embedded: false,
+        r#abstract: false,
+        extends: vec![],
     })
 }
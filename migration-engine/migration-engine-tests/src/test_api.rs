@@ -21,7 +21,7 @@ use super::{
 use migration_connector::{MigrationPersistence, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{ApplyMigrationInput, BaselineInput, BaselineOutput},
 };
 use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector, MIGRATION_TABLE_NAME};
@@ -37,6 +37,7 @@ pub struct TestApi {
     database: Arc<dyn Queryable + Send + Sync + 'static>,
     api: MigrationApi<SqlMigrationConnector, SqlMigration>,
     connection_info: ConnectionInfo,
+    database_url: String,
 }
 
 impl TestApi {
@@ -76,6 +77,24 @@ impl TestApi {
         &self.connection_info
     }
 
+    pub fn connector(&self) -> &SqlMigrationConnector {
+        self.api.connector()
+    }
+
+    /// Open a new, independent connector to the test database.
+    pub async fn new_connector(&self) -> SqlMigrationConnector {
+        SqlMigrationConnector::new(&self.database_url).await.unwrap()
+    }
+
+    /// A migration API on the test database that renders online migrations.
+    pub async fn online_migrations_api(&self) -> MigrationApi<SqlMigrationConnector, SqlMigration> {
+        let connector = SqlMigrationConnector::new_with_online_migrations(&self.database_url, true)
+            .await
+            .unwrap();
+
+        test_api(connector).await
+    }
+
     pub fn sql_family(&self) -> SqlFamily {
         self.connection_info().sql_family()
     }
@@ -160,6 +179,14 @@ impl TestApi {
         }
     }
 
+    pub async fn baseline(&self, migration_id: &str) -> Result<BaselineOutput, migration_core::error::Error> {
+        let input = BaselineInput {
+            migration_id: migration_id.to_owned(),
+        };
+
+        self.api.baseline(&input).await
+    }
+
     pub fn schema_push<'a>(&'a self, dm: impl Into<String>) -> SchemaPush<'a> {
         SchemaPush::new(&self.api, dm.into())
     }
@@ -288,6 +315,7 @@ pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql_8",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -301,6 +329,7 @@ pub async fn mysql_5_6_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql_5_6",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -314,6 +343,7 @@ pub async fn mysql_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -327,6 +357,7 @@ pub async fn mysql_mariadb_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "mysql_mariadb",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -340,6 +371,7 @@ pub async fn postgres9_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres9",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -353,6 +385,7 @@ pub async fn postgres_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -366,6 +399,7 @@ pub async fn postgres11_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres11",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -379,6 +413,7 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres12",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
@@ -392,18 +427,21 @@ pub async fn postgres13_test_api(db_name: &str) -> TestApi {
     TestApi {
         connector_name: "postgres13",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
 }
 
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
-    let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
+    let url = sqlite_test_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
 
     TestApi {
         connector_name: "sqlite",
         connection_info,
+        database_url: url,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
     }
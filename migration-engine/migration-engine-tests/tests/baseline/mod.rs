@@ -0,0 +1,56 @@
+use barrel::types;
+use migration_connector::MigrationStatus;
+use migration_engine_tests::sql::*;
+
+#[test_each_connector]
+async fn baselining_an_existing_database_records_an_applied_migration(api: &TestApi) -> TestResult {
+    let initial_schema = api
+        .barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await?;
+
+    let output = api.baseline("baseline").await?;
+
+    assert!(output.datamodel.contains("model Cat"));
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, "baseline");
+    assert_eq!(migrations[0].status, MigrationStatus::MigrationSuccess);
+
+    // Nothing was executed.
+    api.assert_schema().await?.assert_equals(&initial_schema)?;
+
+    // The next migration starts from the baselined schema.
+    api.infer_apply(&output.datamodel)
+        .migration_id(Some("after-baseline"))
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_a_database_with_a_migration_history_fails(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let err = api.baseline("baseline").await.unwrap_err().to_string();
+
+    assert!(err.contains("already has a migration history"), "{}", err);
+
+    Ok(())
+}
@@ -59,6 +59,75 @@ fn add_UpdateModel_to_existing_schema() {
     test(dm1, dm2);
 }
 
+#[test]
+fn add_abstract_CreateModel_to_existing_schema() {
+    let dm1 = SchemaAst::empty();
+    let dm2 = parse(
+        r#"
+        abstract model Base {
+            id String @id @default(cuid())
+        }
+
+        model Test extends Base {
+            name String
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
+#[test]
+fn add_UpdateModel_with_extends_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        abstract model Timestamps {
+            createdAt DateTime @default(now())
+        }
+
+        model Post {
+            id String @id @default(cuid())
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        abstract model Timestamps {
+            createdAt DateTime @default(now())
+        }
+
+        model Post extends Timestamps {
+            id String @id @default(cuid())
+        }
+    "#,
+    );
+
+    test(dm1.clone(), dm2.clone());
+    test(dm2, dm1);
+}
+
+#[test]
+fn add_UpdateModel_making_a_model_abstract_to_existing_schema() {
+    let dm1 = parse(
+        r#"
+        model Timestamps {
+            id        String   @id @default(cuid())
+            createdAt DateTime @default(now())
+        }
+    "#,
+    );
+    let dm2 = parse(
+        r#"
+        abstract model Timestamps {
+            id        String   @id @default(cuid())
+            createdAt DateTime @default(now())
+        }
+    "#,
+    );
+
+    test(dm1, dm2);
+}
+
 #[test]
 fn add_CreateField_to_existing_schema() {
     let dm1 = parse(
@@ -194,6 +263,9 @@ fn creating_a_model_that_already_exists_must_error() {
 
     let steps = &[MigrationStep::CreateModel(CreateModel {
         model: "Test".to_string(),
+        embedded: false,
+        r#abstract: false,
+        extends: vec![],
     })];
 
     assert_eq!(
@@ -343,6 +415,8 @@ fn updating_a_model_that_does_not_exist_must_error() {
     let steps = &[MigrationStep::UpdateModel(UpdateModel {
         model: "Test".to_string(),
         new_name: None,
+        r#abstract: None,
+        extends: None,
     })];
 
     assert_eq!(
@@ -20,6 +20,9 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
     let expected = &[
         MigrationStep::CreateModel(CreateModel {
             model: "Test".to_string(),
+            embedded: false,
+            r#abstract: false,
+            extends: vec![],
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -1460,6 +1463,9 @@ fn infer_CreateTypeAlias() {
         }),
         MigrationStep::CreateModel(CreateModel {
             model: "User".to_string(),
+            embedded: false,
+            r#abstract: false,
+            extends: vec![],
         }),
         MigrationStep::CreateField(CreateField {
             model: "User".to_string(),
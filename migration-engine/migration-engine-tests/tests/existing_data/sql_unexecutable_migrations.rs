@@ -1,3 +1,4 @@
 mod added_required_field_to_table;
 mod added_unimplementable_unique_constraint;
+mod incompatible_type_change;
 mod made_optional_field_required;
@@ -0,0 +1,381 @@
+use migration_engine_tests::sql::*;
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_a_string_column_to_int_with_non_numeric_values_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", "twelve")
+        .result_raw()
+        .await?;
+    api.insert("Test").value("id", "ghi").result_raw().await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `age` on the `Test` table, but 1 existing values are not valid integers between -2147483648 and 2147483647, for example `twelve`. This migration step cannot be executed.".into(),
+        ])?;
+
+    let rows = api.select("Test").column("age").send_debug().await?;
+    assert_eq!(
+        rows,
+        &[&[r#"Text(Some("12"))"#], &[r#"Text(Some("twelve"))"#], &["Text(None)"]]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_a_string_column_to_int_with_out_of_range_values_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", "3000000000")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `age` on the `Test` table, but 1 existing values are not valid integers between -2147483648 and 2147483647, for example `3000000000`. This migration step cannot be executed.".into(),
+        ])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_a_string_column_to_int_with_numeric_values_is_not_reported_as_unexecutable(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    let output = api.infer_apply(&dm2).send().await?.into_inner();
+
+    assert!(output.unexecutable_migrations.is_empty());
+
+    let rows = api.select("Test").column("age").send_debug().await?;
+    assert_eq!(rows, &[&["Integer(Some(12))"]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_a_float_column_to_int_warns_about_and_rounds_fractional_values(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            weight Float?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("weight", 12.0)
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("weight", 12.6)
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            weight Int?
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .force(Some(true))
+        .send()
+        .await?
+        .assert_warnings(&["You are about to change the type of the column `weight` on the `Test` table. 1 existing values have a fractional part, for example `12.600000000000000000000000000000`. They will be rounded.".into()])?
+        .assert_executable()?;
+
+    let rows = api.select("Test").column("weight").send_debug().await?;
+    assert_eq!(rows, &[&["Integer(Some(12))"], &["Integer(Some(13))"]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn shortening_a_varchar_column_with_longer_values_is_unexecutable(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgresql://localhost/test"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Test {
+            id String @id
+            name String? @pg.VarChar(255)
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "Tom")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("name", "Maximilian")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgresql://localhost/test"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Test {
+            id String @id
+            name String? @pg.VarChar(5)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `name` on the `Test` table, but 1 existing values are longer than 5 characters, for example `Maximilian`. This migration step cannot be executed.".into(),
+        ])?;
+
+    let rows = api.select("Test").column("name").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("Tom"))"#], &[r#"Text(Some("Maximilian"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn changing_a_string_column_to_int_with_non_numeric_values_is_unexecutable_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", "twelve")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `age` on the `Test` table, but 1 existing values are not valid integers between -2147483648 and 2147483647, for example `twelve`. This migration step cannot be executed.".into(),
+        ])?;
+
+    let rows = api.select("Test").column("age").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("12"))"#], &[r#"Text(Some("twelve"))"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn changing_a_string_column_to_int_with_out_of_range_values_is_unexecutable_on_mysql(
+    api: &TestApi,
+) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "12")
+        .result_raw()
+        .await?;
+    api.insert("Test")
+        .value("id", "def")
+        .value("age", "3000000000")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(&dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `age` on the `Test` table, but 1 existing values are not valid integers between -2147483648 and 2147483647, for example `3000000000`. This migration step cannot be executed.".into(),
+        ])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn shortening_a_varchar_column_with_longer_values_is_unexecutable_on_mysql(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost/test"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Test {
+            id String @id
+            name String? @mysql.VarChar(255)
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "Maximilian")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        datasource db {
+            provider = "mysql"
+            url = "mysql://localhost/test"
+            previewFeatures = ["nativeTypes"]
+        }
+
+        model Test {
+            id String @id
+            name String? @mysql.VarChar(5)
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_unexecutable(&[
+            "Changed the type of the column `name` on the `Test` table, but 1 existing values are longer than 5 characters, for example `Maximilian`. This migration step cannot be executed.".into(),
+        ])?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn changing_a_string_column_to_int_on_sqlite_warns_about_the_altered_column(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id String @id
+            age String?
+        }
+    "#;
+
+    api.infer_apply(&dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("age", "twelve")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id String @id
+            age Int?
+        }
+    "#;
+
+    // SQLite does not check the values against the new type, so there is no unexecutable step.
+    api.infer_apply(&dm2)
+        .send()
+        .await?
+        .assert_warnings(&["You are about to alter the column `age` on the `Test` table, which still contains 1 non-null values. The data in that column could be lost.".into()])?
+        .assert_executable()?;
+
+    Ok(())
+}
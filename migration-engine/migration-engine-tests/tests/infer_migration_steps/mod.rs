@@ -1,4 +1,7 @@
-use migration_connector::PrettyDatabaseMigrationStep;
+use migration_connector::{
+    steps::{CreateModel, MigrationStep, UpdateModel},
+    PrettyDatabaseMigrationStep,
+};
 use migration_core::commands::AppliedMigration;
 use migration_engine_tests::sql::*;
 use pretty_assertions::assert_eq;
@@ -326,3 +329,114 @@ async fn watch_migrations_must_be_returned_in_addition_to_regular_inferred_steps
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn abstract_models_and_the_models_extending_them_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm = r#"
+        abstract model Base {
+            id Int @id
+            createdAt DateTime @default(now())
+        }
+
+        model Cat extends Base {
+            name String
+        }
+    "#;
+
+    let steps = api
+        .infer_apply(dm)
+        .send()
+        .await?
+        .assert_green()?
+        .into_inner()
+        .datamodel_steps;
+
+    assert!(steps.contains(&MigrationStep::CreateModel(CreateModel {
+        model: "Base".to_owned(),
+        embedded: false,
+        r#abstract: true,
+        extends: vec![],
+    })));
+    assert!(steps.contains(&MigrationStep::CreateModel(CreateModel {
+        model: "Cat".to_owned(),
+        embedded: false,
+        r#abstract: false,
+        extends: vec!["Base".to_owned()],
+    })));
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Cat", |table| {
+            table
+                .assert_columns_count(3)?
+                .assert_has_column("id")?
+                .assert_has_column("createdAt")?
+                .assert_has_column("name")
+        })?;
+
+    // The datamodel calculated from the applied steps must match the schema.
+    let steps = api.infer(dm).send().await?.datamodel_steps;
+
+    assert!(steps.is_empty(), "{:?}", steps);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn extending_an_abstract_model_must_be_migrated(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        abstract model Timestamps {
+            createdAt DateTime @default(now())
+        }
+
+        model Cat {
+            id Int @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        abstract model Timestamps {
+            createdAt DateTime @default(now())
+        }
+
+        model Cat extends Timestamps {
+            id Int @id
+            name String
+        }
+    "#;
+
+    let steps = api
+        .infer_apply(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .into_inner()
+        .datamodel_steps;
+
+    assert_eq!(
+        steps,
+        &[MigrationStep::UpdateModel(UpdateModel {
+            model: "Cat".to_owned(),
+            new_name: None,
+            r#abstract: None,
+            extends: Some(vec!["Timestamps".to_owned()]),
+        })]
+    );
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Cat", |table| {
+            table.assert_columns_count(3)?.assert_has_column("createdAt")
+        })?;
+
+    let steps = api.infer(dm2).send().await?.datamodel_steps;
+
+    assert!(steps.is_empty(), "{:?}", steps);
+
+    Ok(())
+}
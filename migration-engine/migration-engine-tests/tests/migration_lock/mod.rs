@@ -0,0 +1,26 @@
+use migration_connector::{ErrorKind, MigrationConnector};
+use migration_engine_tests::sql::*;
+use std::time::Duration;
+
+#[test_each_connector]
+async fn the_migration_lock_cannot_be_held_by_two_connectors(api: &TestApi) -> TestResult {
+    let other_connector = api.new_connector().await;
+
+    other_connector.acquire_lock(Duration::from_secs(1)).await?;
+
+    let err = api
+        .connector()
+        .acquire_lock(Duration::from_millis(500))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(err.kind, ErrorKind::MigrationLockTimeout { .. }));
+    assert_eq!(err.user_facing_error.unwrap().error_code, "P3005");
+
+    other_connector.release_lock().await?;
+
+    api.connector().acquire_lock(Duration::from_secs(1)).await?;
+    api.connector().release_lock().await?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 mod apply_migration;
+mod baseline;
 mod calculate_database_steps;
 mod datamodel_calculator;
 mod datamodel_steps_inferrer;
@@ -6,6 +7,7 @@ mod errors;
 mod existing_data;
 mod existing_databases;
 mod infer_migration_steps;
+mod migration_lock;
 mod migration_persistence;
 mod migrations;
 mod multi_user;
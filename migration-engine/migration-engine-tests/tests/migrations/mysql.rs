@@ -282,3 +282,44 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("mysql"))]
+async fn unsupported_type_columns_are_rendered_verbatim_and_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Place {
+            id     Int                          @id
+            area   Unsupported("multipolygon")?
+            bitmap Unsupported("bit(8)")?
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Place", |table| {
+        table
+            .assert_column("area", |col| col.assert_full_data_type("multipolygon"))?
+            .assert_column("bitmap", |col| col.assert_full_data_type("bit(8)"))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Place {
+            id     Int                          @id
+            area   Unsupported("multipolygon")?
+            bitmap Unsupported("bit(16)")?
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Place", |table| {
+        table.assert_column("bitmap", |col| col.assert_full_data_type("bit(16)"))
+    })?;
+
+    Ok(())
+}
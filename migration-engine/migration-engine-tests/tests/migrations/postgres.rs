@@ -161,3 +161,132 @@ async fn native_type_columns_can_be_created(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn online_migrations_avoid_long_locks_on_existing_tables(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+
+        model Box {
+            id String @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.insert("Cat").value("id", "felix").value("name", "Felix").result_raw().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            name String @unique
+            lives Int @default(9)
+            boxId String?
+            box Box? @relation(fields: [boxId], references: [id])
+        }
+
+        model Box {
+            id String @id
+            cats Cat[]
+        }
+    "#;
+
+    let online_api = api.online_migrations_api().await;
+    let output = InferApply::new(&online_api, dm2).send().await?.assert_green()?.into_inner();
+    let rendered_steps: Vec<&str> = output.database_steps.iter().map(|step| step.raw.as_str()).collect();
+
+    assert!(rendered_steps
+        .iter()
+        .any(|sql| sql.starts_with("CREATE UNIQUE INDEX CONCURRENTLY")));
+    assert!(rendered_steps.iter().any(|sql| sql.contains("NOT VALID")));
+    assert!(rendered_steps.iter().any(|sql| sql.contains("VALIDATE CONSTRAINT")));
+    assert!(rendered_steps
+        .iter()
+        .any(|sql| sql.contains(r#"ALTER COLUMN "lives" SET NOT NULL"#)));
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_column("lives", |col| col.assert_is_required())?
+            .assert_fk_on_columns(&["boxId"], |fk| fk.assert_references("Box", &["id"]))
+    })?;
+
+    let rows = api.select("Cat").column("id").column("lives").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text(Some("felix"))"#, "Integer(Some(9))"]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unsupported_type_columns_are_rendered_verbatim_and_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Document {
+            id       Int                      @id
+            search   Unsupported("tsvector")?
+            location Unsupported("point")?
+            prices   Unsupported("money")[]
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Document", |table| {
+        table
+            .assert_column("search", |col| col.assert_full_data_type("tsvector"))?
+            .assert_column("location", |col| col.assert_full_data_type("point"))?
+            .assert_column("prices", |col| col.assert_full_data_type("_money")?.assert_is_list())
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn unsupported_type_columns_are_diffed_by_their_raw_type(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Document {
+            id     Int                      @id
+            search Unsupported("tsvector")?
+        }
+    "#;
+
+    api.schema_push(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Document {
+            id     Int                     @id
+            search Unsupported("tsquery")?
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Document", |table| {
+        table.assert_column("search", |col| col.assert_full_data_type("tsquery"))
+    })?;
+
+    let dm3 = r#"
+        model Document {
+            id     Int    @id
+            search String?
+        }
+    "#;
+
+    api.schema_push(dm3)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Document", |table| {
+        table.assert_column("search", |col| col.assert_type_family(ColumnTypeFamily::String))
+    })?;
+
+    Ok(())
+}
@@ -88,3 +88,40 @@ async fn creating_a_model_with_a_non_autoincrement_id_column_is_idempotent(api:
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn unsupported_type_columns_are_rendered_verbatim_and_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Place {
+            id       Int                      @id
+            location Unsupported("geometry")?
+        }
+    "#;
+
+    api.schema_push(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Place", |table| {
+        table.assert_column("location", |col| col.assert_full_data_type("geometry"))
+    })?;
+
+    api.schema_push(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Place {
+            id       Int                   @id
+            location Unsupported("point")?
+        }
+    "#;
+
+    api.schema_push(dm2)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_has_executed_steps()?;
+
+    api.assert_schema().await?.assert_table("Place", |table| {
+        table.assert_column("location", |col| col.assert_full_data_type("point"))
+    })?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
-use crate::{LintOpts, MiniError};
-use datamodel::error::DatamodelError;
+use crate::{LintOpts, MiniError, MiniQuickFix};
+use datamodel::{error::DatamodelError, lint::LintDiagnostic};
 use serde_json;
 use std::io::{self, Read};
 
@@ -16,22 +16,39 @@ pub fn run(opts: LintOpts) {
         datamodel::parse_datamodel(&datamodel_string)
     };
 
-    match datamodel_result {
-        Err(err) => {
-            let mini_errors: Vec<MiniError> = err
-                .errors
-                .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
-                })
-                .collect();
+    let mini_errors: Vec<MiniError> = match datamodel_result {
+        Err(err) => err
+            .errors
+            .iter()
+            .map(|err: &DatamodelError| MiniError {
+                start: err.span().start,
+                end: err.span().end,
+                text: format!("{}", err),
+                rule: None,
+                severity: None,
+                fixes: err
+                    .quick_fixes(&datamodel_string)
+                    .into_iter()
+                    .map(MiniQuickFix::from)
+                    .collect(),
+            })
+            .collect(),
+        Ok(_) if opts.rules => datamodel::lint_datamodel(&datamodel_string)
+            .unwrap_or_default()
+            .iter()
+            .map(|diagnostic: &LintDiagnostic| MiniError {
+                start: diagnostic.span.start,
+                end: diagnostic.span.end,
+                text: diagnostic.message.clone(),
+                rule: Some(diagnostic.rule),
+                severity: Some(diagnostic.severity),
+                fixes: Vec::new(),
+            })
+            .collect(),
+        Ok(_) => Vec::new(),
+    };
 
-            let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
+    let json = serde_json::to_string(&mini_errors).expect("Failed to render JSON");
 
-            print!("{}", json)
-        }
-        _ => print!("[]"),
-    }
+    print!("{}", json)
 }
@@ -0,0 +1,264 @@
+//! A language server for prisma schemas, speaking LSP over stdio.
+
+mod analysis;
+mod code_action;
+mod completion;
+mod docs;
+mod document;
+mod hover;
+mod navigation;
+
+use datamodel::lint::Severity;
+use document::Document;
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, Notification as _, PublishDiagnostics},
+    request::{CodeActionRequest, Completion, GotoDefinition, HoverRequest, Rename, Request as _},
+    CodeActionParams, CompletionParams, CompletionResponse, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, GotoDefinitionParams, GotoDefinitionResponse, HoverParams,
+    NumberOrString, PublishDiagnosticsParams, RenameParams, Url,
+};
+use serde::de::DeserializeOwned;
+use serde_json::json;
+use std::collections::HashMap;
+
+type ServerResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+pub fn run() {
+    serve().expect("The language server failed.")
+}
+
+fn serve() -> ServerResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    connection.initialize(json!({
+        "textDocumentSync": 1,
+        "completionProvider": { "triggerCharacters": ["@", "."] },
+        "hoverProvider": true,
+        "definitionProvider": true,
+        "renameProvider": true,
+        "codeActionProvider": true,
+    }))?;
+
+    let mut documents: HashMap<Url, Document> = HashMap::new();
+
+    for message in &connection.receiver {
+        match message {
+            Message::Request(request) => {
+                if connection.handle_shutdown(&request)? {
+                    break;
+                }
+
+                let response = handle_request(&documents, request)?;
+                connection.sender.send(Message::Response(response))?;
+            }
+            Message::Notification(notification) => {
+                if let Some(uri) = handle_notification(&mut documents, notification)? {
+                    publish_diagnostics(&connection, &uri, documents.get(&uri))?;
+                }
+            }
+            Message::Response(_) => (),
+        }
+    }
+
+    io_threads.join()?;
+
+    Ok(())
+}
+
+fn handle_request(documents: &HashMap<Url, Document>, request: Request) -> ServerResult<Response> {
+    let Request { id, method, params } = request;
+
+    let result = match method.as_str() {
+        Completion::METHOD => {
+            let params: CompletionParams = match request_params(&id, params) {
+                Ok(params) => params,
+                Err(response) => return Ok(response),
+            };
+            let position = params.text_document_position;
+
+            let items = documents
+                .get(&position.text_document.uri)
+                .map(|document| completion::completions(document, document.offset(position.position)))
+                .unwrap_or_default();
+
+            serde_json::to_value(CompletionResponse::Array(items))?
+        }
+        HoverRequest::METHOD => {
+            let params: HoverParams = match request_params(&id, params) {
+                Ok(params) => params,
+                Err(response) => return Ok(response),
+            };
+            let position = params.text_document_position_params;
+
+            let hover = documents
+                .get(&position.text_document.uri)
+                .and_then(|document| hover::hover(document, document.offset(position.position)));
+
+            serde_json::to_value(hover)?
+        }
+        GotoDefinition::METHOD => {
+            let params: GotoDefinitionParams = match request_params(&id, params) {
+                Ok(params) => params,
+                Err(response) => return Ok(response),
+            };
+            let position = params.text_document_position_params;
+            let uri = &position.text_document.uri;
+
+            let location = documents
+                .get(uri)
+                .and_then(|document| navigation::definition(uri, document, document.offset(position.position)));
+
+            serde_json::to_value(location.map(GotoDefinitionResponse::Scalar))?
+        }
+        Rename::METHOD => {
+            let params: RenameParams = match request_params(&id, params) {
+                Ok(params) => params,
+                Err(response) => return Ok(response),
+            };
+            let position = params.text_document_position;
+            let uri = &position.text_document.uri;
+
+            let edit = documents.get(uri).and_then(|document| {
+                navigation::rename(uri, document, document.offset(position.position), &params.new_name)
+            });
+
+            serde_json::to_value(edit)?
+        }
+        CodeActionRequest::METHOD => {
+            let params: CodeActionParams = match request_params(&id, params) {
+                Ok(params) => params,
+                Err(response) => return Ok(response),
+            };
+            let uri = &params.text_document.uri;
+
+            let actions = documents
+                .get(uri)
+                .map(|document| code_action::code_actions(uri, document, params.range))
+                .unwrap_or_default();
+
+            serde_json::to_value(actions)?
+        }
+        _ => return Ok(method_not_found(id, &method)),
+    };
+
+    Ok(Response::new_ok(id, result))
+}
+
+/// Keeps the open documents in sync, and returns the URI of the document that changed, if any.
+fn handle_notification(documents: &mut HashMap<Url, Document>, notification: Notification) -> ServerResult<Option<Url>> {
+    let Notification { method, params } = notification;
+
+    match method.as_str() {
+        DidOpenTextDocument::METHOD => {
+            let params: DidOpenTextDocumentParams = match notification_params(&method, params) {
+                Some(params) => params,
+                None => return Ok(None),
+            };
+            let uri = params.text_document.uri;
+
+            documents.insert(uri.clone(), Document::new(params.text_document.text));
+
+            Ok(Some(uri))
+        }
+        DidChangeTextDocument::METHOD => {
+            let params: DidChangeTextDocumentParams = match notification_params(&method, params) {
+                Some(params) => params,
+                None => return Ok(None),
+            };
+            let uri = params.text_document.uri;
+
+            // With full synchronization, the last change holds the whole text.
+            if let (Some(document), Some(change)) = (documents.get_mut(&uri), params.content_changes.into_iter().last()) {
+                document.update(change.text);
+            }
+
+            Ok(Some(uri))
+        }
+        DidCloseTextDocument::METHOD => {
+            let params: DidCloseTextDocumentParams = match notification_params(&method, params) {
+                Some(params) => params,
+                None => return Ok(None),
+            };
+            let uri = params.text_document.uri;
+
+            documents.remove(&uri);
+
+            Ok(Some(uri))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Validate and lint the document and send its errors and lint violations to the client. Closed
+/// documents get their diagnostics cleared.
+fn publish_diagnostics(connection: &Connection, uri: &Url, document: Option<&Document>) -> ServerResult<()> {
+    let diagnostics = document
+        .map(|document| match datamodel::lint_datamodel(&document.text) {
+            Err(errors) => errors
+                .errors
+                .iter()
+                .map(|error| Diagnostic {
+                    range: document.range(error.span()),
+                    severity: Some(DiagnosticSeverity::Error),
+                    source: Some("prisma".to_owned()),
+                    message: format!("{}", error),
+                    ..Default::default()
+                })
+                .collect(),
+            Ok(lint_diagnostics) => lint_diagnostics
+                .iter()
+                .map(|diagnostic| Diagnostic {
+                    range: document.range(diagnostic.span),
+                    severity: Some(match diagnostic.severity {
+                        Severity::Error => DiagnosticSeverity::Error,
+                        _ => DiagnosticSeverity::Warning,
+                    }),
+                    code: Some(NumberOrString::String(diagnostic.rule.to_owned())),
+                    source: Some("prisma".to_owned()),
+                    message: diagnostic.message.clone(),
+                    ..Default::default()
+                })
+                .collect(),
+        })
+        .unwrap_or_default();
+
+    let params = PublishDiagnosticsParams::new(uri.clone(), diagnostics, None);
+    let notification = Notification::new(PublishDiagnostics::METHOD.to_owned(), params);
+
+    connection.sender.send(Message::Notification(notification))?;
+
+    Ok(())
+}
+
+/// Deserializes the params of a request. Invalid params are answered with an error response, so a
+/// misbehaving client doesn't bring the server down.
+fn request_params<P: DeserializeOwned>(id: &RequestId, params: serde_json::Value) -> Result<P, Response> {
+    serde_json::from_value(params).map_err(|err| {
+        Response::new_err(
+            id.clone(),
+            lsp_server::ErrorCode::InvalidParams as i32,
+            format!("Invalid params: {}", err),
+        )
+    })
+}
+
+/// Deserializes the params of a notification. Notifications can't be answered, so invalid params are
+/// logged to stderr and the notification is ignored.
+fn notification_params<P: DeserializeOwned>(method: &str, params: serde_json::Value) -> Option<P> {
+    match serde_json::from_value(params) {
+        Ok(params) => Some(params),
+        Err(err) => {
+            eprintln!("Ignoring the `{}` notification with invalid params: {}", method, err);
+            None
+        }
+    }
+}
+
+fn method_not_found(id: RequestId, method: &str) -> Response {
+    Response::new_err(
+        id,
+        lsp_server::ErrorCode::MethodNotFound as i32,
+        format!("Unsupported method: {}", method),
+    )
+}
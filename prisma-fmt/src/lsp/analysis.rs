@@ -0,0 +1,326 @@
+//! Resolution of the names in a schema AST: what is under the cursor, and where it is referenced.
+
+use datamodel::ast::{self, Expression, SchemaAst, Span, Top};
+
+/// The block attributes whose arguments are lists of fields of the model.
+const FIELD_LIST_BLOCK_DIRECTIVES: &[&str] = &["id", "unique", "index"];
+
+/// A name in the schema, as found under the cursor.
+pub(crate) enum Symbol<'a> {
+    /// A model, enum or type alias, at its declaration or where it is used as a field type.
+    Top(&'a Top),
+    /// A model field, at its declaration or where it is referenced in an attribute.
+    Field { model: &'a ast::Model, field: &'a ast::Field },
+    /// The name of an attribute, e.g. `relation` in `@relation`.
+    Directive { directive: &'a ast::Directive, is_block: bool },
+    /// A built-in scalar type used as a field type.
+    ScalarType(&'a str),
+}
+
+/// A field name used as an attribute argument, e.g. in `@@unique([a, b])` or in
+/// `@relation(fields: [a], references: [b])`.
+pub(crate) struct FieldReference<'a> {
+    pub(crate) model: &'a str,
+    pub(crate) field: &'a str,
+    pub(crate) span: Span,
+}
+
+pub(crate) fn symbol_at<'a>(text: &str, ast: &'a SchemaAst, offset: usize) -> Option<Symbol<'a>> {
+    for top in &ast.tops {
+        if contains(top_name_span(top), offset) {
+            return Some(Symbol::Top(top));
+        }
+
+        let model = match top {
+            Top::Model(model) => model,
+            _ => continue,
+        };
+
+        if let Some(parent) = model.extends.iter().find(|parent| contains(parent.span, offset)) {
+            return find_top(ast, &parent.name).map(Symbol::Top);
+        }
+
+        for field in &model.fields {
+            if contains(field.name.span, offset) {
+                return Some(Symbol::Field { model, field });
+            }
+
+            if contains(type_name_span(text, field), offset) {
+                return Some(
+                    find_top(ast, &field.field_type.name)
+                        .map(Symbol::Top)
+                        .unwrap_or_else(|| Symbol::ScalarType(&field.field_type.name)),
+                );
+            }
+
+            if let Some(directive) = field.directives.iter().find(|d| contains(d.name.span, offset)) {
+                return Some(Symbol::Directive {
+                    directive,
+                    is_block: false,
+                });
+            }
+        }
+
+        if let Some(directive) = model.directives.iter().find(|d| contains(d.name.span, offset)) {
+            return Some(Symbol::Directive {
+                directive,
+                is_block: true,
+            });
+        }
+
+        if let Some(reference) = field_references(model).find(|reference| contains(reference.span, offset)) {
+            let (model, field) = find_field(ast, reference.model, reference.field)?;
+
+            return Some(Symbol::Field { model, field });
+        }
+    }
+
+    None
+}
+
+/// The model, enum or type alias with the given name.
+pub(crate) fn find_top<'a>(ast: &'a SchemaAst, name: &str) -> Option<&'a Top> {
+    ast.tops.iter().find(|top| match top {
+        Top::Model(_) | Top::Enum(_) | Top::Type(_) => top.name() == name,
+        Top::Source(_) | Top::Generator(_) => false,
+    })
+}
+
+/// The field with the given name on a model, and the model declaring it: the model itself, or the
+/// abstract model it inherits the field from.
+pub(crate) fn find_field<'a>(ast: &'a SchemaAst, model: &str, field: &str) -> Option<(&'a ast::Model, &'a ast::Field)> {
+    let model = ast.find_model(model)?;
+
+    std::iter::once(model)
+        .chain(model.extends.iter().filter_map(|parent| ast.find_model(&parent.name)))
+        .find_map(|model| {
+            model
+                .fields
+                .iter()
+                .find(|candidate| candidate.name.name == field)
+                .map(|field| (model, field))
+        })
+}
+
+/// The span of the name of a model, enum, type alias, datasource or generator.
+pub(crate) fn top_name_span(top: &Top) -> Span {
+    match top {
+        Top::Enum(enm) => enm.name.span,
+        Top::Model(model) => model.name.span,
+        Top::Source(source) => source.name.span,
+        Top::Generator(generator) => generator.name.span,
+        Top::Type(alias) => alias.name.span,
+    }
+}
+
+/// The span of the type name of a field, without the arity markers.
+pub(crate) fn type_name_span(text: &str, field: &ast::Field) -> Span {
+    let span = field.field_type.span;
+    let start = text
+        .get(span.start..span.end)
+        .and_then(|field_type| field_type.find(field.field_type.name.as_str()))
+        .map(|idx| span.start + idx)
+        .unwrap_or(span.start);
+
+    Span::new(start, start + field.field_type.name.len())
+}
+
+/// All the places where a model, enum or type alias is used as a field type, and where an abstract
+/// model is extended.
+pub(crate) fn type_usages<'a>(text: &'a str, ast: &'a SchemaAst, type_name: &'a str) -> impl Iterator<Item = Span> + 'a {
+    let field_types = ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.fields.iter())
+        .chain(ast.types())
+        .filter(move |field| field.field_type.name == type_name)
+        .map(move |field| type_name_span(text, field));
+
+    let extends = ast
+        .models()
+        .into_iter()
+        .flat_map(|model| model.extends.iter())
+        .filter(move |parent| parent.name == type_name)
+        .map(|parent| parent.span);
+
+    field_types.chain(extends)
+}
+
+/// All the places where a field is referenced in attribute arguments, including the references to a
+/// field of an abstract model in the models extending it.
+pub(crate) fn field_usages<'a>(ast: &'a SchemaAst, model: &'a str, field: &'a str) -> impl Iterator<Item = Span> + 'a {
+    ast.models()
+        .into_iter()
+        .flat_map(field_references)
+        .filter(move |reference| {
+            reference.field == field
+                && find_field(ast, reference.model, reference.field)
+                    .map(|(declaring_model, _)| declaring_model.name.name == model)
+                    .unwrap_or(false)
+        })
+        .map(|reference| reference.span)
+}
+
+/// The field references in the attributes of a model and of its fields.
+pub(crate) fn field_references<'a>(model: &'a ast::Model) -> impl Iterator<Item = FieldReference<'a>> + 'a {
+    let block_references = model
+        .directives
+        .iter()
+        .filter(|directive| FIELD_LIST_BLOCK_DIRECTIVES.contains(&directive.name.name.as_str()))
+        .flat_map(|directive| directive.arguments.iter())
+        .filter(|argument| argument.is_unnamed() || argument.name.name == "fields")
+        .flat_map(move |argument| constants_in(&argument.value, &model.name.name));
+
+    let relation_references = model.fields.iter().flat_map(move |field| {
+        field
+            .directives
+            .iter()
+            .filter(|directive| directive.name.name == "relation")
+            .flat_map(|directive| directive.arguments.iter())
+            .flat_map(move |argument| match argument.name.name.as_str() {
+                "fields" => constants_in(&argument.value, &model.name.name),
+                "references" => constants_in(&argument.value, &field.field_type.name),
+                _ => Vec::new(),
+            })
+    });
+
+    block_references.chain(relation_references)
+}
+
+fn constants_in<'a>(expression: &'a Expression, model: &'a str) -> Vec<FieldReference<'a>> {
+    match expression {
+        Expression::ConstantValue(field, span) => vec![FieldReference {
+            model,
+            field,
+            span: *span,
+        }],
+        Expression::Array(values, _) => values.iter().flat_map(|value| constants_in(value, model)).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+model User {
+  id    Int    @id
+  email String
+  posts Post[]
+
+  @@unique([email])
+}
+
+model Post {
+  id       Int  @id
+  authorId Int
+  author   User @relation(fields: [authorId], references: [id])
+}
+"#;
+
+    fn offset_of(needle: &str, nth: usize) -> usize {
+        SCHEMA.match_indices(needle).nth(nth).unwrap().0 + 1
+    }
+
+    #[test]
+    fn symbol_at_resolves_field_types_to_their_declaration() {
+        let ast = ast::parser::parse_schema(SCHEMA).unwrap();
+
+        match symbol_at(SCHEMA, &ast, offset_of("User @relation", 0)) {
+            Some(Symbol::Top(top)) => assert_eq!(top.name(), "User"),
+            _ => panic!("Expected the User model."),
+        }
+    }
+
+    #[test]
+    fn symbol_at_resolves_relation_references_on_the_related_model() {
+        let ast = ast::parser::parse_schema(SCHEMA).unwrap();
+
+        match symbol_at(SCHEMA, &ast, offset_of("id]", 0)) {
+            Some(Symbol::Field { model, field }) => {
+                assert_eq!(model.name.name, "User");
+                assert_eq!(field.name.name, "id");
+            }
+            _ => panic!("Expected the User.id field."),
+        }
+    }
+
+    #[test]
+    fn field_usages_include_block_and_relation_attributes() {
+        let ast = ast::parser::parse_schema(SCHEMA).unwrap();
+
+        assert_eq!(field_usages(&ast, "User", "email").count(), 1);
+        assert_eq!(field_usages(&ast, "User", "id").count(), 1);
+        assert_eq!(field_usages(&ast, "Post", "authorId").count(), 1);
+    }
+
+    const ABSTRACT_SCHEMA: &str = r#"
+abstract model Base {
+  id Int @id
+}
+
+model User extends Base {
+  email String
+
+  @@unique([id, email])
+}
+"#;
+
+    #[test]
+    fn symbol_at_resolves_extends_to_the_abstract_model() {
+        let ast = ast::parser::parse_schema(ABSTRACT_SCHEMA).unwrap();
+        let offset = ABSTRACT_SCHEMA.find("extends Base").unwrap() + "extends B".len();
+
+        match symbol_at(ABSTRACT_SCHEMA, &ast, offset) {
+            Some(Symbol::Top(top)) => assert_eq!(top.name(), "Base"),
+            _ => panic!("Expected the Base model."),
+        }
+    }
+
+    #[test]
+    fn symbol_at_resolves_inherited_field_references_on_the_abstract_model() {
+        let ast = ast::parser::parse_schema(ABSTRACT_SCHEMA).unwrap();
+        let offset = ABSTRACT_SCHEMA.find("id, email").unwrap() + 1;
+
+        match symbol_at(ABSTRACT_SCHEMA, &ast, offset) {
+            Some(Symbol::Field { model, field }) => {
+                assert_eq!(model.name.name, "Base");
+                assert_eq!(field.name.name, "id");
+            }
+            _ => panic!("Expected the Base.id field."),
+        }
+    }
+
+    #[test]
+    fn type_usages_include_extends() {
+        let ast = ast::parser::parse_schema(ABSTRACT_SCHEMA).unwrap();
+        let spans: Vec<Span> = type_usages(ABSTRACT_SCHEMA, &ast, "Base").collect();
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(&ABSTRACT_SCHEMA[spans[0].start..spans[0].end], "Base");
+        assert!(ABSTRACT_SCHEMA[..spans[0].start].ends_with("extends "));
+    }
+
+    #[test]
+    fn field_usages_include_references_to_inherited_fields() {
+        let ast = ast::parser::parse_schema(ABSTRACT_SCHEMA).unwrap();
+
+        assert_eq!(field_usages(&ast, "Base", "id").count(), 1);
+        assert_eq!(field_usages(&ast, "User", "id").count(), 0);
+        assert_eq!(field_usages(&ast, "User", "email").count(), 1);
+    }
+
+    #[test]
+    fn type_usages_skip_the_arity_markers() {
+        let ast = ast::parser::parse_schema(SCHEMA).unwrap();
+        let spans: Vec<Span> = type_usages(SCHEMA, &ast, "Post").collect();
+
+        assert_eq!(spans.len(), 1);
+        assert_eq!(&SCHEMA[spans[0].start..spans[0].end], "Post");
+    }
+}
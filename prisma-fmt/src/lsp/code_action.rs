@@ -0,0 +1,40 @@
+//! Quick fixes for the errors of the schema, offered as code actions.
+
+use super::document::Document;
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, Range, TextEdit, Url, WorkspaceEdit};
+use std::collections::HashMap;
+
+/// The quick fixes of the errors overlapping the range.
+pub(crate) fn code_actions(uri: &Url, document: &Document, range: Range) -> Vec<CodeActionOrCommand> {
+    let errors = match datamodel::parse_datamodel_and_ignore_datasource_urls(&document.text) {
+        Err(errors) => errors,
+        Ok(_) => return Vec::new(),
+    };
+
+    let start = document.offset(range.start);
+    let end = document.offset(range.end);
+
+    errors
+        .errors
+        .iter()
+        .filter(|error| error.span().start <= end && start <= error.span().end)
+        .flat_map(|error| error.quick_fixes(&document.text))
+        .map(|fix| {
+            let edits = fix
+                .edits
+                .into_iter()
+                .map(|edit| TextEdit::new(document.range(edit.span), edit.new_text))
+                .collect();
+
+            let mut changes = HashMap::new();
+            changes.insert(uri.clone(), edits);
+
+            CodeActionOrCommand::CodeAction(CodeAction {
+                title: fix.title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(WorkspaceEdit::new(changes)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,167 @@
+//! Completion works on the text before the cursor rather than on the AST, because the schema is
+//! usually invalid while the user is typing.
+
+use super::{docs, document::Document};
+use datamodel::ast::Top;
+use lsp_types::{CompletionItem, CompletionItemKind, Documentation};
+
+pub(crate) fn completions(document: &Document, offset: usize) -> Vec<CompletionItem> {
+    if enclosing_block_keyword(&document.text[..offset]) != Some("model") {
+        return Vec::new();
+    }
+
+    let (before_word, _word) = split_trailing_word(document.line_prefix(offset));
+
+    if before_word.ends_with("@@") {
+        return directive_completions(docs::BLOCK_DIRECTIVES);
+    }
+
+    if let Some(before_dot) = before_word.strip_suffix('.') {
+        let (before_datasource, datasource) = split_trailing_word(before_dot);
+
+        return match before_datasource.strip_suffix('@') {
+            Some(before_at) if !before_at.ends_with('@') => native_type_completions(&document.text, datasource),
+            _ => Vec::new(),
+        };
+    }
+
+    if before_word.ends_with('@') {
+        return directive_completions(docs::FIELD_DIRECTIVES);
+    }
+
+    // The type comes right after the field name.
+    let (before_field_name, field_name) = split_trailing_word(before_word.trim_end());
+
+    if !field_name.is_empty() && before_field_name.trim().is_empty() && before_word.ends_with(char::is_whitespace) {
+        return type_completions(document);
+    }
+
+    Vec::new()
+}
+
+fn type_completions(document: &Document) -> Vec<CompletionItem> {
+    let scalar_types = docs::SCALAR_TYPES.iter().map(|(name, doc)| CompletionItem {
+        kind: Some(CompletionItemKind::TypeParameter),
+        documentation: Some(Documentation::String(doc.to_string())),
+        ..CompletionItem::new_simple(name.to_string(), "Scalar type".to_owned())
+    });
+
+    let declared_types = document
+        .ast
+        .iter()
+        .flat_map(|ast| ast.tops.iter())
+        .filter_map(|top| {
+            let (kind, detail) = match top {
+                // Abstract models can only be extended, not used as field types.
+                Top::Model(model) if model.is_abstract => return None,
+                Top::Model(_) => (CompletionItemKind::Class, "Model"),
+                Top::Enum(_) => (CompletionItemKind::Enum, "Enum"),
+                Top::Type(_) => (CompletionItemKind::TypeParameter, "Type alias"),
+                Top::Source(_) | Top::Generator(_) => return None,
+            };
+
+            Some(CompletionItem {
+                kind: Some(kind),
+                ..CompletionItem::new_simple(top.name().to_owned(), detail.to_owned())
+            })
+        });
+
+    scalar_types.chain(declared_types).collect()
+}
+
+fn directive_completions(directives: &[(&str, &str)]) -> Vec<CompletionItem> {
+    directives
+        .iter()
+        .map(|(name, doc)| CompletionItem {
+            kind: Some(CompletionItemKind::Property),
+            documentation: Some(Documentation::String(doc.to_string())),
+            ..CompletionItem::new_simple(name.to_string(), "Attribute".to_owned())
+        })
+        .collect()
+}
+
+fn native_type_completions(text: &str, datasource_name: &str) -> Vec<CompletionItem> {
+    let configuration = match datamodel::parse_configuration_and_ignore_datasource_urls(&datasource_blocks(text)) {
+        Ok(configuration) => configuration,
+        Err(_) => return Vec::new(),
+    };
+
+    let datasource = match configuration
+        .datasources
+        .iter()
+        .find(|datasource| datasource.name == datasource_name)
+    {
+        Some(datasource) => datasource,
+        None => return Vec::new(),
+    };
+
+    datasource
+        .active_connector
+        .available_native_type_constructors()
+        .iter()
+        .map(|constructor| CompletionItem {
+            kind: Some(CompletionItemKind::TypeParameter),
+            ..CompletionItem::new_simple(
+                constructor.name.clone(),
+                format!("Native type for {:?}", constructor.prisma_type),
+            )
+        })
+        .collect()
+}
+
+/// The text of the datasource blocks, which can be parsed on their own while the rest of the
+/// schema is being edited.
+fn datasource_blocks(text: &str) -> String {
+    let mut blocks = String::new();
+    let mut in_datasource = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("datasource ") {
+            in_datasource = true;
+        }
+
+        if in_datasource {
+            blocks.push_str(line);
+            blocks.push('\n');
+        }
+
+        if in_datasource && trimmed.starts_with('}') {
+            in_datasource = false;
+        }
+    }
+
+    blocks
+}
+
+/// The keyword of the block (`model`, `enum`...) the end of the text is in, if any.
+fn enclosing_block_keyword(text: &str) -> Option<&str> {
+    let mut keyword = None;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with('}') {
+            keyword = None;
+        } else if trimmed.trim_end().ends_with('{') {
+            // `abstract model X {` blocks contain fields just like models.
+            keyword = trimmed.split_whitespace().find(|word| *word != "abstract");
+        }
+    }
+
+    keyword
+}
+
+/// Split the identifier characters at the end of the string from the rest.
+fn split_trailing_word(s: &str) -> (&str, &str) {
+    let word_start = s
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| c.is_alphanumeric() || *c == '_')
+        .last()
+        .map(|(idx, _)| idx)
+        .unwrap_or_else(|| s.len());
+
+    s.split_at(word_start)
+}
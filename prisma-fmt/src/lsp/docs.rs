@@ -0,0 +1,67 @@
+//! Documentation for the built-in types and attributes, shown in hovers and completions.
+
+pub(crate) const SCALAR_TYPES: &[(&str, &str)] = &[
+    ("String", "Variable length text."),
+    ("Boolean", "True or false value."),
+    ("Int", "Integer value."),
+    ("Float", "Floating point number."),
+    ("DateTime", "Timestamp."),
+    ("Json", "A JSON object."),
+];
+
+pub(crate) const FIELD_DIRECTIVES: &[(&str, &str)] = &[
+    ("id", "Defines a single-field ID on the model."),
+    (
+        "default",
+        "Defines a default value for this field. `@default` takes an expression, e.g. `5`, `true`, `now()` or `autoincrement()`.",
+    ),
+    ("unique", "Defines a unique constraint for this field."),
+    (
+        "relation",
+        "Defines meta information about the relation. The `fields` argument lists the fields of this model holding the foreign key, and `references` the fields of the related model they point to.",
+    ),
+    ("map", "Maps the field name to a different name in the database."),
+    ("updatedAt", "Automatically stores the time when the record was last updated."),
+    (
+        "ignore",
+        "Excludes this field from the Prisma Client. The field is still managed by migrations.",
+    ),
+    (
+        "length",
+        "Validates the number of characters of a String on every write, e.g. `@length(min: 1, max: 20)`.",
+    ),
+    (
+        "pattern",
+        "Validates that a String matches a regular expression on every write, e.g. `@pattern(\"^[a-z]+$\")`.",
+    ),
+    (
+        "range",
+        "Validates the inclusive bounds of a number on every write, e.g. `@range(min: 0, max: 100)`.",
+    ),
+];
+
+pub(crate) const BLOCK_DIRECTIVES: &[(&str, &str)] = &[
+    ("id", "Defines a multi-field ID on the model, e.g. `@@id([firstName, lastName])`."),
+    (
+        "unique",
+        "Defines a compound unique constraint for the specified fields, e.g. `@@unique([firstName, lastName])`.",
+    ),
+    ("index", "Defines an index on the specified fields, e.g. `@@index([title])`."),
+    ("map", "Maps the model name to a different table name in the database."),
+    (
+        "ignore",
+        "Excludes this model from the Prisma Client. The model is still managed by migrations.",
+    ),
+];
+
+pub(crate) fn scalar_type(name: &str) -> Option<&'static str> {
+    lookup(SCALAR_TYPES, name)
+}
+
+pub(crate) fn directive(name: &str, is_block: bool) -> Option<&'static str> {
+    lookup(if is_block { BLOCK_DIRECTIVES } else { FIELD_DIRECTIVES }, name)
+}
+
+fn lookup(docs: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    docs.iter().find(|(doc_name, _)| *doc_name == name).map(|(_, doc)| *doc)
+}
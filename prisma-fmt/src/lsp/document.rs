@@ -0,0 +1,86 @@
+use datamodel::ast::{self, SchemaAst};
+use lsp_types::{Position, Range};
+
+/// An open schema file, with the AST of its last version that could be parsed.
+pub(crate) struct Document {
+    pub(crate) text: String,
+    /// Kept across edits that break the syntax, so completion still knows about the models and
+    /// enums while the user is typing.
+    pub(crate) ast: Option<SchemaAst>,
+    ast_is_current: bool,
+}
+
+impl Document {
+    pub(crate) fn new(text: String) -> Self {
+        let mut document = Document {
+            text: String::new(),
+            ast: None,
+            ast_is_current: false,
+        };
+        document.update(text);
+        document
+    }
+
+    pub(crate) fn update(&mut self, text: String) {
+        match ast::parser::parse_schema(&text) {
+            Ok(ast) => {
+                self.ast = Some(ast);
+                self.ast_is_current = true;
+            }
+            Err(_) => self.ast_is_current = false,
+        }
+
+        self.text = text;
+    }
+
+    /// The AST, only if it matches the current text.
+    pub(crate) fn current_ast(&self) -> Option<&SchemaAst> {
+        self.ast.as_ref().filter(|_| self.ast_is_current)
+    }
+
+    /// Convert an LSP position (line and UTF-16 column) to a byte offset in the text.
+    pub(crate) fn offset(&self, position: Position) -> usize {
+        let mut line_start = 0;
+
+        for _ in 0..position.line {
+            match self.text[line_start..].find('\n') {
+                Some(idx) => line_start += idx + 1,
+                None => return self.text.len(),
+            }
+        }
+
+        let mut utf16_column = 0;
+
+        for (idx, c) in self.text[line_start..].char_indices() {
+            if c == '\n' || utf16_column >= position.character as usize {
+                return line_start + idx;
+            }
+
+            utf16_column += c.len_utf16();
+        }
+
+        self.text.len()
+    }
+
+    /// Convert a byte offset in the text to an LSP position.
+    pub(crate) fn position(&self, offset: usize) -> Position {
+        let prefix = &self.text[..offset.min(self.text.len())];
+        let line_start = prefix.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let line = prefix.matches('\n').count();
+        let character: usize = prefix[line_start..].chars().map(char::len_utf16).sum();
+
+        Position::new(line as u64, character as u64)
+    }
+
+    pub(crate) fn range(&self, span: ast::Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    /// The text of the line containing the offset, up to the offset.
+    pub(crate) fn line_prefix(&self, offset: usize) -> &str {
+        let prefix = &self.text[..offset];
+        let line_start = prefix.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+
+        &prefix[line_start..]
+    }
+}
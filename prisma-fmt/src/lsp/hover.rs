@@ -0,0 +1,134 @@
+use super::{
+    analysis::{self, Symbol},
+    docs,
+    document::Document,
+};
+use datamodel::{
+    ast::{self, Top, WithDocumentation},
+    walkers::walk_models,
+};
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+
+pub(crate) fn hover(document: &Document, offset: usize) -> Option<Hover> {
+    let ast = document.current_ast()?;
+
+    let (value, span) = match analysis::symbol_at(&document.text, ast, offset)? {
+        Symbol::Top(top) => (describe_top(top), analysis::top_name_span(top)),
+        Symbol::Field { model, field } => (describe_field(document, model, field), field.name.span),
+        Symbol::Directive { directive, is_block } => (
+            describe_directive(&directive.name.name, is_block)?,
+            directive.name.span,
+        ),
+        Symbol::ScalarType(name) => (
+            format!("```prisma\n{}\n```\n\n{}", name, docs::scalar_type(name)?),
+            ast::Span::new(offset, offset),
+        ),
+    };
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range: Some(document.range(span)).filter(|_| span.start != span.end),
+    })
+}
+
+fn describe_top(top: &Top) -> String {
+    let (declaration, documentation) = match top {
+        Top::Model(model) => (format!("model {}", model.name.name), model.documentation()),
+        Top::Enum(enm) => {
+            let values: Vec<&str> = enm.values.iter().map(|value| value.name.name.as_str()).collect();
+
+            (
+                format!("enum {} {{ {} }}", enm.name.name, values.join(", ")),
+                enm.documentation(),
+            )
+        }
+        Top::Type(alias) => (
+            format!("type {} = {}", alias.name.name, alias.field_type.name),
+            alias.documentation(),
+        ),
+        Top::Source(source) => (format!("datasource {}", source.name.name), &source.documentation),
+        Top::Generator(generator) => (format!("generator {}", generator.name.name), &generator.documentation),
+    };
+
+    with_documentation(declaration, documentation)
+}
+
+fn describe_field(document: &Document, model: &ast::Model, field: &ast::Field) -> String {
+    let arity = match field.arity {
+        ast::FieldArity::Required => "",
+        ast::FieldArity::Optional => "?",
+        ast::FieldArity::List => "[]",
+    };
+    let mut description = with_documentation(
+        format!("{} {}{}", field.name.name, field.field_type.name, arity),
+        field.documentation(),
+    );
+
+    // Relations are only resolved on valid schemas.
+    if let Ok(datamodel) = datamodel::parse_datamodel_and_ignore_datasource_urls(&document.text) {
+        let relation_field = walk_models(&datamodel)
+            .find(|walker| walker.name() == model.name.name)
+            .and_then(|walker| {
+                walker
+                    .relation_fields()
+                    .find(|relation_field| relation_field.name() == field.name.name)
+            });
+
+        if let Some(relation_field) = relation_field {
+            let opposite_side = relation_field.opposite_side();
+            let cardinality = if relation_field.is_one_to_one() {
+                "one-to-one"
+            } else if relation_field.arity().is_list() && opposite_side.arity().is_list() {
+                "many-to-many"
+            } else {
+                "one-to-many"
+            };
+
+            description.push_str(&format!(
+                "\n\n{} relation `{}` to `{}.{}`.",
+                capitalize(cardinality),
+                relation_field.relation_name(),
+                opposite_side.model().name(),
+                opposite_side.name(),
+            ));
+        }
+    }
+
+    description
+}
+
+fn describe_directive(name: &str, is_block: bool) -> Option<String> {
+    let prefix = if is_block { "@@" } else { "@" };
+
+    if let Some(documentation) = docs::directive(name, is_block) {
+        return Some(format!("`{}{}`\n\n{}", prefix, name, documentation));
+    }
+
+    // Native type attributes are namespaced by the datasource name, e.g. `@pg.VarChar`.
+    let mut parts = name.splitn(2, '.');
+    let (datasource, native_type) = (parts.next()?, parts.next()?);
+
+    Some(format!(
+        "`{}{}`\n\nThe `{}` native database type of the `{}` datasource.",
+        prefix, name, native_type, datasource
+    ))
+}
+
+fn with_documentation(declaration: String, documentation: &Option<ast::Comment>) -> String {
+    match documentation {
+        Some(comment) => format!("```prisma\n{}\n```\n\n{}", declaration, comment.text),
+        None => format!("```prisma\n{}\n```", declaration),
+    }
+}
+
+fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,88 @@
+//! Go to definition and rename.
+
+use super::{
+    analysis::{self, Symbol},
+    document::Document,
+};
+use datamodel::ast::{Span, Top};
+use lsp_types::{Location, TextEdit, Url, WorkspaceEdit};
+use std::collections::HashMap;
+
+pub(crate) fn definition(uri: &Url, document: &Document, offset: usize) -> Option<Location> {
+    let ast = document.current_ast()?;
+
+    let span = match analysis::symbol_at(&document.text, ast, offset)? {
+        Symbol::Top(top) => analysis::top_name_span(top),
+        Symbol::Field { field, .. } => field.name.span,
+        Symbol::Directive { .. } | Symbol::ScalarType(_) => return None,
+    };
+
+    Some(Location::new(uri.clone(), document.range(span)))
+}
+
+/// Rename a model, enum, type alias or field, along with all its usages in the schema.
+pub(crate) fn rename(uri: &Url, document: &Document, offset: usize, new_name: &str) -> Option<WorkspaceEdit> {
+    let ast = document.current_ast()?;
+
+    let spans: Vec<Span> = match analysis::symbol_at(&document.text, ast, offset)? {
+        Symbol::Top(top) => match top {
+            Top::Model(_) | Top::Enum(_) | Top::Type(_) => std::iter::once(analysis::top_name_span(top))
+                .chain(analysis::type_usages(&document.text, ast, top.name()))
+                .collect(),
+            Top::Source(_) | Top::Generator(_) => return None,
+        },
+        Symbol::Field { model, field } => std::iter::once(field.name.span)
+            .chain(analysis::field_usages(ast, &model.name.name, &field.name.name))
+            .collect(),
+        Symbol::Directive { .. } | Symbol::ScalarType(_) => return None,
+    };
+
+    let edits = spans
+        .into_iter()
+        .map(|span| TextEdit::new(document.range(span), new_name.to_owned()))
+        .collect();
+
+    let mut changes = HashMap::new();
+    changes.insert(uri.clone(), edits);
+
+    Some(WorkspaceEdit::new(changes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCHEMA: &str = r#"
+abstract model Base {
+  id Int @id
+}
+
+model User extends Base {
+  email String
+
+  @@unique([id, email])
+}
+"#;
+
+    fn rename_edits(offset: usize) -> Vec<TextEdit> {
+        let uri = Url::parse("file:///schema.prisma").unwrap();
+        let document = Document::new(SCHEMA.to_owned());
+        let edit = rename(&uri, &document, offset, "Renamed").unwrap();
+
+        edit.changes.unwrap().remove(&uri).unwrap()
+    }
+
+    #[test]
+    fn renaming_an_abstract_model_renames_its_extends_references() {
+        let edits = rename_edits(SCHEMA.find("Base").unwrap() + 1);
+
+        assert_eq!(edits.len(), 2);
+    }
+
+    #[test]
+    fn renaming_an_inherited_field_renames_its_references_in_extending_models() {
+        let edits = rename_edits(SCHEMA.find("id Int").unwrap() + 1);
+
+        assert_eq!(edits.len(), 2);
+    }
+}
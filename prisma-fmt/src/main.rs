@@ -1,5 +1,6 @@
 mod format;
 mod lint;
+mod lsp;
 mod native;
 
 use std::path::PathBuf;
@@ -12,6 +13,9 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// If set, also reports violations of the lint rules, with the severities configured in the schema
+    #[structopt(long)]
+    rules: bool,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -39,6 +43,8 @@ pub enum FmtOpts {
     Format(FormatOpts),
     /// Specifies Native Types mode
     NativeTypes,
+    /// Starts the language server over stdio
+    Lsp,
 }
 
 #[derive(serde::Serialize)]
@@ -46,6 +52,45 @@ pub struct MiniError {
     pub start: usize,
     pub end: usize,
     pub text: String,
+    /// Only set for lint rule violations, which are not errors of the schema itself.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<datamodel::lint::Severity>,
+    /// Edits that resolve the error, see `DatamodelError::quick_fixes`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fixes: Vec<MiniQuickFix>,
+}
+
+#[derive(serde::Serialize)]
+pub struct MiniQuickFix {
+    pub title: String,
+    pub edits: Vec<MiniTextEdit>,
+}
+
+/// Replaces the text from `start` to `end`.
+#[derive(serde::Serialize)]
+pub struct MiniTextEdit {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+impl From<datamodel::quick_fix::QuickFix> for MiniQuickFix {
+    fn from(fix: datamodel::quick_fix::QuickFix) -> Self {
+        MiniQuickFix {
+            title: fix.title,
+            edits: fix
+                .edits
+                .into_iter()
+                .map(|edit| MiniTextEdit {
+                    start: edit.span.start,
+                    end: edit.span.end,
+                    text: edit.new_text,
+                })
+                .collect(),
+        }
+    }
 }
 
 fn main() {
@@ -53,5 +98,6 @@ fn main() {
         FmtOpts::Lint(opts) => lint::run(opts),
         FmtOpts::Format(opts) => format::run(opts),
         FmtOpts::NativeTypes => native::run(),
+        FmtOpts::Lsp => lsp::run(),
     }
 }
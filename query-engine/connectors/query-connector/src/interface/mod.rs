@@ -6,6 +6,7 @@ use async_trait::async_trait;
 use dml::FieldArity;
 use prisma_models::*;
 use prisma_value::PrismaValue;
+use serde::Serialize;
 
 #[async_trait]
 pub trait Connector {
@@ -14,17 +15,51 @@ pub trait Connector {
 
     /// Returns name of the connector.
     fn name(&self) -> String;
+
+    /// Closes the connection pool of the connector. Connections checked out before are disconnected once
+    /// they are returned, later calls to `get_connection` fail.
+    fn close(&self);
 }
 
 #[async_trait]
 pub trait Connection: ReadOperations + WriteOperations + Send + Sync {
     async fn start_transaction<'a>(&'a self) -> crate::Result<Box<dyn Transaction + 'a>>;
+
+    /// Returns a handle to cancel the statement running on this connection (or a transaction started on it)
+    /// from outside of it. `None` if the connector can't cancel statements.
+    async fn statement_canceller(&self) -> crate::Result<Option<Box<dyn StatementCanceller>>>;
+}
+
+/// Cancels the statement currently running on a connection, e.g. when it exceeds a timeout.
+#[async_trait]
+pub trait StatementCanceller: Send + Sync {
+    async fn cancel(&self) -> crate::Result<()>;
 }
 
 #[async_trait]
 pub trait Transaction: ReadOperations + WriteOperations + Send + Sync {
     async fn commit(&self) -> crate::Result<()>;
     async fn rollback(&self) -> crate::Result<()>;
+
+    /// Records the statements run in the transaction from now on, e.g. to explain a request. With `explain`,
+    /// the database explains every statement before it runs.
+    fn record_statements(&self, explain: bool);
+
+    /// The statements run since `record_statements` was called.
+    fn recorded_statements(&self) -> Vec<RecordedStatement>;
+}
+
+/// A statement run in a transaction while recording.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedStatement {
+    /// The statement, rendered for the database.
+    pub statement: String,
+
+    pub parameters: Vec<serde_json::Value>,
+
+    /// The plan of the database for the statement, e.g. the output of `EXPLAIN`. `None` if not requested,
+    /// or if the database can't explain statements.
+    pub plan: Option<serde_json::Value>,
 }
 
 pub enum ConnectionLike<'conn, 'tx>
@@ -0,0 +1,85 @@
+use crate::SqlError;
+use async_trait::async_trait;
+use connector_interface::{self as connector, StatementCanceller};
+use quaint::{connector::Queryable, prelude::ConnectionInfo, single::Quaint};
+
+/// Cancels the statement running in a database session. The cancellation is sent from a connection opened
+/// for it, as the pool of the session may have no connection left, e.g. because all of them run statements
+/// that need to be cancelled.
+///
+/// Only PostgreSQL and MySQL statements are cancelled. SQLite runs statements in the process of the engine
+/// and SQL Server can only kill whole sessions, so their statements run until they finish, and rolling back
+/// the transaction of an interrupted execution waits for them.
+pub struct SqlStatementCanceller {
+    url: String,
+    connection_info: ConnectionInfo,
+    session_id: i64,
+}
+
+impl SqlStatementCanceller {
+    pub fn new(url: &str, connection_info: &ConnectionInfo, session_id: i64) -> Self {
+        Self {
+            url: url.to_owned(),
+            connection_info: connection_info.clone(),
+            session_id,
+        }
+    }
+
+    /// Looks up the id of the session of the connection. `None` for databases where statements are not
+    /// cancelled.
+    pub async fn session_id(conn: &dyn Queryable, connection_info: &ConnectionInfo) -> crate::Result<Option<i64>> {
+        let session_query = match connection_info {
+            ConnectionInfo::Postgres(_) => "SELECT pg_backend_pid() AS session_id",
+            ConnectionInfo::Mysql(_) => "SELECT CONNECTION_ID() AS session_id",
+            _ => return Ok(None),
+        };
+
+        let session_id = conn
+            .query_raw(session_query, &[])
+            .await?
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("session_id").and_then(|value| value.as_i64()));
+
+        Ok(session_id)
+    }
+
+    /// Cancels the statement of the session if it runs one. An idle session is left alone, so that the
+    /// cancellation can't interrupt the next statement it runs.
+    async fn cancel_running_statement(&self, conn: &Quaint) -> crate::Result<()> {
+        match self.connection_info {
+            ConnectionInfo::Postgres(_) => {
+                let cancel = format!(
+                    "SELECT pg_cancel_backend(pid) FROM pg_stat_activity WHERE pid = {} AND state = 'active'",
+                    self.session_id
+                );
+
+                conn.query_raw(&cancel, &[]).await?;
+            }
+            ConnectionInfo::Mysql(_) => {
+                let running_statement = format!(
+                    "SELECT id FROM information_schema.processlist WHERE id = {} AND command = 'Query'",
+                    self.session_id
+                );
+
+                if !conn.query_raw(&running_statement, &[]).await?.is_empty() {
+                    conn.raw_cmd(&format!("KILL QUERY {}", self.session_id)).await?;
+                }
+            }
+            _ => (),
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StatementCanceller for SqlStatementCanceller {
+    async fn cancel(&self) -> connector::Result<()> {
+        super::catch(&self.connection_info, async move {
+            let conn = Quaint::new(&self.url).await.map_err(SqlError::from)?;
+            self.cancel_running_statement(&conn).await
+        })
+        .await
+    }
+}
@@ -1,27 +1,45 @@
-use super::transaction::SqlConnectorTransaction;
+use super::{canceller::SqlStatementCanceller, transaction::SqlConnectorTransaction};
 use crate::{database::operations::*, QueryExt, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationResult, Aggregator, Connection, QueryArguments, ReadOperations,
-    RecordFilter, Transaction, WriteArgs, WriteOperations,
+    RecordFilter, StatementCanceller, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::{connector::TransactionCapable, prelude::ConnectionInfo};
-use std::future::Future;
+use quaint::{
+    connector::{Queryable, TransactionCapable},
+    prelude::ConnectionInfo,
+};
+use query_engine_metrics::GaugeGuard;
+use std::{future::Future, sync::Mutex};
 
 pub struct SqlConnection<C> {
     inner: C,
+    /// The URL of the database, to cancel the statements of the connection from another connection.
+    url: String,
     connection_info: ConnectionInfo,
+    /// The id of the database session of the connection, looked up once the first canceller is created.
+    session_id: Mutex<Option<i64>>,
+    /// Counts the connection as busy until it is returned to the pool.
+    _busy: GaugeGuard,
 }
 
 impl<C> SqlConnection<C>
 where
-    C: QueryExt + Send + Sync + 'static,
+    C: Queryable + Send + Sync + 'static,
 {
-    pub fn new(inner: C, connection_info: &ConnectionInfo) -> Self {
+    pub fn new(inner: C, url: &str, connection_info: &ConnectionInfo) -> Self {
         let connection_info = connection_info.clone();
-        Self { inner, connection_info }
+        let _busy = query_engine_metrics::POOL_CONNECTIONS_BUSY.track(&[]);
+
+        Self {
+            inner,
+            url: url.to_owned(),
+            connection_info,
+            session_id: Mutex::new(None),
+            _busy,
+        }
     }
 
     async fn catch<O>(
@@ -35,10 +53,23 @@ where
     }
 }
 
+impl<C> QueryExt for SqlConnection<C>
+where
+    C: Queryable + Send + Sync + 'static,
+{
+    fn queryable(&self) -> &dyn Queryable {
+        &self.inner
+    }
+
+    fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+}
+
 #[async_trait]
 impl<C> Connection for SqlConnection<C>
 where
-    C: QueryExt + TransactionCapable + Send + Sync + 'static,
+    C: Queryable + TransactionCapable + Send + Sync + 'static,
 {
     async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
         let fut_tx = self.inner.start_transaction();
@@ -49,12 +80,31 @@ where
         })
         .await
     }
+
+    async fn statement_canceller(&self) -> connector::Result<Option<Box<dyn StatementCanceller>>> {
+        self.catch(async move {
+            let cached = *self.session_id.lock().unwrap();
+
+            let session_id = match cached {
+                Some(session_id) => Some(session_id),
+                None => SqlStatementCanceller::session_id(&self.inner, &self.connection_info).await?,
+            };
+
+            *self.session_id.lock().unwrap() = session_id;
+
+            let canceller =
+                session_id.map(|session_id| SqlStatementCanceller::new(&self.url, &self.connection_info, session_id));
+
+            Ok(canceller.map(|canceller| Box::new(canceller) as Box<dyn StatementCanceller>))
+        })
+        .await
+    }
 }
 
 #[async_trait]
 impl<C> ReadOperations for SqlConnection<C>
 where
-    C: QueryExt + Send + Sync + 'static,
+    C: Queryable + Send + Sync + 'static,
 {
     async fn get_single_record(
         &self,
@@ -62,7 +112,7 @@ where
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move { read::get_single_record(self, model, filter, selected_fields).await })
             .await
     }
 
@@ -72,7 +122,7 @@ where
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move { read::get_many_records(self, model, query_arguments, selected_fields).await })
             .await
     }
 
@@ -81,7 +131,7 @@ where
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
+        self.catch(async move { read::get_related_m2m_record_ids(self, from_field, from_record_ids).await })
             .await
     }
 
@@ -91,7 +141,7 @@ where
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move { read::aggregate(self, model, aggregators, query_arguments).await })
             .await
     }
 }
@@ -99,10 +149,10 @@ where
 #[async_trait]
 impl<C> WriteOperations for SqlConnection<C>
 where
-    C: QueryExt + Send + Sync + 'static,
+    C: Queryable + Send + Sync + 'static,
 {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch(async move { write::create_record(self, model, args).await })
             .await
     }
 
@@ -112,12 +162,12 @@ where
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch(async move { write::update_records(self, model, record_filter, args).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch(async move { write::delete_records(self, model, record_filter).await })
             .await
     }
 
@@ -127,7 +177,7 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::connect(self, field, parent_id, child_ids).await })
             .await
     }
 
@@ -137,17 +187,17 @@ where
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::disconnect(self, field, parent_id, child_ids).await })
             .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::execute_raw(self, query, parameters).await })
             .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(self, query, parameters).await })
             .await
     }
 }
@@ -1,7 +1,9 @@
+mod canceller;
 mod connection;
 #[cfg(feature = "mssql")]
 mod mssql;
 mod mysql;
+mod pool;
 mod postgresql;
 mod sqlite;
 mod transaction;
@@ -11,6 +13,9 @@ pub(crate) mod operations;
 use async_trait::async_trait;
 use connector_interface::{error::ConnectorError, Connector};
 use datamodel::Datasource;
+use pool::Pool;
+use quaint::pooled::{PooledConnection, Quaint};
+use std::time::Instant;
 
 #[cfg(feature = "mssql")]
 pub use mssql::*;
@@ -20,7 +25,16 @@ pub use sqlite::*;
 
 #[async_trait]
 pub trait FromSource {
+    /// Connects to the database at the URL of the datasource.
     async fn from_source(source: &Datasource) -> connector_interface::Result<Self>
+    where
+        Self: Connector + Sized,
+    {
+        Self::from_url(&source.url().value).await
+    }
+
+    /// Connects to the database at the URL, e.g. one of the `replica_urls` of a datasource.
+    async fn from_url(url: &str) -> connector_interface::Result<Self>
     where
         Self: Connector + Sized;
 }
@@ -34,3 +48,12 @@ async fn catch<O>(
         Err(err) => Err(err.into_connector_error(connection_info)),
     }
 }
+
+/// Checks a connection out of the pool, recording how long it took.
+async fn check_out(pool: &Quaint) -> Result<PooledConnection, crate::SqlError> {
+    let start = Instant::now();
+    let conn = pool.check_out().await?;
+    query_engine_metrics::POOL_CHECKOUT_DURATION.observe(&[], start.elapsed().as_secs_f64());
+
+    Ok(conn)
+}
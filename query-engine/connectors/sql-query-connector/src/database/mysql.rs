@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, Pool};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -6,22 +6,22 @@ use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector,
 };
-use datamodel::Datasource;
 use quaint::{pooled::Quaint, prelude::ConnectionInfo};
 use std::time::Duration;
 
 pub struct Mysql {
-    pool: Quaint,
+    pool: Pool,
+    url: String,
     connection_info: ConnectionInfo,
 }
 
 #[async_trait]
 impl FromSource for Mysql {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Self> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+    async fn from_url(url: &str) -> connector_interface::Result<Self> {
+        let connection_info = ConnectionInfo::from_url(url)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let mut builder = Quaint::builder(&source.url().value)
+        let mut builder = Quaint::builder(url)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -32,7 +32,11 @@ impl FromSource for Mysql {
         let pool = builder.build();
         let connection_info = pool.connection_info().to_owned();
 
-        Ok(Mysql { pool, connection_info })
+        Ok(Mysql {
+            pool: Pool::new(pool),
+            url: url.to_owned(),
+            connection_info,
+        })
     }
 }
 
@@ -40,8 +44,9 @@ impl FromSource for Mysql {
 impl Connector for Mysql {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info, async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, &self.connection_info);
+            let pool = self.pool.get()?;
+            let conn = super::check_out(&pool).await?;
+            let conn = SqlConnection::new(conn, &self.url, &self.connection_info);
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -51,4 +56,8 @@ impl Connector for Mysql {
     fn name(&self) -> String {
         "mysql".to_owned()
     }
+
+    fn close(&self) {
+        self.pool.close();
+    }
 }
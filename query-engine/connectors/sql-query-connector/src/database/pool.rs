@@ -0,0 +1,31 @@
+use crate::SqlError;
+use quaint::{error::ErrorKind as QuaintKind, pooled::Quaint};
+use std::sync::RwLock;
+
+/// The connection pool of a connector. Closing it drops the handle of the connector to the quaint pool,
+/// which disconnects the pooled connections once the connections checked out of it are returned.
+pub(super) struct Pool {
+    inner: RwLock<Option<Quaint>>,
+}
+
+impl Pool {
+    pub fn new(quaint: Quaint) -> Self {
+        Self {
+            inner: RwLock::new(Some(quaint)),
+        }
+    }
+
+    /// A handle to the quaint pool, to check out connections. Fails once the pool is closed.
+    pub fn get(&self) -> Result<Quaint, SqlError> {
+        match &*self.inner.read().unwrap() {
+            Some(quaint) => Ok(quaint.clone()),
+            None => Err(SqlError::ConnectionError(QuaintKind::ConnectionError(
+                "The connection pool is closed.".into(),
+            ))),
+        }
+    }
+
+    pub fn close(&self) {
+        self.inner.write().unwrap().take();
+    }
+}
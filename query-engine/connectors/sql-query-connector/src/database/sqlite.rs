@@ -1,4 +1,4 @@
-use super::connection::SqlConnection;
+use super::{connection::SqlConnection, Pool};
 use crate::{FromSource, SqlError};
 use async_trait::async_trait;
 use connector_interface::{
@@ -6,12 +6,13 @@ use connector_interface::{
     error::{ConnectorError, ErrorKind},
     Connection, Connector,
 };
-use datamodel::Datasource;
 use quaint::{connector::SqliteParams, error::ErrorKind as QuaintKind, pooled::Quaint, prelude::ConnectionInfo};
 use std::{convert::TryFrom, time::Duration};
 
 pub struct Sqlite {
-    pool: Quaint,
+    pool: Pool,
+    url: String,
+    connection_info: ConnectionInfo,
     file_path: String,
 }
 
@@ -21,17 +22,17 @@ impl Sqlite {
     }
 
     fn connection_info(&self) -> &ConnectionInfo {
-        self.pool.connection_info()
+        &self.connection_info
     }
 }
 
 #[async_trait]
 impl FromSource for Sqlite {
-    async fn from_source(source: &Datasource) -> connector_interface::Result<Sqlite> {
-        let connection_info = ConnectionInfo::from_url(&source.url().value)
+    async fn from_url(url: &str) -> connector_interface::Result<Sqlite> {
+        let connection_info = ConnectionInfo::from_url(url)
             .map_err(|err| ConnectorError::from_kind(ErrorKind::ConnectionError(err.into())))?;
 
-        let params = SqliteParams::try_from(source.url().value.as_str())
+        let params = SqliteParams::try_from(url)
             .map_err(SqlError::from)
             .map_err(|sql_error| sql_error.into_connector_error(&connection_info))?;
 
@@ -44,7 +45,7 @@ impl FromSource for Sqlite {
                 .ok_or_else(|| invalid_file_path_error(&file_path, &connection_info))?
                 .to_owned();
 
-            let mut splitted = source.url().value.split("?");
+            let mut splitted = url.split("?");
             let url = splitted.next().unwrap();
             let params = splitted.next();
 
@@ -68,8 +69,14 @@ impl FromSource for Sqlite {
         builder.test_on_check_out(true);
 
         let pool = builder.build();
+        let connection_info = pool.connection_info().to_owned();
 
-        Ok(Sqlite { pool, file_path })
+        Ok(Sqlite {
+            pool: Pool::new(pool),
+            url: url.to_owned(),
+            connection_info,
+            file_path,
+        })
     }
 }
 
@@ -85,8 +92,9 @@ fn invalid_file_path_error(file_path: &str, connection_info: &ConnectionInfo) ->
 impl Connector for Sqlite {
     async fn get_connection<'a>(&'a self) -> connector::Result<Box<dyn Connection + 'static>> {
         super::catch(&self.connection_info(), async move {
-            let conn = self.pool.check_out().await.map_err(SqlError::from)?;
-            let conn = SqlConnection::new(conn, self.connection_info());
+            let pool = self.pool.get()?;
+            let conn = super::check_out(&pool).await?;
+            let conn = SqlConnection::new(conn, &self.url, self.connection_info());
 
             Ok(Box::new(conn) as Box<dyn Connection>)
         })
@@ -96,4 +104,8 @@ impl Connector for Sqlite {
     fn name(&self) -> String {
         "sqlite".to_owned()
     }
+
+    fn close(&self) {
+        self.pool.close();
+    }
 }
@@ -1,17 +1,18 @@
 use crate::database::operations::*;
-use crate::SqlError;
+use crate::{QueryExt, SqlError, StatementRecorder};
 use async_trait::async_trait;
 use connector_interface::{
     self as connector, filter::Filter, AggregationResult, Aggregator, QueryArguments, ReadOperations, RecordFilter,
-    Transaction, WriteArgs, WriteOperations,
+    RecordedStatement, Transaction, WriteArgs, WriteOperations,
 };
 use prisma_models::prelude::*;
 use prisma_value::PrismaValue;
-use quaint::prelude::ConnectionInfo;
+use quaint::{connector::Queryable, prelude::ConnectionInfo};
 
 pub struct SqlConnectorTransaction<'tx> {
     inner: quaint::connector::Transaction<'tx>,
     connection_info: ConnectionInfo,
+    recorder: StatementRecorder,
 }
 
 impl<'tx> SqlConnectorTransaction<'tx> {
@@ -20,6 +21,7 @@ impl<'tx> SqlConnectorTransaction<'tx> {
         Self {
             inner: tx,
             connection_info,
+            recorder: StatementRecorder::default(),
         }
     }
 
@@ -34,6 +36,20 @@ impl<'tx> SqlConnectorTransaction<'tx> {
     }
 }
 
+impl<'tx> QueryExt for SqlConnectorTransaction<'tx> {
+    fn queryable(&self) -> &dyn Queryable {
+        &self.inner
+    }
+
+    fn connection_info(&self) -> &ConnectionInfo {
+        &self.connection_info
+    }
+
+    fn statement_recorder(&self) -> Option<&StatementRecorder> {
+        Some(&self.recorder)
+    }
+}
+
 #[async_trait]
 impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
     async fn commit(&self) -> connector::Result<()> {
@@ -45,6 +61,14 @@ impl<'tx> Transaction for SqlConnectorTransaction<'tx> {
         self.catch(async move { Ok(self.inner.rollback().await.map_err(SqlError::from)?) })
             .await
     }
+
+    fn record_statements(&self, explain: bool) {
+        self.recorder.start(explain);
+    }
+
+    fn recorded_statements(&self) -> Vec<RecordedStatement> {
+        self.recorder.statements()
+    }
 }
 
 #[async_trait]
@@ -55,7 +79,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         filter: &Filter,
         selected_fields: &ModelProjection,
     ) -> connector::Result<Option<SingleRecord>> {
-        self.catch(async move { read::get_single_record(&self.inner, model, filter, selected_fields).await })
+        self.catch(async move { read::get_single_record(self, model, filter, selected_fields).await })
             .await
     }
 
@@ -65,7 +89,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         query_arguments: QueryArguments,
         selected_fields: &ModelProjection,
     ) -> connector::Result<ManyRecords> {
-        self.catch(async move { read::get_many_records(&self.inner, model, query_arguments, selected_fields).await })
+        self.catch(async move { read::get_many_records(self, model, query_arguments, selected_fields).await })
             .await
     }
 
@@ -74,7 +98,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         from_field: &RelationFieldRef,
         from_record_ids: &[RecordProjection],
     ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
-        self.catch(async move { read::get_related_m2m_record_ids(&self.inner, from_field, from_record_ids).await })
+        self.catch(async move { read::get_related_m2m_record_ids(self, from_field, from_record_ids).await })
             .await
     }
 
@@ -84,7 +108,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
         aggregators: Vec<Aggregator>,
         query_arguments: QueryArguments,
     ) -> connector::Result<Vec<AggregationResult>> {
-        self.catch(async move { read::aggregate(&self.inner, model, aggregators, query_arguments).await })
+        self.catch(async move { read::aggregate(self, model, aggregators, query_arguments).await })
             .await
     }
 }
@@ -92,7 +116,7 @@ impl<'tx> ReadOperations for SqlConnectorTransaction<'tx> {
 #[async_trait]
 impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
     async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
-        self.catch(async move { write::create_record(&self.inner, model, args).await })
+        self.catch(async move { write::create_record(self, model, args).await })
             .await
     }
 
@@ -102,12 +126,12 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         record_filter: RecordFilter,
         args: WriteArgs,
     ) -> connector::Result<Vec<RecordProjection>> {
-        self.catch(async move { write::update_records(&self.inner, model, record_filter, args).await })
+        self.catch(async move { write::update_records(self, model, record_filter, args).await })
             .await
     }
 
     async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
-        self.catch(async move { write::delete_records(&self.inner, model, record_filter).await })
+        self.catch(async move { write::delete_records(self, model, record_filter).await })
             .await
     }
 
@@ -117,7 +141,7 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::connect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::connect(self, field, parent_id, child_ids).await })
             .await
     }
 
@@ -127,17 +151,17 @@ impl<'tx> WriteOperations for SqlConnectorTransaction<'tx> {
         parent_id: &RecordProjection,
         child_ids: &[RecordProjection],
     ) -> connector::Result<()> {
-        self.catch(async move { write::disconnect(&self.inner, field, parent_id, child_ids).await })
+        self.catch(async move { write::disconnect(self, field, parent_id, child_ids).await })
             .await
     }
 
     async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
-        self.catch(async move { write::execute_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::execute_raw(self, query, parameters).await })
             .await
     }
 
     async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
-        self.catch(async move { write::query_raw(&self.inner, query, parameters).await })
+        self.catch(async move { write::query_raw(self, query, parameters).await })
             .await
     }
 }
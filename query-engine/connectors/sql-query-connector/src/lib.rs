@@ -7,10 +7,12 @@ mod query_arguments_ext;
 mod query_builder;
 mod query_ext;
 mod row;
+mod statement_recorder;
 
 use filter_conversion::*;
 use query_ext::QueryExt;
 use row::*;
+use statement_recorder::StatementRecorder;
 
 pub use database::*;
 pub use error::SqlError;
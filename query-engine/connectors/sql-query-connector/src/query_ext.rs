@@ -1,4 +1,4 @@
-use crate::{error::*, AliasedCondition, SqlRow, ToSqlRow};
+use crate::{error::*, statement_recorder::result_set_to_json, AliasedCondition, SqlRow, StatementRecorder, ToSqlRow};
 use async_trait::async_trait;
 use connector_interface::{filter::Filter, RecordFilter};
 use datamodel::FieldArity;
@@ -6,20 +6,67 @@ use futures::future::FutureExt;
 use prisma_models::*;
 use quaint::{
     ast::*,
-    connector::{self, Queryable},
-    pooled::PooledConnection,
+    connector::{Queryable, ResultSet},
+    prelude::ConnectionInfo,
+    visitor::{self, Visitor},
 };
 
-use serde_json::{Map, Value};
+use serde_json::Value;
 use std::{convert::TryFrom, panic::AssertUnwindSafe};
+use tracing::{field, info_span, Span};
+use tracing_futures::Instrument;
 
-impl<'t> QueryExt for connector::Transaction<'t> {}
-impl QueryExt for PooledConnection {}
-
-/// An extension trait for Quaint's `Queryable`, offering certain Prisma-centric
-/// database operations on top of `Queryable`.
+/// Prisma-centric database operations on top of a Quaint `Queryable`. Every SQL statement is run in
+/// an `sql_statement` span, with the rendered statement and its number of parameters. Statements built by
+/// the engine are also recorded by the statement recorder if it is recording.
 #[async_trait]
-pub trait QueryExt: Queryable + Send + Sync {
+pub trait QueryExt: Send + Sync {
+    /// The connection or transaction the statements are run on.
+    fn queryable(&self) -> &dyn Queryable;
+
+    /// Determines the SQL dialect statements are rendered in for their spans and the statement recorder.
+    fn connection_info(&self) -> &ConnectionInfo;
+
+    /// Records the statements run on the connection or transaction, if any.
+    fn statement_recorder(&self) -> Option<&StatementRecorder> {
+        None
+    }
+
+    async fn query(&self, q: Query<'_>) -> quaint::Result<ResultSet> {
+        self.record(&q).await?;
+        let span = statement_span(self.connection_info(), &q);
+        self.queryable().query(q).instrument(span).await
+    }
+
+    async fn insert(&self, q: Insert<'_>) -> quaint::Result<ResultSet> {
+        self.record(&q.clone().into()).await?;
+        let span = statement_span(self.connection_info(), &q.clone().into());
+        self.queryable().insert(q).instrument(span).await
+    }
+
+    async fn delete(&self, q: Delete<'_>) -> quaint::Result<()> {
+        self.record(&q.clone().into()).await?;
+        let span = statement_span(self.connection_info(), &q.clone().into());
+        self.queryable().delete(q).instrument(span).await
+    }
+
+    /// Records the statement with the statement recorder, if it is recording. Only statements rendered by the
+    /// engine are recorded, raw statements are neither recorded nor explained.
+    async fn record(&self, q: &Query<'_>) -> quaint::Result<()> {
+        match self.statement_recorder() {
+            Some(recorder) if recorder.is_recording() => {
+                let (statement, params) = render_statement(self.connection_info(), q)?;
+
+                recorder
+                    .record(self.queryable(), self.connection_info(), &statement, &params)
+                    .await;
+
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Filter and map the resulting types with the given identifiers.
     async fn filter(&self, q: Query<'_>, idents: &[(TypeIdentifier, FieldArity)]) -> crate::Result<Vec<SqlRow>> {
         let result_set = self.query(q).await?;
@@ -40,23 +87,12 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<Value, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
-        let result_set = AssertUnwindSafe(self.query_raw(&q, &params)).catch_unwind().await??;
-
-        let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
-        let mut result = Vec::new();
-
-        for row in result_set.into_iter() {
-            let mut object = Map::new();
+        let span = raw_statement_span(&q, params.len());
+        let result_set = AssertUnwindSafe(self.queryable().query_raw(&q, &params).instrument(span))
+            .catch_unwind()
+            .await??;
 
-            for (idx, p_value) in row.into_iter().enumerate() {
-                let column_name: String = columns[idx].clone();
-                object.insert(column_name, Value::from(p_value));
-            }
-
-            result.push(Value::Object(object));
-        }
-
-        Ok(Value::Array(result))
+        Ok(result_set_to_json(result_set))
     }
 
     /// Execute a singular SQL query in the database, returning the number of
@@ -67,7 +103,10 @@ pub trait QueryExt: Queryable + Send + Sync {
         params: Vec<PrismaValue>,
     ) -> std::result::Result<usize, crate::error::RawError> {
         let params: Vec<_> = params.into_iter().map(quaint::ast::Value::from).collect();
-        let changes = AssertUnwindSafe(self.execute_raw(&q, &params)).catch_unwind().await??;
+        let span = raw_statement_span(&q, params.len());
+        let changes = AssertUnwindSafe(self.queryable().execute_raw(&q, &params).instrument(span))
+            .catch_unwind()
+            .await??;
 
         Ok(changes as usize)
     }
@@ -147,3 +186,37 @@ pub trait QueryExt: Queryable + Send + Sync {
         Ok(result)
     }
 }
+
+/// The statement is only rendered if the span is recorded by a subscriber.
+fn statement_span(connection_info: &ConnectionInfo, query: &Query<'_>) -> Span {
+    let span = info_span!("sql_statement", db.statement = field::Empty, db.params = field::Empty);
+
+    if span.is_disabled() {
+        return span;
+    }
+
+    if let Ok((statement, params)) = render_statement(connection_info, query) {
+        span.record("db.statement", &statement.as_str());
+        span.record("db.params", &(params.len() as u64));
+    }
+
+    span
+}
+
+/// Renders the query in the SQL dialect of the database, with its parameters.
+fn render_statement<'a>(
+    connection_info: &ConnectionInfo,
+    query: &Query<'a>,
+) -> quaint::Result<(String, Vec<quaint::ast::Value<'a>>)> {
+    match connection_info {
+        ConnectionInfo::Postgres(..) => visitor::Postgres::build(query.clone()),
+        ConnectionInfo::Mysql(..) => visitor::Mysql::build(query.clone()),
+        ConnectionInfo::Sqlite { .. } => visitor::Sqlite::build(query.clone()),
+        ConnectionInfo::Mssql(..) => visitor::Mssql::build(query.clone()),
+    }
+}
+
+fn raw_statement_span(statement: &str, params: usize) -> Span {
+    let params = params as u64;
+    info_span!("sql_statement", db.statement = statement, db.params = params)
+}
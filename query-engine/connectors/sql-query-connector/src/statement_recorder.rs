@@ -0,0 +1,136 @@
+use connector_interface::RecordedStatement;
+use quaint::{
+    ast::Value,
+    connector::{Queryable, ResultSet},
+    prelude::ConnectionInfo,
+};
+use serde_json::Map;
+use std::sync::Mutex;
+
+/// The savepoint statements are explained in, so that a failed explanation can be rolled back.
+const EXPLAIN_SAVEPOINT: &str = "prisma_explain";
+
+/// Records the statements run in a transaction, e.g. to explain a request. Does nothing until started.
+#[derive(Default)]
+pub struct StatementRecorder {
+    recording: Mutex<Option<Recording>>,
+}
+
+struct Recording {
+    explain: bool,
+    statements: Vec<RecordedStatement>,
+}
+
+impl StatementRecorder {
+    /// Starts recording. With `explain`, every statement is explained before it runs.
+    pub fn start(&self, explain: bool) {
+        *self.recording.lock().unwrap() = Some(Recording {
+            explain,
+            statements: Vec::new(),
+        });
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recording.lock().unwrap().is_some()
+    }
+
+    pub fn statements(&self) -> Vec<RecordedStatement> {
+        match &*self.recording.lock().unwrap() {
+            Some(recording) => recording.statements.clone(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Records the statement about to run on the queryable, explaining it first if requested. Statements the
+    /// database fails to explain are recorded without a plan, the explanation never fails the statement.
+    pub async fn record(
+        &self,
+        queryable: &dyn Queryable,
+        connection_info: &ConnectionInfo,
+        statement: &str,
+        params: &[Value<'_>],
+    ) {
+        let explain = match &*self.recording.lock().unwrap() {
+            Some(recording) => recording.explain,
+            None => return,
+        };
+
+        let plan = match explain_statement(connection_info, statement) {
+            Some(explain_statement) if explain => {
+                match explain_in_savepoint(queryable, &explain_statement, params).await {
+                    Ok(plan) => Some(plan),
+                    Err(err) => {
+                        tracing::debug!("Failed to explain the statement `{}`: {}", statement, err);
+                        None
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        let recorded = RecordedStatement {
+            statement: statement.to_owned(),
+            parameters: params.iter().cloned().map(serde_json::Value::from).collect(),
+            plan,
+        };
+
+        if let Some(recording) = &mut *self.recording.lock().unwrap() {
+            recording.statements.push(recorded);
+        }
+    }
+}
+
+/// Explains the statement in a savepoint, as a failed statement aborts the whole transaction on PostgreSQL.
+async fn explain_in_savepoint(
+    queryable: &dyn Queryable,
+    explain_statement: &str,
+    params: &[Value<'_>],
+) -> quaint::Result<serde_json::Value> {
+    queryable.raw_cmd(&format!("SAVEPOINT {}", EXPLAIN_SAVEPOINT)).await?;
+
+    match queryable.query_raw(explain_statement, params).await {
+        Ok(result_set) => {
+            queryable
+                .raw_cmd(&format!("RELEASE SAVEPOINT {}", EXPLAIN_SAVEPOINT))
+                .await?;
+
+            Ok(result_set_to_json(result_set))
+        }
+        Err(err) => {
+            queryable
+                .raw_cmd(&format!("ROLLBACK TO SAVEPOINT {}", EXPLAIN_SAVEPOINT))
+                .await?;
+
+            Err(err)
+        }
+    }
+}
+
+/// The statement asking the database for the plan of the statement. `None` if the database can't explain
+/// statements without running them.
+fn explain_statement(connection_info: &ConnectionInfo, statement: &str) -> Option<String> {
+    match connection_info {
+        ConnectionInfo::Postgres(..) | ConnectionInfo::Mysql(..) => Some(format!("EXPLAIN {}", statement)),
+        ConnectionInfo::Sqlite { .. } => Some(format!("EXPLAIN QUERY PLAN {}", statement)),
+        ConnectionInfo::Mssql(..) => None,
+    }
+}
+
+/// The rows of the result set as an array of JSON objects, keyed by column name.
+pub(crate) fn result_set_to_json(result_set: ResultSet) -> serde_json::Value {
+    let columns: Vec<String> = result_set.columns().into_iter().map(ToString::to_string).collect();
+    let mut result = Vec::new();
+
+    for row in result_set.into_iter() {
+        let mut object = Map::new();
+
+        for (idx, p_value) in row.into_iter().enumerate() {
+            let column_name: String = columns[idx].clone();
+            object.insert(column_name, serde_json::Value::from(p_value));
+        }
+
+        result.push(serde_json::Value::Object(object));
+    }
+
+    serde_json::Value::Array(result)
+}
@@ -1,6 +1,7 @@
 use crate::{InterpreterError, QueryGraphBuilderError, QueryGraphError, QueryParserError, RelationViolation};
 use connector::error::ConnectorError;
 use prisma_models::DomainError;
+use std::time::Duration;
 use thiserror::Error;
 
 // TODO: Cleanup unused errors after refactorings.
@@ -32,6 +33,12 @@ pub enum CoreError {
 
     #[error("{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[error("The query did not finish within the timeout of {} ms.", _0.as_millis())]
+    QueryTimeout(Duration),
+
+    #[error("The query engine is shutting down.")]
+    ShuttingDown,
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
@@ -133,6 +140,17 @@ impl From<CoreError> for user_facing_errors::Error {
             })
             .unwrap()
             .into(),
+            CoreError::QueryGraphBuilderError(QueryGraphBuilderError::FieldValidationFailed {
+                field_name,
+                model_name,
+                details,
+            }) => user_facing_errors::KnownError::new(user_facing_errors::query_engine::FieldValidationFailed {
+                field_name,
+                model_name,
+                details,
+            })
+            .unwrap()
+            .into(),
             CoreError::QueryGraphBuilderError(QueryGraphBuilderError::InputError(details)) => {
                 user_facing_errors::KnownError::new(user_facing_errors::query_engine::InputError { details })
                     .unwrap()
@@ -171,6 +189,18 @@ impl From<CoreError> for user_facing_errors::Error {
                     .into(),
                 }
             }
+            CoreError::QueryTimeout(timeout) => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryTimeout {
+                    timeout_milliseconds: timeout.as_millis() as u64,
+                })
+                .unwrap()
+                .into()
+            }
+            CoreError::ShuttingDown => {
+                user_facing_errors::KnownError::new(user_facing_errors::query_engine::ShuttingDown)
+                    .unwrap()
+                    .into()
+            }
             _ => user_facing_errors::Error::from_dyn_error(&err),
         }
     }
@@ -1,57 +1,272 @@
-use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, ResponseData};
+use super::{
+    metrics::{record_commit, record_rollback, OperationMetrics},
+    pipeline::QueryPipeline,
+    shutdown::Shutdown,
+    timeout::{statement_canceller, with_timeout},
+    ExecutionOptions, QueryExecutor, QueryPlan,
+};
+use crate::{
+    format_expression, CacheInvalidator, CacheKey, CacheLookup, Env, Expressionista, FindOneLoader, IrSerializer,
+    Operation, PendingCacheEntry, QueryCache, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, QueryType,
+    ResponseData,
+};
 use async_trait::async_trait;
 use connector::{Connection, ConnectionLike, Connector};
 use futures::future;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
     /// The loaded connector
     connector: C,
 
+    /// Connectors to read replicas of the database. Read-only operations are distributed over them
+    /// round-robin, everything else runs on `connector`.
+    replicas: Vec<C>,
+
+    /// The index of the replica the next read-only operation runs on, modulo the number of replicas.
+    next_replica: AtomicUsize,
+
+    /// Batches the `findOne` reads of concurrent operations on the primary.
+    primary_loader: Arc<FindOneLoader>,
+
+    /// Batches the `findOne` reads of concurrent operations on the replicas.
+    replica_loader: Arc<FindOneLoader>,
+
+    /// Caches the responses of read-only operations, if enabled.
+    query_cache: Option<Arc<QueryCache>>,
+
     /// Flag that forces individual operations to run in a transaction.
     /// Does _not_ force batches to use transactions.
     force_transactions: bool,
+
+    /// Refuses and aborts executions once the executor shuts down.
+    shutdown: Arc<Shutdown>,
+}
+
+/// An operation with its query graph built, ready to be executed.
+struct PreparedOperation {
+    query: QueryType,
+    serializer: IrSerializer,
+    metrics: OperationMetrics,
+    start: Instant,
+
+    /// The key of the response in the query cache, if the operation is read-only and the cache enabled.
+    cache_key: Option<CacheKey>,
+
+    /// Caches the response once executed, after a cache miss.
+    cache_entry: Option<PendingCacheEntry>,
+
+    /// Invalidates the cached responses of the models the operation writes to.
+    cache_invalidator: Option<Arc<CacheInvalidator>>,
 }
 
 impl<C> InterpretingExecutor<C>
 where
     C: Connector + Send + Sync,
 {
-    pub fn new(connector: C, force_transactions: bool) -> Self {
+    pub fn new(connector: C, replicas: Vec<C>, force_transactions: bool) -> Self {
         InterpretingExecutor {
             connector,
+            replicas,
+            next_replica: AtomicUsize::new(0),
+            primary_loader: Arc::new(FindOneLoader::new()),
+            replica_loader: Arc::new(FindOneLoader::new()),
+            query_cache: None,
             force_transactions,
+            shutdown: Arc::new(Shutdown::new()),
         }
     }
 
+    /// Caches the responses of read-only operations in the process. Only the writes of this process
+    /// invalidate the cache, so read-only operations forced onto the primary are not served from it.
+    pub fn with_query_cache(mut self, query_cache: QueryCache) -> Self {
+        self.query_cache = Some(Arc::new(query_cache));
+        self
+    }
+
+    /// Parse, validate, and extract query graph from query document.
+    fn prepare_operation(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<PreparedOperation> {
+        let start = Instant::now();
+        let metrics = OperationMetrics::new(&operation, &query_schema);
+        let cached_operation = self.query_cache.as_ref().map(|_| operation.clone());
+
+        // Operations failing to build are still requests, counted with their error.
+        let (query, serializer) = match QueryGraphBuilder::new(query_schema).build(operation) {
+            Ok(built) => built,
+            Err(err) => {
+                metrics.record_execution(start.elapsed());
+                return Err(err.into());
+            }
+        };
+
+        metrics.record_query(&query);
+
+        let cache_key = cached_operation.and_then(|operation| QueryCache::key(&operation, &query));
+        let cache_invalidator = match self.query_cache {
+            Some(ref cache) if cache_key.is_none() => Some(Arc::new(CacheInvalidator::new(Arc::clone(cache)))),
+            _ => None,
+        };
+
+        Ok(PreparedOperation {
+            query,
+            serializer,
+            metrics,
+            start,
+            cache_key,
+            cache_entry: None,
+            cache_invalidator,
+        })
+    }
+
+    /// The cached response of the operation. Otherwise, the operation caches its response once executed if
+    /// it runs on the primary: a replica may not have replicated the writes that invalidated the cache yet.
+    fn cached_response(&self, operation: &mut PreparedOperation, options: ExecutionOptions) -> Option<ResponseData> {
+        let (cache, key) = match (&self.query_cache, &operation.cache_key) {
+            (Some(cache), Some(key)) => (cache, key),
+            _ => return None,
+        };
+
+        // Forced reads may be after writes of other processes, which don't invalidate the cache, but their
+        // own responses are fresh.
+        if options.force_primary {
+            operation.cache_entry = Some(cache.entry(key));
+            return None;
+        }
+
+        match cache.get(key) {
+            CacheLookup::Hit(response) => {
+                operation.metrics.record_cache_hit();
+                operation.metrics.record_execution(operation.start.elapsed());
+
+                Some(response)
+            }
+            CacheLookup::Miss(entry) => {
+                operation.metrics.record_cache_miss();
+
+                if self.reads_primary(&operation.query, false) {
+                    operation.cache_entry = Some(entry);
+                }
+
+                None
+            }
+        }
+    }
+
+    /// The connector to execute the query on, and the loader batching its `findOne` reads if enabled.
+    /// Only read-only queries outside of transactions are sent to a replica, unless the caller forces them
+    /// onto the primary, e.g. to read its own writes.
+    fn connector_for(&self, query: &QueryType, force_primary: bool) -> (&C, Option<Arc<FindOneLoader>>) {
+        let (connector, loader) = if self.reads_primary(query, force_primary) {
+            (&self.connector, &self.primary_loader)
+        } else {
+            let index = self.next_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+            (&self.replicas[index], &self.replica_loader)
+        };
+
+        // Batching is currently experimental. It adds the `BATCH_WINDOW` of the loader to every lookup opening a
+        // batch, even when no other lookup joins it.
+        let loader = if feature_flags::get().findOneBatching {
+            Some(Arc::clone(loader))
+        } else {
+            None
+        };
+
+        (connector, loader)
+    }
+
+    /// Whether the query runs on the primary, see `connector_for`.
+    fn reads_primary(&self, query: &QueryType, force_primary: bool) -> bool {
+        let needs_primary =
+            force_primary || self.force_transactions || query.needs_transaction() || !query.is_read_only();
+
+        needs_primary || self.replicas.is_empty()
+    }
+
     /// Async wrapper for executing an individual operation to allow code sharing with `execute_batch`.
     async fn execute_single_operation(
-        operation: Operation,
+        operation: PreparedOperation,
         conn: Box<dyn Connection>,
+        find_one_loader: Option<Arc<FindOneLoader>>,
         force_transactions: bool,
-        query_schema: QuerySchemaRef,
+        shutdown: Arc<Shutdown>,
+        options: ExecutionOptions,
     ) -> crate::Result<ResponseData> {
-        // Parse, validate, and extract query graph from query document.
-        let (query, serializer) = QueryGraphBuilder::new(query_schema).build(operation)?;
+        let PreparedOperation {
+            query,
+            serializer,
+            metrics,
+            start,
+            cache_key: _,
+            cache_entry,
+            cache_invalidator,
+        } = operation;
         let needs_transaction = force_transactions || query.needs_transaction();
+        let canceller = statement_canceller(conn.as_ref(), options.timeout).await?;
 
-        if needs_transaction {
+        let result = if needs_transaction {
             let tx = conn.start_transaction().await?;
-            let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query, interpreter, serializer).execute().await;
+            let mut interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
 
-            if result.is_ok() {
-                tx.commit().await?;
-            } else {
-                tx.rollback().await?;
+            if let Some(ref invalidator) = cache_invalidator {
+                interpreter = interpreter.with_cache_invalidator(Arc::clone(invalidator));
             }
 
-            result
+            let pipeline = QueryPipeline::new(query, interpreter, serializer);
+            let result = with_timeout(options.timeout, &shutdown, canceller, pipeline.execute()).await;
+
+            // Failed commits and rollbacks fail the operation, which is still counted below.
+            match result {
+                Ok(response) => match tx.commit().await {
+                    Ok(()) => {
+                        record_commit();
+                        Ok(response)
+                    }
+                    Err(err) => Err(err.into()),
+                },
+                Err(err) => match tx.rollback().await {
+                    Ok(()) => {
+                        record_rollback();
+                        Err(err)
+                    }
+                    Err(rollback_err) => Err(rollback_err.into()),
+                },
+            }
         } else {
-            let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query, interpreter, serializer).execute().await
+            let mut interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
+
+            // Reads in transactions are never batched, as they must see the writes of the transaction.
+            if let Some(loader) = find_one_loader {
+                interpreter = interpreter.with_find_one_loader(loader);
+            }
+
+            if let Some(ref invalidator) = cache_invalidator {
+                interpreter = interpreter.with_cache_invalidator(Arc::clone(invalidator));
+            }
+
+            let pipeline = QueryPipeline::new(query, interpreter, serializer);
+            with_timeout(options.timeout, &shutdown, canceller, pipeline.execute()).await
+        };
+
+        // Invalidates the written models once more, now that the writes are committed or rolled back.
+        drop(cache_invalidator);
+
+        if let (Ok(response), Some(entry)) = (&result, cache_entry) {
+            entry.insert(response);
         }
+
+        metrics.record_execution(start.elapsed());
+        result
     }
 }
 
@@ -76,7 +291,10 @@ where
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>> {
+        let _running = self.shutdown.start_execution()?;
+
         if transactional {
             // Transactional batches are currently experimental
             if !feature_flags::get().transaction {
@@ -87,37 +305,91 @@ where
 
             let queries = operations
                 .into_iter()
-                .map(|op| QueryGraphBuilder::new(query_schema.clone()).build(op))
-                .collect::<std::result::Result<Vec<_>, _>>()?;
+                .map(|op| {
+                    let start = Instant::now();
+                    let metrics = OperationMetrics::new(&op, &query_schema);
+                    let (query, info) = match QueryGraphBuilder::new(query_schema.clone()).build(op) {
+                        Ok(built) => built,
+                        Err(err) => {
+                            metrics.record_execution(start.elapsed());
+                            return Err(err.into());
+                        }
+                    };
+
+                    metrics.record_query(&query);
+
+                    Ok((query, info, metrics))
+                })
+                .collect::<crate::Result<Vec<_>>>()?;
 
+            // Transactions always run on the primary.
             let conn = self.connector.get_connection().await?;
+            let canceller = statement_canceller(conn.as_ref(), options.timeout).await?;
             let tx = conn.start_transaction().await?;
-            let mut results = Vec::with_capacity(queries.len());
+            let cache_invalidator = self
+                .query_cache
+                .as_ref()
+                .map(|cache| Arc::new(CacheInvalidator::new(Arc::clone(cache))));
+
+            let execution = async {
+                let mut results = Vec::with_capacity(queries.len());
+
+                for (query, info, metrics) in queries {
+                    let start = Instant::now();
+                    let mut interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+
+                    if let Some(ref invalidator) = cache_invalidator {
+                        interpreter = interpreter.with_cache_invalidator(Arc::clone(invalidator));
+                    }
 
-            for (query, info) in queries {
-                let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-                let result = QueryPipeline::new(query, interpreter, info).execute().await;
+                    let result = QueryPipeline::new(query, interpreter, info).execute().await;
+                    metrics.record_execution(start.elapsed());
 
-                if !result.is_ok() {
-                    tx.rollback().await?;
+                    results.push(Ok(result?));
                 }
 
-                results.push(Ok(result?));
+                Ok::<_, crate::CoreError>(results)
+            };
+
+            let results = with_timeout(options.timeout, &self.shutdown, canceller, execution).await;
+
+            if results.is_ok() {
+                tx.commit().await?;
+                record_commit();
+            } else {
+                tx.rollback().await?;
+                record_rollback();
             }
 
-            tx.commit().await?;
-            Ok(results)
+            // Invalidates the written models once more, now that the writes are committed or rolled back.
+            drop(cache_invalidator);
+
+            results
         } else {
             let mut futures = Vec::with_capacity(operations.len());
 
             for operation in operations {
-                let conn = self.connector.get_connection().await?;
-                futures.push(tokio::spawn(Self::execute_single_operation(
-                    operation,
-                    conn,
-                    self.force_transactions,
-                    query_schema.clone(),
-                )));
+                match self.prepare_operation(operation, query_schema.clone()) {
+                    Ok(mut operation) => {
+                        if let Some(response) = self.cached_response(&mut operation, options) {
+                            futures.push(tokio::spawn(future::ok(response)));
+                            continue;
+                        }
+
+                        let (connector, loader) = self.connector_for(&operation.query, options.force_primary);
+                        let conn = connector.get_connection().await?;
+                        futures.push(tokio::spawn(Self::execute_single_operation(
+                            operation,
+                            conn,
+                            loader,
+                            self.force_transactions,
+                            Arc::clone(&self.shutdown),
+                            options,
+                        )));
+                    }
+                    // Like failed executions, invalid operations don't fail the batch.
+                    Err(err) => futures.push(tokio::spawn(future::err(err))),
+                }
             }
 
             let responses: Vec<_> = future::join_all(futures)
@@ -131,12 +403,102 @@ where
     }
 
     /// Executes a single operation. Execution will be inside of a transaction or not depending on the needs of the query.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData> {
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
+    ) -> crate::Result<ResponseData> {
+        let _running = self.shutdown.start_execution()?;
+        let mut operation = self.prepare_operation(operation, query_schema)?;
+
+        if let Some(response) = self.cached_response(&mut operation, options) {
+            return Ok(response);
+        }
+
+        let (connector, loader) = self.connector_for(&operation.query, options.force_primary);
+        let conn = connector.get_connection().await?;
+
+        Self::execute_single_operation(
+            operation,
+            conn,
+            loader,
+            self.force_transactions,
+            Arc::clone(&self.shutdown),
+            options,
+        )
+        .await
+    }
+
+    /// Explains a single operation. Its writes are rolled back, but the statements still run on the
+    /// primary, e.g. take locks until the rollback. Raw queries are refused, as the database would
+    /// explain statements the engine doesn't control.
+    async fn explain(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
+        explain_statements: bool,
+    ) -> crate::Result<QueryPlan> {
+        let _running = self.shutdown.start_execution()?;
+        let (query, _) = QueryGraphBuilder::new(query_schema).build(operation)?;
+
+        let mut graph = match query {
+            QueryType::Graph(graph) => graph,
+            QueryType::Raw { .. } => {
+                return Err(crate::CoreError::UnsupportedFeatureError(
+                    "Explaining raw queries.".to_owned(),
+                ))
+            }
+        };
+
+        graph.finalize()?;
+        let query_graph = graph.to_string();
+        let expression = Expressionista::translate(graph)?;
+        let formatted_expression = format_expression(&expression, 0);
+
+        // Even read-only operations run in a transaction, to record their statements.
         let conn = self.connector.get_connection().await?;
-        Self::execute_single_operation(operation, conn, self.force_transactions, query_schema.clone()).await
+        let canceller = statement_canceller(conn.as_ref(), options.timeout).await?;
+        let tx = conn.start_transaction().await?;
+        tx.record_statements(explain_statements);
+
+        let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
+        let execution =
+            async { Ok::<_, crate::CoreError>(interpreter.interpret(expression, Env::default(), 0).await?) };
+        let result = with_timeout(options.timeout, &self.shutdown, canceller, execution).await;
+
+        let statements = tx.recorded_statements();
+        let rollback = tx.rollback().await;
+
+        // The execution error explains the failure, the rollback error only follows from it.
+        if let Err(err) = rollback {
+            if result.is_ok() {
+                return Err(err.into());
+            }
+
+            warn!("Failed to roll back the transaction of an explained operation: {}", err);
+        }
+
+        result?;
+
+        Ok(QueryPlan {
+            query_graph,
+            expression: formatted_expression,
+            statements,
+        })
     }
 
     fn primary_connector(&self) -> &dyn Connector {
         &self.connector
     }
+
+    async fn shutdown(&self, timeout: Duration) {
+        self.shutdown.shut_down(timeout).await;
+        self.connector.close();
+
+        for replica in self.replicas.iter() {
+            replica.close();
+        }
+    }
 }
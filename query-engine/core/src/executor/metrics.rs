@@ -0,0 +1,61 @@
+use crate::{Operation, QuerySchema, QueryType, SchemaQueryBuilder};
+use std::time::Duration;
+
+/// Records the metrics of an operation, labeled with the operation and model.
+pub(super) struct OperationMetrics {
+    operation: String,
+    model: String,
+}
+
+impl OperationMetrics {
+    /// Model operations like `findOneUser` are labeled with their query tag and model, other operations
+    /// like `executeRaw` only with their name.
+    pub fn new(operation: &Operation, query_schema: &QuerySchema) -> Self {
+        let field = match operation {
+            Operation::Read(_) => query_schema.find_query_field(operation.name()),
+            Operation::Write(_) => query_schema.find_mutation_field(operation.name()),
+        };
+
+        let (operation, model) = match field.as_ref().map(|field| field.query_builder()) {
+            Some(Some(SchemaQueryBuilder::ModelQueryBuilder(builder))) => {
+                (builder.tag.to_string(), builder.model.name.clone())
+            }
+            Some(_) => (operation.name().to_owned(), String::new()),
+            // Unknown operations fail validation, and would only add label values.
+            None => ("unknown".to_owned(), String::new()),
+        };
+
+        Self { operation, model }
+    }
+
+    pub fn record_query(&self, query: &QueryType) {
+        if let QueryType::Graph(graph) = query {
+            query_engine_metrics::QUERY_GRAPH_NODES.observe(&self.labels(), graph.node_count() as f64);
+        }
+    }
+
+    pub fn record_execution(&self, duration: Duration) {
+        query_engine_metrics::REQUESTS.inc(&self.labels());
+        query_engine_metrics::REQUEST_DURATION.observe(&self.labels(), duration.as_secs_f64());
+    }
+
+    pub fn record_cache_hit(&self) {
+        query_engine_metrics::QUERY_CACHE_HITS.inc(&self.labels());
+    }
+
+    pub fn record_cache_miss(&self) {
+        query_engine_metrics::QUERY_CACHE_MISSES.inc(&self.labels());
+    }
+
+    fn labels(&self) -> [&str; 2] {
+        [&self.operation, &self.model]
+    }
+}
+
+pub(super) fn record_commit() {
+    query_engine_metrics::TRANSACTIONS.inc(&["commit"]);
+}
+
+pub(super) fn record_rollback() {
+    query_engine_metrics::TRANSACTIONS.inc(&["rollback"]);
+}
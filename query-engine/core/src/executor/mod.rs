@@ -6,18 +6,28 @@
 //! What the executor module DOES NOT DO:
 //! - Define low level execution of queries. This is considered an implementation detail of the modules used by the executors.
 mod interpreting_executor;
+mod metrics;
 mod pipeline;
+mod shutdown;
+mod timeout;
 
 pub use interpreting_executor::*;
 
 use crate::{query_document::Operation, response_ir::ResponseData, schema::QuerySchemaRef};
 use async_trait::async_trait;
-use connector::Connector;
+use connector::{Connector, RecordedStatement};
+use serde::Serialize;
+use std::time::Duration;
 
 #[async_trait]
 pub trait QueryExecutor {
     /// Executes a single operation and returns its result.
-    async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<ResponseData>;
+    async fn execute(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
+    ) -> crate::Result<ResponseData>;
 
     // Executes a batch of operations as either a fanout of individual operations (non-transactional), or in series (transactional).
     async fn execute_batch(
@@ -25,7 +35,50 @@ pub trait QueryExecutor {
         operations: Vec<Operation>,
         transactional: bool,
         query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
     ) -> crate::Result<Vec<crate::Result<ResponseData>>>;
 
+    /// Explains how the operation runs: the query graph built for it, the expression tree the interpreter
+    /// runs and the statements the connector runs, explained by the database if `explain_statements` is set.
+    /// The operation runs on the primary, in a transaction that is always rolled back. Raw queries can't be
+    /// explained.
+    async fn explain(
+        &self,
+        operation: Operation,
+        query_schema: QuerySchemaRef,
+        options: ExecutionOptions,
+        explain_statements: bool,
+    ) -> crate::Result<QueryPlan>;
+
     fn primary_connector(&self) -> &dyn Connector;
+
+    /// Refuses new executions and aborts the running ones, rolling back their transactions. Once they
+    /// finished, or at the latest after the timeout, closes the connection pools of the connectors.
+    async fn shutdown(&self, timeout: Duration);
+}
+
+/// Options of the execution of the operations of a request.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExecutionOptions {
+    /// Runs read-only operations on the primary database instead of a read replica, e.g. to read the
+    /// writes of a previous request.
+    pub force_primary: bool,
+
+    /// Cancels operations that don't finish in time. Applies to every operation of a non-transactional
+    /// batch, and to a transactional batch as a whole.
+    pub timeout: Option<Duration>,
+}
+
+/// How an operation runs, to debug slow requests.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryPlan {
+    /// The query graph built for the operation, formatted.
+    pub query_graph: String,
+
+    /// The expression tree the interpreter runs, formatted.
+    pub expression: String,
+
+    /// The statements the operation ran, in order.
+    pub statements: Vec<RecordedStatement>,
 }
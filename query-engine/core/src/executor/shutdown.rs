@@ -0,0 +1,95 @@
+use crate::CoreError;
+use futures::{
+    channel::oneshot,
+    future::{FutureExt, Shared},
+};
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// How often the executor checks whether the aborted executions finished, e.g. rolled back their transactions.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Shuts the executor down: refuses new executions and aborts the running ones.
+pub(super) struct Shutdown {
+    started: AtomicBool,
+    running: AtomicUsize,
+    sender: Mutex<Option<oneshot::Sender<()>>>,
+    aborted: Shared<oneshot::Receiver<()>>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        let (sender, receiver) = oneshot::channel();
+
+        Self {
+            started: AtomicBool::new(false),
+            running: AtomicUsize::new(0),
+            sender: Mutex::new(Some(sender)),
+            aborted: receiver.shared(),
+        }
+    }
+
+    /// Counts an execution as running until the returned guard is dropped. Fails once the shutdown started.
+    pub fn start_execution(self: &Arc<Self>) -> crate::Result<RunningExecution> {
+        // Counting first makes sure the shutdown waits for every execution that didn't see it start.
+        self.running.fetch_add(1, Ordering::SeqCst);
+        let execution = RunningExecution(Arc::clone(self));
+
+        if self.started.load(Ordering::SeqCst) {
+            return Err(CoreError::ShuttingDown);
+        }
+
+        Ok(execution)
+    }
+
+    /// Resolves once the shutdown started, to abort the running executions.
+    pub fn aborted(&self) -> impl Future<Output = ()> {
+        self.aborted.clone().map(|_| ())
+    }
+
+    /// Refuses new executions, aborts the running ones and waits until they finished, at most for the
+    /// timeout. Executions still running after it, e.g. waiting for a rollback, are abandoned.
+    pub async fn shut_down(&self, timeout: Duration) {
+        self.started.store(true, Ordering::SeqCst);
+
+        if let Some(sender) = self.sender.lock().unwrap().take() {
+            let _ = sender.send(());
+        }
+
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let running = self.running.load(Ordering::SeqCst);
+
+            if running == 0 {
+                return;
+            }
+
+            if Instant::now() >= deadline {
+                warn!(
+                    "{} aborted executions still running after {:?}, abandoning them.",
+                    running, timeout
+                );
+
+                return;
+            }
+
+            tokio::time::delay_for(POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// An execution counted as running by the shutdown.
+pub(super) struct RunningExecution(Arc<Shutdown>);
+
+impl Drop for RunningExecution {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
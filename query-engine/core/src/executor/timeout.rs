@@ -0,0 +1,74 @@
+use super::shutdown::Shutdown;
+use crate::CoreError;
+use connector::{Connection, StatementCanceller};
+use futures::{
+    future::{self, Either},
+    pin_mut,
+};
+use std::{future::Future, time::Duration};
+
+/// The canceller of the statements of the connection, if the execution has a timeout. Looking it up
+/// may take a round trip to the database, so it is skipped without a timeout.
+pub(super) async fn statement_canceller(
+    conn: &dyn Connection,
+    timeout: Option<Duration>,
+) -> crate::Result<Option<Box<dyn StatementCanceller>>> {
+    match timeout {
+        Some(_) => Ok(conn.statement_canceller().await?),
+        None => Ok(None),
+    }
+}
+
+/// How long an interrupted execution may take to finish after its statement was cancelled.
+const CANCELLATION_GRACE_PERIOD: Duration = Duration::from_secs(1);
+
+/// Runs the execution until it finishes, the timeout expires or the executor shuts down. If interrupted,
+/// the statement the execution runs is cancelled, and the execution is awaited until it fails, before the
+/// connection can be used again, e.g. to roll back the transaction of the execution. That way the
+/// cancellation can't hit a statement sent after the execution. Without a timeout, there is no canceller
+/// and rolling back waits for the statement to finish.
+pub(super) async fn with_timeout<T>(
+    timeout: Option<Duration>,
+    shutdown: &Shutdown,
+    canceller: Option<Box<dyn StatementCanceller>>,
+    execution: impl Future<Output = crate::Result<T>>,
+) -> crate::Result<T> {
+    let expiry = async {
+        match timeout {
+            Some(timeout) => {
+                tokio::time::delay_for(timeout).await;
+                CoreError::QueryTimeout(timeout)
+            }
+            None => future::pending().await,
+        }
+    };
+
+    let aborted = async {
+        shutdown.aborted().await;
+        CoreError::ShuttingDown
+    };
+
+    pin_mut!(execution, expiry, aborted);
+
+    match future::select(execution, future::select(expiry, aborted)).await {
+        Either::Left((result, _)) => result,
+        Either::Right((interruption, execution)) => {
+            let (err, _) = interruption.factor_first();
+
+            if let Some(canceller) = canceller {
+                // The interruption is reported either way, failing to cancel only leaves the statement running.
+                match canceller.cancel().await {
+                    Ok(()) => {
+                        let _ = tokio::time::timeout(CANCELLATION_GRACE_PERIOD, execution).await;
+                    }
+                    Err(cancel_err) => warn!(
+                        "Failed to cancel a statement after an interruption ({}): {}",
+                        err, cancel_err
+                    ),
+                }
+            }
+
+            Err(err)
+        }
+    }
+}
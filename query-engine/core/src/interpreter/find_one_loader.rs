@@ -0,0 +1,213 @@
+use connector::{
+    filter::{Filter, QueryMode, ScalarCondition, ScalarFilter, ScalarProjection},
+    ConnectionLike, IdFilter, QueryArguments, ReadOperations,
+};
+use futures::channel::oneshot;
+use prisma_models::{ModelProjection, ModelRef, Record, RecordProjection, ScalarFieldRef, SingleRecord};
+use prisma_value::PrismaValue;
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+/// How long the first lookup of a batch waits for concurrent lookups to join it.
+const BATCH_WINDOW: Duration = Duration::from_millis(2);
+
+/// Coalesces concurrent `findOne` lookups of records by their unique fields into a single `IN` query.
+///
+/// The first lookup of a model by a set of unique fields opens a batch, waits for `BATCH_WINDOW`, and
+/// reads the records of every lookup that joined the batch in the meantime on its own connection.
+/// Lookups whose record isn't found in the batch, e.g. because the database compares the values
+/// differently than the engine, or whose batch failed, fall back to querying the record themselves.
+/// Batched lookups therefore return the same results as individual ones.
+///
+/// Only `findOne` reads, top-level or nested, go through the loader. Nested relation reads already load
+/// the related records of all parents in one query per relation, see `nested_read`, and are not batched
+/// across requests.
+///
+/// Lookups must run on connections that see the same data, so reads of the primary and of the
+/// replicas use separate loaders, and lookups in transactions are never batched.
+#[derive(Default)]
+pub struct FindOneLoader {
+    batches: Mutex<HashMap<BatchKey, Vec<Lookup>>>,
+}
+
+/// Lookups can only share a query if they read the same model by the same fields, and select the
+/// same fields.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct BatchKey {
+    model: String,
+    unique_fields: Vec<String>,
+    selected_fields: Vec<String>,
+}
+
+struct Lookup {
+    unique_values: RecordProjection,
+    sender: oneshot::Sender<SingleRecord>,
+}
+
+impl FindOneLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the record matching the filter, batched with concurrent lookups if the filter only
+    /// compares unique fields for equality.
+    pub async fn get_single_record<'conn, 'tx>(
+        &self,
+        conn: &'conn ConnectionLike<'conn, 'tx>,
+        model: &ModelRef,
+        filter: &Filter,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<SingleRecord>> {
+        let unique_values = match unique_values(filter) {
+            Some(unique_values) => unique_values,
+            None => return conn.get_single_record(model, filter, selected_fields).await,
+        };
+
+        let key = BatchKey {
+            model: model.name.clone(),
+            unique_fields: unique_values.fields().map(|field| field.name.clone()).collect(),
+            selected_fields: selected_fields.db_names().collect(),
+        };
+
+        let unique_fields = ModelProjection::from(&unique_values);
+        let (sender, receiver) = oneshot::channel();
+        let opens_batch = {
+            let mut batches = self.batches.lock().unwrap();
+            let lookups = batches.entry(key.clone()).or_insert_with(Vec::new);
+            lookups.push(Lookup { unique_values, sender });
+
+            lookups.len() == 1
+        };
+
+        if opens_batch {
+            // Closes the batch if this lookup is dropped while waiting, so that the lookups that
+            // joined it fall back instead of waiting for a batch that is never executed.
+            let batch = OpenBatch {
+                loader: self,
+                key: Some(key),
+            };
+            tokio::time::delay_for(BATCH_WINDOW).await;
+
+            let lookups = batch.close();
+            let result = execute_batch(conn, model, &unique_fields, selected_fields, lookups).await;
+
+            if let Err(err) = result {
+                debug!(
+                    "Batched findOne lookups failed, falling back to individual queries: {}",
+                    err
+                );
+            }
+        }
+
+        match receiver.await {
+            Ok(record) => Ok(Some(record)),
+            Err(_) => conn.get_single_record(model, filter, selected_fields).await,
+        }
+    }
+
+    fn take_batch(&self, key: &BatchKey) -> Vec<Lookup> {
+        self.batches.lock().unwrap().remove(key).unwrap_or_default()
+    }
+}
+
+/// A batch that lookups can still join. `None` once closed, as a later lookup may open a new batch
+/// with the same key.
+struct OpenBatch<'a> {
+    loader: &'a FindOneLoader,
+    key: Option<BatchKey>,
+}
+
+impl<'a> OpenBatch<'a> {
+    fn close(mut self) -> Vec<Lookup> {
+        match self.key.take() {
+            Some(key) => self.loader.take_batch(&key),
+            None => vec![],
+        }
+    }
+}
+
+impl<'a> Drop for OpenBatch<'a> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.loader.take_batch(&key);
+        }
+    }
+}
+
+/// Reads the records of all lookups in one query, and sends each lookup its record. Lookups without
+/// a record are dropped, which makes them fall back.
+async fn execute_batch<'conn, 'tx>(
+    conn: &'conn ConnectionLike<'conn, 'tx>,
+    model: &ModelRef,
+    unique_fields: &ModelProjection,
+    selected_fields: &ModelProjection,
+    lookups: Vec<Lookup>,
+) -> connector::Result<()> {
+    let filter = lookups
+        .iter()
+        .map(|lookup| lookup.unique_values.clone())
+        .collect::<Vec<_>>()
+        .filter();
+
+    // The unique fields are needed to match the records to the lookups.
+    let query_fields = selected_fields.clone().merge(unique_fields.clone());
+    let records = conn
+        .get_many_records(model, QueryArguments::from((model.clone(), filter)), &query_fields)
+        .await?;
+
+    let mut records_by_unique_values = HashMap::with_capacity(records.records.len());
+
+    for record in records.records.iter() {
+        let values = record.projection(&records.field_names, unique_fields)?;
+        let selected_values = record.projection(&records.field_names, selected_fields)?;
+
+        records_by_unique_values.insert(values, selected_values.values().collect::<Vec<_>>());
+    }
+
+    let field_names: Vec<String> = selected_fields.db_names().collect();
+
+    for lookup in lookups {
+        if let Some(values) = records_by_unique_values.get(&lookup.unique_values) {
+            let record = SingleRecord::new(Record::new(values.clone()), field_names.clone());
+
+            // The lookup was dropped if the receiver is gone.
+            let _ = lookup.sender.send(record);
+        }
+    }
+
+    Ok(())
+}
+
+/// The values of the fields of a filter that only compares fields for equality, e.g. a unique filter
+/// of a `findOne`, sorted by field name. `None` for all other filters.
+fn unique_values(filter: &Filter) -> Option<RecordProjection> {
+    let mut pairs = Vec::new();
+    collect_equals(filter, &mut pairs)?;
+
+    pairs.sort_by(|(a, _), (b, _)| a.name.cmp(&b.name));
+
+    let has_duplicates = pairs.windows(2).any(|pair| pair[0].0.name == pair[1].0.name);
+
+    if pairs.is_empty() || has_duplicates {
+        None
+    } else {
+        Some(RecordProjection::new(pairs))
+    }
+}
+
+fn collect_equals(filter: &Filter, pairs: &mut Vec<(ScalarFieldRef, PrismaValue)>) -> Option<()> {
+    match filter {
+        Filter::And(filters) => filters.iter().try_for_each(|filter| collect_equals(filter, pairs)),
+
+        // `NULL` never matches in an `IN` list.
+        Filter::Scalar(ScalarFilter {
+            projection: ScalarProjection::Single(field),
+            condition: ScalarCondition::Equals(value),
+            mode: QueryMode::Default,
+        }) if !value.is_null() => {
+            pairs.push((field.clone(), value.clone()));
+            Some(())
+        }
+
+        _ => None,
+    }
+}
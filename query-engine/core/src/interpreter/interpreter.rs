@@ -1,5 +1,7 @@
 use super::{
     expression::*,
+    find_one_loader::FindOneLoader,
+    query_cache::CacheInvalidator,
     query_interpreters::{read, write},
     InterpretationResult, InterpreterError,
 };
@@ -9,6 +11,9 @@ use crossbeam_queue::SegQueue;
 use futures::future::{BoxFuture, FutureExt};
 use im::HashMap;
 use prisma_models::prelude::*;
+use std::sync::Arc;
+use tracing::Span;
+use tracing_futures::Instrument;
 
 #[derive(Debug, Clone)]
 pub enum ExpressionResult {
@@ -123,6 +128,8 @@ impl Env {
 }
 pub struct QueryInterpreter<'conn, 'tx> {
     pub(crate) conn: ConnectionLike<'conn, 'tx>,
+    find_one_loader: Option<Arc<FindOneLoader>>,
+    cache_invalidator: Option<Arc<CacheInvalidator>>,
     log: SegQueue<String>,
 }
 
@@ -141,14 +148,42 @@ where
             log.push("\n".to_string());
         }
 
-        Self { conn, log }
+        Self {
+            conn,
+            find_one_loader: None,
+            cache_invalidator: None,
+            log,
+        }
+    }
+
+    /// Batches the `findOne` reads of the interpreted queries with the ones of concurrent operations.
+    pub fn with_find_one_loader(mut self, loader: Arc<FindOneLoader>) -> Self {
+        self.find_one_loader = Some(loader);
+        self
+    }
+
+    /// Invalidates the cached responses of the models written to by the interpreted queries.
+    pub fn with_cache_invalidator(mut self, invalidator: Arc<CacheInvalidator>) -> Self {
+        self.cache_invalidator = Some(invalidator);
+        self
     }
 
+    /// Interprets the expression in a span, named like the line of the expression in the log.
     pub fn interpret(
         &'conn self,
         exp: Expression,
         env: Env,
         level: usize,
+    ) -> BoxFuture<'conn, InterpretationResult<ExpressionResult>> {
+        let span = expression_span(&exp);
+        self.interpret_expression(exp, env, level).instrument(span).boxed()
+    }
+
+    fn interpret_expression(
+        &'conn self,
+        exp: Expression,
+        env: Env,
+        level: usize,
     ) -> BoxFuture<'conn, InterpretationResult<ExpressionResult>> {
         match exp {
             Expression::Func { func } => {
@@ -209,13 +244,18 @@ where
                     match query {
                         Query::Read(read) => {
                             self.log_line(level, || format!("READ {}", read));
-                            Ok(read::execute(&self.conn, read, None)
+                            Ok(read::execute(&self.conn, read, None, self.find_one_loader.as_deref())
                                 .await
                                 .map(|res| ExpressionResult::Query(res))?)
                         }
 
                         Query::Write(write) => {
                             self.log_line(level, || format!("WRITE {}", write));
+
+                            if let Some(invalidator) = &self.cache_invalidator {
+                                invalidator.invalidate(&write);
+                            }
+
                             Ok(write::execute(&self.conn, write)
                                 .await
                                 .map(|res| ExpressionResult::Query(res))?)
@@ -294,3 +334,22 @@ where
         }
     }
 }
+
+fn expression_span(exp: &Expression) -> Span {
+    match exp {
+        // Functions only produce the expression to interpret, which gets its own span.
+        Expression::Func { .. } => Span::none(),
+        Expression::Sequence { .. } => debug_span!("expression", kind = "SEQ"),
+        Expression::Let { .. } => debug_span!("expression", kind = "LET"),
+        Expression::Query {
+            query: Query::Read(read),
+        } => debug_span!("expression", kind = "READ", query = %read),
+        Expression::Query {
+            query: Query::Write(write),
+        } => debug_span!("expression", kind = "WRITE", query = %write),
+        Expression::Get { binding_name } => debug_span!("expression", kind = "GET", binding = %binding_name),
+        Expression::GetFirstNonEmpty { .. } => debug_span!("expression", kind = "GET FIRST NON EMPTY"),
+        Expression::If { .. } => debug_span!("expression", kind = "IF"),
+        Expression::Return { .. } => debug_span!("expression", kind = "RETURN"),
+    }
+}
@@ -1,15 +1,19 @@
 mod error;
 mod expression;
 mod expressionista;
+mod find_one_loader;
 mod formatters;
 mod interpreter;
+mod query_cache;
 
 pub(self) mod query_interpreters;
 
 pub use error::*;
 pub use expression::*;
 pub use expressionista::*;
+pub use find_one_loader::*;
 pub use formatters::*;
 pub use interpreter::*;
+pub use query_cache::*;
 
 type InterpretationResult<T> = std::result::Result<T, InterpreterError>;
@@ -0,0 +1,364 @@
+use crate::{Operation, QueryType, QueryValue, ReadQuery, ResponseData, Selection, WriteQuery};
+use connector::filter::Filter;
+use indexmap::IndexMap;
+use prisma_models::ModelRef;
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Write,
+    sync::{Arc, Mutex},
+};
+
+/// In-process cache of the responses of read-only operations.
+///
+/// Responses are cached by the normalized query document of their operation, which includes the
+/// selected fields, and are invalidated by model: the interpreter invalidates the models of every
+/// write query it executes, including nested writes and raw queries, and once more after the write
+/// is committed or rolled back. A response is only cached if none of the models it was read from was
+/// invalidated while it was read, so that uncommitted or concurrent writes are never cached.
+///
+/// The cache only sees the writes of this process, and replicas may not have replicated them yet, so
+/// only responses read from the primary are cached. It holds at most `max_entries` responses and
+/// evicts the least recently used one when full.
+pub struct QueryCache {
+    max_entries: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Cached responses by normalized query document, the least recently used first.
+    entries: IndexMap<String, CacheEntry>,
+
+    /// The number of times each model was invalidated.
+    model_versions: HashMap<String, u64>,
+
+    /// The number of times all models were invalidated.
+    epoch: u64,
+}
+
+struct CacheEntry {
+    response: ResponseData,
+    versions: Versions,
+}
+
+/// The versions of the models a response is read from.
+#[derive(Debug, Clone, PartialEq)]
+struct Versions {
+    epoch: u64,
+    models: Vec<(String, u64)>,
+}
+
+/// A read-only operation whose response can be cached.
+#[derive(Debug, Clone)]
+pub struct CacheKey {
+    document: String,
+    models: BTreeSet<String>,
+}
+
+/// The result of a cache lookup.
+pub enum CacheLookup {
+    Hit(ResponseData),
+
+    /// The response must be read, and can then be cached with the entry.
+    Miss(PendingCacheEntry),
+}
+
+/// A response that is being read, to be cached once it is.
+pub struct PendingCacheEntry {
+    cache: Arc<QueryCache>,
+    document: String,
+    versions: Versions,
+}
+
+impl QueryCache {
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// The key of the response of the operation. `None` if the operation writes, or might write.
+    pub fn key(operation: &Operation, query: &QueryType) -> Option<CacheKey> {
+        let (selection, graph) = match (operation, query) {
+            (Operation::Read(selection), QueryType::Graph(graph)) if !graph.has_writes() => (selection, graph),
+            _ => return None,
+        };
+
+        let mut document = String::new();
+        write_selection(&mut document, selection);
+
+        let mut models = BTreeSet::new();
+
+        for read in graph.read_queries() {
+            collect_read_models(read, &mut models);
+        }
+
+        Some(CacheKey { document, models })
+    }
+
+    pub fn get(self: &Arc<Self>, key: &CacheKey) -> CacheLookup {
+        let mut state = self.state.lock().unwrap();
+        let versions = state.versions(&key.models);
+
+        // Entries are moved to the back when used, so that the front is the least recently used one.
+        match state.entries.shift_remove(&key.document) {
+            Some(entry) if entry.versions == versions => {
+                let response = entry.response.clone();
+                state.entries.insert(key.document.clone(), entry);
+
+                CacheLookup::Hit(response)
+            }
+            _ => CacheLookup::Miss(PendingCacheEntry {
+                cache: Arc::clone(self),
+                document: key.document.clone(),
+                versions,
+            }),
+        }
+    }
+
+    /// An entry to cache the response with once it is read, without looking up the cached response.
+    pub fn entry(self: &Arc<Self>, key: &CacheKey) -> PendingCacheEntry {
+        let state = self.state.lock().unwrap();
+
+        PendingCacheEntry {
+            cache: Arc::clone(self),
+            document: key.document.clone(),
+            versions: state.versions(&key.models),
+        }
+    }
+
+    /// Drops the cached responses read from the models.
+    fn invalidate_models(&self, models: &BTreeSet<String>) {
+        let mut state = self.state.lock().unwrap();
+
+        for model in models {
+            *state.model_versions.entry(model.clone()).or_insert(0) += 1;
+        }
+
+        state
+            .entries
+            .retain(|_, entry| !entry.versions.models.iter().any(|(model, _)| models.contains(model)));
+    }
+
+    /// Drops all cached responses.
+    fn invalidate_all(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        state.epoch += 1;
+        state.entries.clear();
+    }
+}
+
+impl CacheState {
+    fn versions(&self, models: &BTreeSet<String>) -> Versions {
+        let models = models
+            .iter()
+            .map(|model| (model.clone(), self.model_versions.get(model).copied().unwrap_or(0)))
+            .collect();
+
+        Versions {
+            epoch: self.epoch,
+            models,
+        }
+    }
+}
+
+impl PendingCacheEntry {
+    /// Caches the response, unless one of its models was invalidated since the lookup.
+    pub fn insert(self, response: &ResponseData) {
+        let cache = self.cache;
+        let mut state = cache.state.lock().unwrap();
+        let models = self.versions.models.iter().map(|(model, _)| model.clone()).collect();
+
+        if cache.max_entries == 0 || state.versions(&models) != self.versions {
+            return;
+        }
+
+        if !state.entries.contains_key(&self.document) && state.entries.len() >= cache.max_entries {
+            state.entries.shift_remove_index(0);
+        }
+
+        state.entries.insert(
+            self.document,
+            CacheEntry {
+                response: response.clone(),
+                versions: self.versions,
+            },
+        );
+    }
+}
+
+/// Invalidates the models written to by an operation, or the batch of operations of a transaction.
+/// The models are invalidated when they're written to, and once more when the invalidator is dropped,
+/// which must happen after the writes are committed or rolled back.
+pub struct CacheInvalidator {
+    cache: Arc<QueryCache>,
+    invalidated: Mutex<Invalidation>,
+}
+
+#[derive(Default)]
+struct Invalidation {
+    models: BTreeSet<String>,
+    all: bool,
+}
+
+impl CacheInvalidator {
+    pub fn new(cache: Arc<QueryCache>) -> Self {
+        Self {
+            cache,
+            invalidated: Mutex::new(Invalidation::default()),
+        }
+    }
+
+    /// Invalidates the models the write query may change.
+    pub fn invalidate(&self, write: &WriteQuery) {
+        let mut invalidated = self.invalidated.lock().unwrap();
+
+        match written_models(write) {
+            Some(models) => {
+                self.cache.invalidate_models(&models);
+                invalidated.models.extend(models);
+            }
+            None => {
+                self.cache.invalidate_all();
+                invalidated.all = true;
+            }
+        }
+    }
+}
+
+impl Drop for CacheInvalidator {
+    fn drop(&mut self) {
+        let invalidated = self.invalidated.get_mut().unwrap();
+
+        if invalidated.all {
+            self.cache.invalidate_all();
+        } else if !invalidated.models.is_empty() {
+            self.cache.invalidate_models(&invalidated.models);
+        }
+    }
+}
+
+/// Renders the selection with its arguments sorted by name, as their order doesn't change the response.
+/// The order of the nested selections does, it's the order of the fields in the response.
+fn write_selection(out: &mut String, selection: &Selection) {
+    let mut arguments: Vec<_> = selection.arguments().iter().collect();
+    arguments.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    write!(out, "{}", selection.name()).unwrap();
+
+    if let Some(alias) = selection.alias() {
+        write!(out, " as {}", alias).unwrap();
+    }
+
+    out.push('(');
+
+    for (name, value) in arguments {
+        write!(out, "{}:", name).unwrap();
+        write_value(out, value);
+        out.push(',');
+    }
+
+    out.push_str("){");
+
+    for nested in selection.nested_selections() {
+        write_selection(out, nested);
+        out.push(',');
+    }
+
+    out.push('}');
+}
+
+/// Renders the value with the fields of its input objects sorted by name, at any depth, like arguments.
+/// The order of list items does matter, e.g. for `orderBy` or `in` lists.
+fn write_value(out: &mut String, value: &QueryValue) {
+    match value {
+        QueryValue::List(values) => {
+            out.push('[');
+
+            for value in values {
+                write_value(out, value);
+                out.push(',');
+            }
+
+            out.push(']');
+        }
+        QueryValue::Object(fields) => {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            out.push('{');
+
+            for (name, value) in fields {
+                write!(out, "{}:", name).unwrap();
+                write_value(out, value);
+                out.push(',');
+            }
+
+            out.push('}');
+        }
+        _ => write!(out, "{:?}", value).unwrap(),
+    }
+}
+
+/// Collects the models the read query and its nested reads select from or filter by.
+fn collect_read_models(read: &ReadQuery, models: &mut BTreeSet<String>) {
+    models.insert(read.model().name.clone());
+
+    let (filter, nested) = match read {
+        ReadQuery::RecordQuery(q) => (q.filter.as_ref(), q.nested.as_slice()),
+        ReadQuery::ManyRecordsQuery(q) => (q.args.filter.as_ref(), q.nested.as_slice()),
+        ReadQuery::RelatedRecordsQuery(q) => (q.args.filter.as_ref(), q.nested.as_slice()),
+        ReadQuery::AggregateRecordsQuery(q) => (q.args.filter.as_ref(), &[][..]),
+    };
+
+    if let Some(filter) = filter {
+        collect_filter_models(filter, models);
+    }
+
+    for nested in nested {
+        collect_read_models(nested, models);
+    }
+}
+
+fn collect_filter_models(filter: &Filter, models: &mut BTreeSet<String>) {
+    match filter {
+        Filter::And(filters) | Filter::Or(filters) | Filter::Not(filters) => {
+            for filter in filters {
+                collect_filter_models(filter, models);
+            }
+        }
+        Filter::Relation(filter) => {
+            models.insert(filter.field.related_model().name.clone());
+            collect_filter_models(&filter.nested_filter, models);
+        }
+        Filter::OneRelationIsNull(filter) => {
+            models.insert(filter.field.related_model().name.clone());
+        }
+        _ => (),
+    }
+}
+
+/// The models the write query may change. Creates and updates may change the relations, and with them
+/// the relation fields, of the related models. `None` if any model may change: deletes may cascade
+/// arbitrarily far, and raw queries are opaque.
+fn written_models(write: &WriteQuery) -> Option<BTreeSet<String>> {
+    let models: Vec<ModelRef> = match write {
+        WriteQuery::CreateRecord(q) => with_related_models(&q.model),
+        WriteQuery::UpdateRecord(q) => with_related_models(&q.model),
+        WriteQuery::UpdateManyRecords(q) => with_related_models(&q.model),
+        WriteQuery::ConnectRecords(q) => vec![q.relation_field.model(), q.relation_field.related_model()],
+        WriteQuery::DisconnectRecords(q) => vec![q.relation_field.model(), q.relation_field.related_model()],
+        WriteQuery::DeleteRecord(_) | WriteQuery::DeleteManyRecords(_) | WriteQuery::Raw { .. } => return None,
+    };
+
+    Some(models.into_iter().map(|model| model.name.clone()).collect())
+}
+
+fn with_related_models(model: &ModelRef) -> Vec<ModelRef> {
+    let mut models = vec![model.clone()];
+    models.extend(model.fields().relation().iter().map(|field| field.related_model()));
+
+    models
+}
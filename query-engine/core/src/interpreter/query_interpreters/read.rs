@@ -1,5 +1,9 @@
 use super::*;
-use crate::{interpreter::InterpretationResult, query_ast::*, result_ast::*};
+use crate::{
+    interpreter::{FindOneLoader, InterpretationResult},
+    query_ast::*,
+    result_ast::*,
+};
 use connector::{self, ConnectionLike, QueryArguments, ReadOperations};
 use futures::future::{BoxFuture, FutureExt};
 use inmemory_record_processor::InMemoryRecordProcessor;
@@ -9,12 +13,13 @@ pub fn execute<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     query: ReadQuery,
     parent_result: Option<&'a ManyRecords>,
+    find_one_loader: Option<&'a FindOneLoader>,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
         match query {
-            ReadQuery::RecordQuery(q) => read_one(tx, q).await,
-            ReadQuery::ManyRecordsQuery(q) => read_many(tx, q).await,
-            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result).await,
+            ReadQuery::RecordQuery(q) => read_one(tx, q, find_one_loader).await,
+            ReadQuery::ManyRecordsQuery(q) => read_many(tx, q, find_one_loader).await,
+            ReadQuery::RelatedRecordsQuery(q) => read_related(tx, q, parent_result, find_one_loader).await,
             ReadQuery::AggregateRecordsQuery(q) => aggregate(tx, q).await,
         }
     };
@@ -22,24 +27,34 @@ pub fn execute<'a, 'b>(
     fut.boxed()
 }
 
-/// Queries a single record.
+/// Queries a single record, batched with concurrent lookups if a loader is given.
 fn read_one<'conn, 'tx>(
     tx: &'conn ConnectionLike<'conn, 'tx>,
     query: RecordQuery,
+    find_one_loader: Option<&'conn FindOneLoader>,
 ) -> BoxFuture<'conn, InterpretationResult<QueryResult>> {
     let fut = async move {
         let model = query.model;
         let model_id = model.primary_identifier();
         let filter = query.filter.expect("Expected filter to be set for ReadOne query.");
-        let scalars = tx.get_single_record(&model, &filter, &query.selected_fields).await?;
+        let scalars = match find_one_loader {
+            Some(loader) => {
+                loader
+                    .get_single_record(tx, &model, &filter, &query.selected_fields)
+                    .await?
+            }
+            None => tx.get_single_record(&model, &filter, &query.selected_fields).await?,
+        };
 
         match scalars {
             Some(record) => {
                 let records: ManyRecords = record.into();
-                let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&records)).await?;
+                let nested: Vec<QueryResult> =
+                    process_nested(tx, query.nested, Some(&records), find_one_loader).await?;
 
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
+                    alias: query.alias,
                     fields: query.selection_order,
                     scalars: records,
                     nested,
@@ -50,6 +65,7 @@ fn read_one<'conn, 'tx>(
 
             None => Ok(QueryResult::RecordSelection(RecordSelection {
                 name: query.name,
+                alias: query.alias,
                 fields: query.selection_order,
                 model_id,
                 scalars: ManyRecords::default(),
@@ -71,6 +87,7 @@ fn read_one<'conn, 'tx>(
 fn read_many<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: ManyRecordsQuery,
+    find_one_loader: Option<&'a FindOneLoader>,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
         let scalars = if query.args.distinct.is_some() || query.args.contains_unstable_cursor() {
@@ -86,10 +103,11 @@ fn read_many<'a, 'b>(
         };
 
         let model_id = query.model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars), find_one_loader).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
+            alias: query.alias,
             fields: query.selection_order,
             query_arguments: query.args,
             model_id,
@@ -106,6 +124,7 @@ fn read_related<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     mut query: RelatedRecordsQuery,
     parent_result: Option<&'a ManyRecords>,
+    find_one_loader: Option<&'a FindOneLoader>,
 ) -> BoxFuture<'a, InterpretationResult<QueryResult>> {
     let fut = async move {
         let relation = query.parent_field.relation();
@@ -129,10 +148,11 @@ fn read_related<'a, 'b>(
 
         let model = query.parent_field.related_model();
         let model_id = model.primary_identifier();
-        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars)).await?;
+        let nested: Vec<QueryResult> = process_nested(tx, query.nested, Some(&scalars), find_one_loader).await?;
 
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
+            alias: query.alias,
             fields: query.selection_order,
             query_arguments: query.args,
             model_id,
@@ -159,10 +179,13 @@ async fn aggregate<'a, 'b>(
     }))
 }
 
+/// Executes the nested reads of the parent records. Related records are read in one query for all parents,
+/// see `nested_read`, so the loader only batches nested `findOne` lookups.
 fn process_nested<'a, 'b>(
     tx: &'a ConnectionLike<'a, 'b>,
     nested: Vec<ReadQuery>,
     parent_result: Option<&'a ManyRecords>,
+    find_one_loader: Option<&'a FindOneLoader>,
 ) -> BoxFuture<'a, InterpretationResult<Vec<QueryResult>>> {
     let fut = async move {
         let results = if matches!(parent_result, Some(parent_records) if parent_records.records.is_empty()) {
@@ -173,7 +196,7 @@ fn process_nested<'a, 'b>(
             let mut nested_results = Vec::with_capacity(nested.len());
 
             for query in nested {
-                let result = execute(tx, query, parent_result).await?;
+                let result = execute(tx, query, parent_result, find_one_loader).await?;
                 nested_results.push(result);
             }
 
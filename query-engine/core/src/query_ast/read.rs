@@ -100,7 +100,9 @@ pub struct RecordQuery {
     pub filter: Option<Filter>,
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
-    pub selection_order: Vec<String>,
+
+    /// The response keys and names of the selected fields, in the order of the query selection.
+    pub selection_order: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -111,7 +113,9 @@ pub struct ManyRecordsQuery {
     pub args: QueryArguments,
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
-    pub selection_order: Vec<String>,
+
+    /// The response keys and names of the selected fields, in the order of the query selection.
+    pub selection_order: Vec<(String, String)>,
 }
 
 #[derive(Debug, Clone)]
@@ -122,7 +126,9 @@ pub struct RelatedRecordsQuery {
     pub args: QueryArguments,
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
-    pub selection_order: Vec<String>,
+
+    /// The response keys and names of the selected fields, in the order of the query selection.
+    pub selection_order: Vec<(String, String)>,
 
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
@@ -134,7 +140,9 @@ pub struct AggregateRecordsQuery {
     pub name: String,
     pub alias: Option<String>,
     pub model: ModelRef,
-    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+    /// The response keys and names of the selected aggregations and their selected fields, in the
+    /// order of the query selection.
+    pub selection_order: Vec<(String, String, Option<Vec<(String, String)>>)>,
     pub args: QueryArguments,
     pub aggregators: Vec<Aggregator>,
 }
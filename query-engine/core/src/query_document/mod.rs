@@ -64,6 +64,7 @@ impl BatchDocument {
                 Some((first, rest)) if first.is_find_one() => rest.into_iter().all(|op| {
                     op.is_find_one()
                         && first.name() == op.name()
+                        && first.alias() == op.alias()
                         && first.nested_selections().len() == op.nested_selections().len()
                         && first
                             .nested_selections()
@@ -91,6 +92,7 @@ pub struct CompactedDocument {
     pub operation: Operation,
     pub keys: Vec<String>,
     name: String,
+    alias: Option<String>,
 }
 
 impl CompactedDocument {
@@ -98,6 +100,11 @@ impl CompactedDocument {
         format!("findOne{}", self.name)
     }
 
+    /// The key of the responses of the single queries, their alias if they have one.
+    pub fn single_key(&self) -> String {
+        self.alias.clone().unwrap_or_else(|| self.single_name())
+    }
+
     pub fn plural_name(&self) -> String {
         format!("findMany{}", self.name)
     }
@@ -160,10 +167,7 @@ impl From<Vec<Operation>> for CompactedDocument {
             // expression and with a compound id a combination of `AND` and `OR`.
             builder.push_argument("where", In::new(selection_set));
 
-            if let Some(ref alias) = selections[0].alias() {
-                builder.alias(alias);
-            };
-
+            // The findMany query isn't aliased, its response is looked up by its name.
             builder.build()
         };
 
@@ -173,11 +177,12 @@ impl From<Vec<Operation>> for CompactedDocument {
         let nested_selection = selections[0]
             .nested_selections()
             .iter()
-            .map(|s| s.name().to_string())
+            .map(|s| s.response_key().to_string())
             .collect();
 
         // Saving the stub of the query name for later use.
         let name = selections[0].name().replacen("findOne", "", 1);
+        let alias = selections[0].alias().clone();
 
         // Convert the selections into a vector of arguments. This defines the
         // response order and how we fetch the right data from the response set.
@@ -214,6 +219,7 @@ impl From<Vec<Operation>> for CompactedDocument {
 
         Self {
             name,
+            alias,
             arguments,
             nested_selection,
             keys,
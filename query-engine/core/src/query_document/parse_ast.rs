@@ -34,6 +34,11 @@ pub enum RawQueryType {
 }
 
 impl ParsedField {
+    /// The key of the field in the response, its alias if it has one, its name otherwise.
+    pub fn response_key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
+
     /// For raw SQL queries, returns the expected type of the result sets.
     pub fn raw_query_type(&self) -> Option<RawQueryType> {
         match self.name.as_str() {
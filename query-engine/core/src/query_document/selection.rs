@@ -41,8 +41,11 @@ impl SelectionBuilder {
         self
     }
 
+    /// True if the field is selected without an alias, i.e. its value is returned under its name.
     pub fn contains_nested_selection(&self, name: &str) -> bool {
-        self.nested_selections.iter().any(|sel| sel.name() == name)
+        self.nested_selections
+            .iter()
+            .any(|sel| sel.name() == name && sel.alias().is_none())
     }
 
     pub fn build(self) -> Selection {
@@ -87,11 +90,13 @@ impl Selection {
         }
     }
 
+    /// Removes nested selections with the same response key, the same field can be selected more than
+    /// once under different aliases.
     pub fn dedup(mut self) -> Self {
         self.nested_selections = self
             .nested_selections
             .into_iter()
-            .unique_by(|s| s.name.clone())
+            .unique_by(|s| s.response_key().to_owned())
             .collect();
 
         self
@@ -120,6 +125,11 @@ impl Selection {
     pub fn alias(&self) -> &Option<String> {
         &self.alias
     }
+
+    /// The key of the selection in the response, its alias if it has one, its name otherwise.
+    pub fn response_key(&self) -> &str {
+        self.alias.as_deref().unwrap_or(&self.name)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -6,7 +6,7 @@
 //! assume the data has to be because of the structural guarantees of the query schema validation.
 use super::*;
 use chrono::prelude::*;
-use prisma_models::{OrderBy, PrismaValue, ScalarFieldRef};
+use prisma_models::{OrderBy, PrismaValue, ScalarFieldRef, TypeHint};
 use rust_decimal::prelude::ToPrimitive;
 use std::convert::TryInto;
 
@@ -30,6 +30,48 @@ impl TryInto<PrismaValue> for ParsedInputValue {
     }
 }
 
+impl ParsedInputValue {
+    /// Converts the value of a field storing a composite type into the JSON document it is stored as.
+    pub fn into_composite_value(self) -> QueryParserResult<PrismaValue> {
+        match self {
+            ParsedInputValue::Single(PrismaValue::Null(_)) => Ok(PrismaValue::Null(TypeHint::Json)),
+            v => {
+                let json = v.into_json()?;
+                let serialized = serde_json::to_string(&json).map_err(|err| {
+                    QueryParserError::AssertionError(format!("Serializing a composite value failed: {}", err))
+                })?;
+
+                Ok(PrismaValue::Json(serialized))
+            }
+        }
+    }
+
+    fn into_json(self) -> QueryParserResult<serde_json::Value> {
+        match self {
+            // Nested JSON fields are validated JSON documents already.
+            ParsedInputValue::Single(PrismaValue::Json(s)) => serde_json::from_str(&s)
+                .map_err(|err| QueryParserError::AssertionError(format!("Invalid JSON in a composite value: {}", err))),
+            ParsedInputValue::Single(val) => serde_json::to_value(val).map_err(|err| {
+                QueryParserError::AssertionError(format!("Serializing a composite value failed: {}", err))
+            }),
+            ParsedInputValue::List(values) => values
+                .into_iter()
+                .map(|val| val.into_json())
+                .collect::<QueryParserResult<Vec<_>>>()
+                .map(serde_json::Value::Array),
+            ParsedInputValue::Map(map) => map
+                .into_iter()
+                .map(|(key, val)| val.into_json().map(|json| (key, json)))
+                .collect::<QueryParserResult<serde_json::Map<_, _>>>()
+                .map(serde_json::Value::Object),
+            v => Err(QueryParserError::AssertionError(format!(
+                "Attempted conversion of ParsedInputValue ({:?}) into a composite value failed.",
+                v
+            ))),
+        }
+    }
+}
+
 impl TryInto<ParsedInputMap> for ParsedInputValue {
     type Error = QueryParserError;
 
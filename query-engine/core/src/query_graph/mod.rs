@@ -197,6 +197,31 @@ impl QueryGraph {
         }
     }
 
+    pub fn node_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    /// If true, the graph contains write queries, which must be executed on the primary database.
+    pub fn has_writes(&self) -> bool {
+        self.graph.node_indices().any(|node_ix| {
+            matches!(
+                self.graph.node_weight(node_ix).unwrap().borrow(),
+                Some(Node::Query(Query::Write(_)))
+            )
+        })
+    }
+
+    /// The read queries of the graph.
+    pub fn read_queries(&self) -> Vec<&ReadQuery> {
+        self.graph
+            .node_indices()
+            .filter_map(|node_ix| match self.graph.node_weight(node_ix).unwrap().borrow() {
+                Some(Node::Query(Query::Read(read))) => Some(read),
+                _ => None,
+            })
+            .collect()
+    }
+
     pub fn finalize(&mut self) -> QueryGraphResult<()> {
         if !self.finalized {
             self.swap_marked()?;
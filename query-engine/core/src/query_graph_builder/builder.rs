@@ -24,6 +24,14 @@ impl QueryType {
             Self::Raw { .. } => false,
         }
     }
+
+    /// If true, the query can be executed on a read replica. Raw queries are opaque, they may write.
+    pub fn is_read_only(&self) -> bool {
+        match self {
+            Self::Graph(qg) => !qg.has_writes(),
+            Self::Raw { .. } => false,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -62,10 +70,12 @@ impl QueryGraphBuilder {
 
     /// Maps an operation to a query.
     pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        match operation {
+        let span = info_span!("build_query_graph", operation = operation.name());
+
+        span.in_scope(|| match operation {
             Operation::Read(selection) => self.map_read_operation(selection),
             Operation::Write(selection) => self.map_write_operation(selection),
-        }
+        })
     }
 
     /// Maps a read operation to one or more queries.
@@ -39,6 +39,13 @@ pub enum QueryGraphBuilderError {
 
     RecordNotFound(String),
 
+    /// A written value does not satisfy a `@length`, `@pattern` or `@range` validation of its field.
+    FieldValidationFailed {
+        field_name: String,
+        model_name: String,
+        details: String,
+    },
+
     QueryGraphError(QueryGraphError),
 }
 
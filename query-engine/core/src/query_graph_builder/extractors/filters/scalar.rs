@@ -40,12 +40,12 @@ pub fn parse(
             }
         }
 
-        "equals" if reverse => field.not_equals(as_prisma_value(input)?),
+        "equals" if reverse => field.not_equals(as_equality_value(field, input)?),
         "contains" if reverse => field.not_contains(as_prisma_value(input)?),
         "startsWith" if reverse => field.not_starts_with(as_prisma_value(input)?),
         "endsWith" if reverse => field.not_ends_with(as_prisma_value(input)?),
 
-        "equals" => field.equals(as_prisma_value(input)?),
+        "equals" => field.equals(as_equality_value(field, input)?),
         "contains" => field.contains(as_prisma_value(input)?),
         "startsWith" => field.starts_with(as_prisma_value(input)?),
         "endsWith" => field.ends_with(as_prisma_value(input)?),
@@ -72,3 +72,12 @@ pub fn parse(
 fn as_prisma_value(input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
     Ok(input.try_into()?)
 }
+
+/// Composite values are compared as the JSON document they are stored as.
+fn as_equality_value(field: &ScalarFieldRef, input: ParsedInputValue) -> QueryGraphBuilderResult<PrismaValue> {
+    if field.composite_type.is_some() {
+        Ok(input.into_composite_value()?)
+    } else {
+        as_prisma_value(input)
+    }
+}
@@ -42,16 +42,17 @@ impl AggregateRecordsBuilder {
             .collect()
     }
 
-    fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, Option<Vec<String>>)> {
+    fn collect_selection_tree(fields: &[ParsedField]) -> Vec<(String, String, Option<Vec<(String, String)>>)> {
         fields
-            .into_iter()
+            .iter()
             .map(|field| {
                 (
+                    field.response_key().to_owned(),
                     field.name.clone(),
                     field
                         .nested_fields
                         .as_ref()
-                        .map(|nested_object| nested_object.fields.iter().map(|f| f.name.clone()).collect()),
+                        .map(|nested_object| collect_selection_order(&nested_object.fields)),
                 )
             })
             .collect()
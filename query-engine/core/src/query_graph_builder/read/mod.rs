@@ -31,14 +31,10 @@ impl Builder<ReadQuery> for ReadQueryBuilder {
     }
 }
 
-pub fn collect_selection_order(from: &[ParsedField]) -> Vec<String> {
+/// Collects the response keys, i.e. the aliases or names, and the names of the selected fields.
+pub fn collect_selection_order(from: &[ParsedField]) -> Vec<(String, String)> {
     from.iter()
-        .map(|selected_field| {
-            selected_field
-                .alias
-                .clone()
-                .unwrap_or_else(|| selected_field.name.clone())
-        })
+        .map(|selected_field| (selected_field.response_key().to_owned(), selected_field.name.clone()))
         .collect()
 }
 
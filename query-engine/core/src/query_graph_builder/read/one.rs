@@ -34,7 +34,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
         let alias = self.field.alias;
         let model = self.model;
         let nested_fields = self.field.nested_fields.unwrap().fields;
-        let selection_order: Vec<String> = collect_selection_order(&nested_fields);
+        let selection_order: Vec<(String, String)> = collect_selection_order(&nested_fields);
         let selected_fields = collect_selected_fields(&nested_fields, &model);
         let nested = collect_nested_queries(nested_fields, &model)?;
         let selected_fields = merge_relation_selections(selected_fields, None, &nested);
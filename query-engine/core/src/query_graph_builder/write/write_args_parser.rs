@@ -4,7 +4,8 @@ use crate::{
     InputAssertions,
 };
 use connector::{WriteArgs, WriteExpression};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{dml::FieldValidation, Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use rust_decimal::prelude::ToPrimitive;
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
@@ -23,6 +24,8 @@ impl WriteArgsParser {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
+                    Field::Scalar(sf) if sf.composite_type.is_some() => args.args.insert(sf, v.into_composite_value()?),
+
                     Field::Scalar(sf) if sf.is_list => {
                         let vals: ParsedInputMap = v.try_into()?;
                         let set_value = vals.into_iter().find(|(k, _)| k == "set");
@@ -38,6 +41,7 @@ impl WriteArgsParser {
                             }
                         };
 
+                        validate_value(sf, &set_value)?;
                         args.args.insert(sf, set_value)
                     }
 
@@ -62,6 +66,11 @@ impl WriteArgsParser {
                             _ => unreachable!(),
                         };
 
+                        match expr {
+                            WriteExpression::Value(ref value) => validate_value(sf, value)?,
+                            _ => validate_atomic_operation(sf)?,
+                        }
+
                         args.args.insert(sf, expr)
                     }
 
@@ -76,3 +85,83 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Checks a written value against the `@length`, `@pattern` and `@range` validations of the field.
+/// Lists are checked element by element, and nulls are left to the nullability checks.
+fn validate_value(sf: &ScalarFieldRef, value: &PrismaValue) -> QueryGraphBuilderResult<()> {
+    match value {
+        PrismaValue::Null(_) => Ok(()),
+        PrismaValue::List(values) => values.iter().try_for_each(|value| validate_value(sf, value)),
+        value => sf.validations.iter().try_for_each(|validation| {
+            check_validation(validation, value).map_err(|details| QueryGraphBuilderError::FieldValidationFailed {
+                field_name: sf.name.clone(),
+                model_name: sf.model().name.clone(),
+                details,
+            })
+        }),
+    }
+}
+
+/// The result of an atomic number operation is only known to the database, so it can't be checked against
+/// a `@range`. These operations are rejected on fields with a range.
+fn validate_atomic_operation(sf: &ScalarFieldRef) -> QueryGraphBuilderResult<()> {
+    match sf
+        .validations
+        .iter()
+        .find(|validation| matches!(validation, FieldValidation::Range { .. }))
+    {
+        Some(_) => Err(QueryGraphBuilderError::FieldValidationFailed {
+            field_name: sf.name.clone(),
+            model_name: sf.model().name.clone(),
+            details:
+                "Atomic number operations cannot be used on fields with a `@range` attribute. Set the value instead."
+                    .to_owned(),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn check_validation(validation: &FieldValidation, value: &PrismaValue) -> Result<(), String> {
+    match (validation, value) {
+        (FieldValidation::Length { min, max }, PrismaValue::String(s)) => {
+            let length = s.chars().count() as u64;
+
+            match (min, max) {
+                (Some(min), _) if length < *min => Err(format!(
+                    "The value must be at least {} characters long, but is {} characters long.",
+                    min, length
+                )),
+                (_, Some(max)) if length > *max => Err(format!(
+                    "The value must be at most {} characters long, but is {} characters long.",
+                    max, length
+                )),
+                _ => Ok(()),
+            }
+        }
+        (FieldValidation::Pattern(pattern), PrismaValue::String(s)) => {
+            if pattern.is_match(s) {
+                Ok(())
+            } else {
+                Err(format!("The value must match the pattern `{}`.", pattern))
+            }
+        }
+        (FieldValidation::Range { min, max }, value) => {
+            let number = match value {
+                PrismaValue::Int(i) => *i as f64,
+                PrismaValue::Float(f) => f.to_f64().unwrap_or(f64::NAN),
+                _ => return Ok(()),
+            };
+
+            match (min, max) {
+                // Comparisons with NaN are always false, so it would pass any range.
+                _ if number.is_nan() => Err(format!("The value must be a number, but is {}.", value)),
+                (Some(min), _) if number < *min => {
+                    Err(format!("The value must be at least {}, but is {}.", min, value))
+                }
+                (_, Some(max)) if number > *max => Err(format!("The value must be at most {}, but is {}.", max, value)),
+                _ => Ok(()),
+            }
+        }
+        _ => Ok(()),
+    }
+}
@@ -90,21 +90,22 @@ fn serialize_aggregation(record_aggregation: RecordAggregation) -> crate::Result
         }
     }
 
-    // Reorder fields based on the original query selection.
+    // Reorder fields based on the original query selection, under their response keys.
+    // Results are cloned, as the same aggregation can be selected more than once under different aliases.
     let mut inner_map: Map = IndexMap::with_capacity(ordering.len());
-    for (query, field_order) in ordering {
+    for (key, query, field_order) in ordering {
         if let Some(order) = field_order {
             let mut nested_map = Map::new();
 
-            for field in order {
-                let item = flattened.remove(&format!("{}_{}", query, field)).unwrap();
-                nested_map.insert(field, item);
+            for (field_key, field) in order {
+                let item = flattened.get(&format!("{}_{}", query, field)).cloned().unwrap();
+                nested_map.insert(field_key, item);
             }
 
-            inner_map.insert(query, Item::Map(nested_map));
+            inner_map.insert(key, Item::Map(nested_map));
         } else {
-            let item = flattened.remove(&query).unwrap();
-            inner_map.insert(query, item);
+            let item = flattened.get(&query).cloned().unwrap();
+            inner_map.insert(key, item);
         }
     }
 
@@ -207,8 +208,8 @@ fn serialize_objects(
     // Move nested out of result for separate processing.
     let nested = std::mem::replace(&mut result.nested, Vec::new());
 
-    // { <nested field name> -> { parent ID -> items } }
-    let mut nested_mapping: HashMap<String, CheckedItemsWithParents> = process_nested_results(nested, &typ)?;
+    // { <nested response key> -> (<nested field name>, { parent ID -> items }) }
+    let mut nested_mapping: HashMap<String, (String, CheckedItemsWithParents)> = process_nested_results(nested, &typ)?;
 
     // We need the Arcs to solve the issue where we have multiple parents claiming the same data (we want to move the data out of the nested structure
     // to prevent expensive copying during serialization).
@@ -244,14 +245,21 @@ fn serialize_objects(
             }
         }
 
-        // Write nested results
-        write_nested_items(&record_id, &mut nested_mapping, &mut object, &typ);
+        // Write nested results, by response key.
+        let mut nested_items = HashMap::with_capacity(nested_mapping.len());
+        write_nested_items(&record_id, &mut nested_mapping, &mut nested_items, &typ);
 
+        // Scalars are cloned, as the same field can be selected more than once under different aliases.
         let map = result
             .fields
             .iter()
-            .fold(Map::with_capacity(result.fields.len()), |mut acc, field_name| {
-                acc.insert(field_name.to_owned(), object.remove(field_name).unwrap());
+            .fold(Map::with_capacity(result.fields.len()), |mut acc, (key, field_name)| {
+                let item = match nested_items.remove(key) {
+                    Some(item) => item,
+                    None => object.get(field_name).cloned().unwrap(),
+                };
+
+                acc.insert(key.to_owned(), item);
                 acc
             });
 
@@ -273,17 +281,17 @@ fn serialize_objects(
 /// Unwraps are safe due to query validation.
 fn write_nested_items(
     record_id: &Option<RecordProjection>,
-    items_with_parent: &mut HashMap<String, CheckedItemsWithParents>,
+    items_with_parent: &mut HashMap<String, (String, CheckedItemsWithParents)>,
     into: &mut HashMap<String, Item>,
     enclosing_type: &ObjectTypeStrongRef,
 ) {
-    items_with_parent.iter_mut().for_each(|(field_name, inner)| {
+    items_with_parent.iter_mut().for_each(|(key, (field_name, inner))| {
         let val = inner.get(record_id);
 
         // The value must be a reference (or None - handle default), everything else is an error in the serialization logic.
         match val {
             Some(Item::Ref(ref r)) => {
-                into.insert(field_name.to_owned(), Item::Ref(ItemRef::clone(r)));
+                into.insert(key.to_owned(), Item::Ref(ItemRef::clone(r)));
             }
 
             None => {
@@ -303,18 +311,19 @@ fn write_nested_items(
                     ),
                 };
 
-                into.insert(field_name.to_owned(), Item::Ref(ItemRef::new(default)));
+                into.insert(key.to_owned(), Item::Ref(ItemRef::new(default)));
             }
             _ => panic!("Application logic invariant error: Nested items have to be wrapped as a Item::Ref."),
         };
     });
 }
 
-/// Processes nested results into a more ergonomic structure of { <nested field name> -> { parent ID -> item (list, map, ...) } }.
+/// Processes nested results into a more ergonomic structure of
+/// { <nested response key> -> (<nested field name>, { parent ID -> item (list, map, ...) }) }.
 fn process_nested_results(
     nested: Vec<QueryResult>,
     enclosing_type: &ObjectTypeStrongRef,
-) -> crate::Result<HashMap<String, CheckedItemsWithParents>> {
+) -> crate::Result<HashMap<String, (String, CheckedItemsWithParents)>> {
     // For each nested selected field we need to map the parents to their items.
     let mut nested_mapping = HashMap::with_capacity(nested.len());
 
@@ -324,10 +333,11 @@ fn process_nested_results(
         // todo Workaround, tb changed with flat reads.
         if let QueryResult::RecordSelection(ref rs) = nested_result {
             let name = rs.name.clone();
+            let key = rs.alias.clone().unwrap_or_else(|| name.clone());
             let field = enclosing_type.find_field(&name).unwrap();
             let result = serialize_internal(nested_result, &field.field_type, false, false)?;
 
-            nested_mapping.insert(name, result);
+            nested_mapping.insert(key, (name, result));
         }
     }
 
@@ -17,8 +17,11 @@ pub struct RecordSelection {
     /// Name of the query.
     pub name: String,
 
-    /// Holds an ordered list of selected field names for each contained record.
-    pub fields: Vec<String>,
+    /// Alias of the query, its response key if set.
+    pub alias: Option<String>,
+
+    /// Holds an ordered list of the response keys and names of the selected fields for each contained record.
+    pub fields: Vec<(String, String)>,
 
     /// Scalar field results
     pub scalars: ManyRecords,
@@ -37,7 +40,7 @@ pub struct RecordSelection {
 #[derive(Debug, Clone)]
 pub struct RecordAggregation {
     /// Ordered list of selected fields as defined by the original incoming query.
-    pub selection_order: Vec<(String, Option<Vec<String>>)>,
+    pub selection_order: Vec<(String, String, Option<Vec<(String, String)>>)>,
 
     /// Actual aggregation results.
     pub results: Vec<AggregationResult>,
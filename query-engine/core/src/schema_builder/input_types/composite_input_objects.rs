@@ -0,0 +1,31 @@
+use super::*;
+
+/// Builds "<x>CompositeInput" input object types, the typed input of fields storing a composite type.
+/// The same object is used to create, update and filter on the field, as the value is always written as a whole.
+pub(crate) fn composite_input_type(ctx: &mut BuilderContext, composite_type: &ModelRef) -> InputObjectTypeWeakRef {
+    let name = format!("{}CompositeInput", composite_type.name);
+    return_cached_input!(ctx, &name);
+
+    let input_object = Arc::new(init_input_object_type(name.clone()));
+
+    // Cache empty object for circuit breaking
+    ctx.cache_input_type(name, input_object.clone());
+
+    let fields = composite_type
+        .fields()
+        .scalar()
+        .into_iter()
+        .map(|f| {
+            let typ = if f.is_required && !f.is_list {
+                map_required_field_input_type(ctx, &f)
+            } else {
+                map_optional_field_input_type(ctx, &f)
+            };
+
+            input_field(f.name.clone(), typ, None)
+        })
+        .collect();
+
+    input_object.set_fields(fields);
+    Arc::downgrade(&input_object)
+}
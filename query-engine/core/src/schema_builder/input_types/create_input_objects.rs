@@ -68,14 +68,14 @@ pub(crate) fn create_input_type(
         model.name.clone(),
         "Create",
         scalar_fields,
-        |_, f: ScalarFieldRef| {
+        |ctx, f: ScalarFieldRef| {
             if f.is_required && f.default_value.is_none() && (f.is_created_at() || f.is_updated_at()) {
                 //todo shouldnt these also be Default Value expressions at some point?
                 map_optional_input_type(&f)
             } else if f.is_required && f.default_value.is_none() {
-                map_required_input_type(&f)
+                map_required_field_input_type(ctx, &f)
             } else {
-                map_optional_input_type(&f)
+                map_optional_field_input_type(ctx, &f)
             }
         },
         true,
@@ -53,7 +53,7 @@ fn scalar_list_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> Inp
     let object = Arc::new(init_input_object_type(name.clone()));
     ctx.cache_input_type(name, object.clone());
 
-    let fields = equality_filters(sf).collect();
+    let fields = equality_filters(ctx, sf).collect();
     object.set_fields(fields);
 
     Arc::downgrade(&object)
@@ -67,20 +67,20 @@ fn scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: boo
     ctx.cache_input_type(name, object.clone());
 
     let mut fields: Vec<_> = match sf.type_identifier {
-        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(sf)
+        TypeIdentifier::String | TypeIdentifier::UUID => equality_filters(ctx, sf)
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .chain(string_filters(sf))
             .chain(query_mode_field(ctx, nested))
             .collect(),
 
-        TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::DateTime => equality_filters(sf)
+        TypeIdentifier::Int | TypeIdentifier::Float | TypeIdentifier::DateTime => equality_filters(ctx, sf)
             .chain(inclusion_filters(sf))
             .chain(alphanumeric_filters(sf))
             .collect(),
 
-        TypeIdentifier::Boolean | TypeIdentifier::Json => equality_filters(sf).collect(),
-        TypeIdentifier::Enum(_) => equality_filters(sf).chain(inclusion_filters(sf)).collect(),
+        TypeIdentifier::Boolean | TypeIdentifier::Json => equality_filters(ctx, sf).collect(),
+        TypeIdentifier::Enum(_) => equality_filters(ctx, sf).chain(inclusion_filters(sf)).collect(),
     };
 
     fields.push(input_field(
@@ -93,8 +93,8 @@ fn scalar_filter_type(ctx: &mut BuilderContext, sf: &ScalarFieldRef, nested: boo
     Arc::downgrade(&object)
 }
 
-fn equality_filters(sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
-    let mapped_type = map_optional_input_type(sf);
+fn equality_filters(ctx: &mut BuilderContext, sf: &ScalarFieldRef) -> impl Iterator<Item = InputField> {
+    let mapped_type = map_optional_field_input_type(ctx, sf);
 
     vec![input_field("equals", mapped_type.clone(), None)].into_iter()
 }
@@ -163,6 +163,10 @@ fn scalar_filter_name(sf: &ScalarFieldRef, nested: bool) -> String {
     let nullable = if sf.is_required { "" } else { "Nullable" };
     let nested = if nested { "Nested" } else { "" };
 
+    if let Some(ref composite_type) = sf.composite_type {
+        return format!("{}{}Composite{}{}Filter", nested, composite_type, nullable, list);
+    }
+
     match sf.type_identifier {
         TypeIdentifier::UUID => format!("{}Uuid{}{}Filter", nested, nullable, list),
         TypeIdentifier::String => format!("{}String{}{}Filter", nested, nullable, list),
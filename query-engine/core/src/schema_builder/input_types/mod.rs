@@ -1,3 +1,4 @@
+pub(crate) mod composite_input_objects;
 pub(crate) mod create_input_objects;
 pub(crate) mod field_filter_types;
 pub(crate) mod filter_input_objects;
@@ -61,6 +62,26 @@ fn map_required_input_type(field: &ScalarFieldRef) -> InputType {
     }
 }
 
+fn map_optional_field_input_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
+    InputType::opt(map_required_field_input_type(ctx, field))
+}
+
+/// Like `map_required_input_type`, but maps fields storing a composite type to its typed input object.
+fn map_required_field_input_type(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
+    let composite_type = match field.composite_type() {
+        Some(composite_type) => composite_type,
+        None => return map_required_input_type(field),
+    };
+
+    let typ = InputType::object(composite_input_objects::composite_input_type(ctx, &composite_type));
+
+    match (field.is_list, field.is_required) {
+        (true, _) => InputType::list(typ),
+        (false, true) => typ,
+        (false, false) => InputType::null(typ),
+    }
+}
+
 fn map_enum_input_type(field: &ScalarFieldRef) -> InputType {
     let internal_enum = field
         .internal_enum
@@ -49,6 +49,9 @@ fn scalar_input_fields_for_update(ctx: &mut BuilderContext, model: &ModelRef) ->
 fn scalar_update_field_type_mapper(ctx: &mut BuilderContext, field: &ScalarFieldRef) -> InputType {
     if field.is_list {
         map_optional_input_type(field)
+    } else if field.composite_type().is_some() {
+        // Composite values are always replaced as a whole.
+        map_optional_field_input_type(ctx, field)
     } else {
         let typ = match &field.type_identifier {
             TypeIdentifier::Float => operations_object_type(ctx, "Float", field, true),
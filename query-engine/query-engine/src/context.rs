@@ -18,6 +18,7 @@ pub struct PrismaContext {
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    query_cache_size: Option<usize>,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -33,14 +34,32 @@ impl ContextBuilder {
         self
     }
 
+    pub fn query_cache_size(mut self, val: Option<usize>) -> Self {
+        self.query_cache_size = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.query_cache_size,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        query_cache_size: Option<usize>,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -50,7 +69,7 @@ impl PrismaContext {
             .ok_or_else(|| PrismaError::ConfigurationError("No valid data source found".into()))?;
 
         // Load executor
-        let (db_name, executor) = exec_loader::load(&data_source).await?;
+        let (db_name, executor) = exec_loader::load(&data_source, query_cache_size).await?;
 
         // Build internal data model
         let internal_data_model = template.build(db_name);
@@ -75,6 +94,7 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            query_cache_size: None,
             datamodel,
             config,
         }
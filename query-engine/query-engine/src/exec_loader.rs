@@ -5,7 +5,10 @@ use datamodel::{
     common::provider_names::{MYSQL_SOURCE_NAME, POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
     Datasource,
 };
-use query_core::executor::{InterpretingExecutor, QueryExecutor};
+use query_core::{
+    executor::{InterpretingExecutor, QueryExecutor},
+    QueryCache,
+};
 use std::{collections::HashMap, path::PathBuf};
 use url::Url;
 
@@ -15,19 +18,22 @@ use sql_connector::*;
 #[cfg(all(feature = "sql", feature = "mssql"))]
 use datamodel::common::provider_names::MSSQL_SOURCE_NAME;
 
-pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+pub async fn load(
+    source: &Datasource,
+    query_cache_size: Option<usize>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     match source.active_provider.as_str() {
         #[cfg(feature = "sql")]
-        SQLITE_SOURCE_NAME => sqlite(source).await,
+        SQLITE_SOURCE_NAME => sqlite(source, query_cache_size).await,
 
         #[cfg(feature = "sql")]
-        MYSQL_SOURCE_NAME => mysql(source).await,
+        MYSQL_SOURCE_NAME => mysql(source, query_cache_size).await,
 
         #[cfg(feature = "sql")]
-        POSTGRES_SOURCE_NAME => postgres(source).await,
+        POSTGRES_SOURCE_NAME => postgres(source, query_cache_size).await,
 
         #[cfg(all(feature = "sql", feature = "mssql"))]
-        MSSQL_SOURCE_NAME => mssql(source).await,
+        MSSQL_SOURCE_NAME => mssql(source, query_cache_size).await,
 
         x => Err(PrismaError::ConfigurationError(format!(
             "Unsupported connector type: {}",
@@ -37,19 +43,27 @@ pub async fn load(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
 }
 
 #[cfg(feature = "sql")]
-async fn sqlite(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn sqlite(
+    source: &Datasource,
+    query_cache_size: Option<usize>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQLite connector...");
 
     let sqlite = Sqlite::from_source(source).await?;
     let path = PathBuf::from(sqlite.file_path());
     let db_name = path.file_stem().unwrap().to_str().unwrap().to_owned(); // Safe due to previous validations.
 
+    let replicas = replicas(source).await?;
+
     trace!("Loaded SQLite connector.");
-    Ok((db_name, sql_executor(sqlite, false)))
+    Ok((db_name, sql_executor(sqlite, replicas, false, query_cache_size)))
 }
 
 #[cfg(feature = "sql")]
-async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn postgres(
+    source: &Datasource,
+    query_cache_size: Option<usize>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading Postgres connector...");
 
     let url = Url::parse(&source.url().value)?;
@@ -67,12 +81,20 @@ async fn postgres(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExe
         .and_then(|flag| flag.parse().ok())
         .unwrap_or(false);
 
+    let replicas = replicas(source).await?;
+
     trace!("Loaded Postgres connector.");
-    Ok((db_name, sql_executor(psql, force_transactions)))
+    Ok((
+        db_name,
+        sql_executor(psql, replicas, force_transactions, query_cache_size),
+    ))
 }
 
 #[cfg(feature = "sql")]
-async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mysql(
+    source: &Datasource,
+    query_cache_size: Option<usize>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading MySQL connector...");
 
     let mysql = Mysql::from_source(source).await?;
@@ -84,13 +106,17 @@ async fn mysql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
         .ok_or_else(|| PrismaError::ConfigurationError(err_str.into()))?;
 
     let db_name = db_name.next().expect(err_str).to_owned();
+    let replicas = replicas(source).await?;
 
     trace!("Loaded MySQL connector.");
-    Ok((db_name, sql_executor(mysql, false)))
+    Ok((db_name, sql_executor(mysql, replicas, false, query_cache_size)))
 }
 
 #[cfg(all(feature = "sql", feature = "mssql"))]
-async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
+async fn mssql(
+    source: &Datasource,
+    query_cache_size: Option<usize>,
+) -> PrismaResult<(String, Box<dyn QueryExecutor + Send + Sync + 'static>)> {
     trace!("Loading SQL Server connector...");
 
     let mssql = Mssql::from_source(source).await?;
@@ -109,15 +135,41 @@ async fn mssql(source: &Datasource) -> PrismaResult<(String, Box<dyn QueryExecut
         .collect();
 
     let db_name = params.remove("database").unwrap_or_else(|| String::from("master"));
+    let replicas = replicas(source).await?;
 
     trace!("Loaded SQL Server connector.");
-    Ok((db_name, sql_executor(mssql, false)))
+    Ok((db_name, sql_executor(mssql, replicas, false, query_cache_size)))
 }
 
+/// Connects to the read replicas of the datasource, with the connector of the primary.
 #[cfg(feature = "sql")]
-fn sql_executor<T>(connector: T, force_transactions: bool) -> Box<dyn QueryExecutor + Send + Sync + 'static>
+async fn replicas<T>(source: &Datasource) -> PrismaResult<Vec<T>>
+where
+    T: FromSource + Connector,
+{
+    let mut replicas = Vec::with_capacity(source.replica_urls.len());
+
+    for url in source.replica_urls.iter() {
+        replicas.push(T::from_url(&url.value).await?);
+    }
+
+    Ok(replicas)
+}
+
+#[cfg(feature = "sql")]
+fn sql_executor<T>(
+    connector: T,
+    replicas: Vec<T>,
+    force_transactions: bool,
+    query_cache_size: Option<usize>,
+) -> Box<dyn QueryExecutor + Send + Sync + 'static>
 where
     T: Connector + Send + Sync + 'static,
 {
-    Box::new(InterpretingExecutor::new(connector, force_transactions))
+    let executor = InterpretingExecutor::new(connector, replicas, force_transactions);
+
+    match query_cache_size {
+        Some(size) => Box::new(executor.with_query_cache(QueryCache::new(size))),
+        None => Box::new(executor),
+    }
 }
@@ -3,12 +3,16 @@ extern crate tracing;
 
 use cli::CliCommand;
 use error::PrismaError;
+use opentelemetry::sdk::trace::Tracer;
 use opt::PrismaOpt;
 use request_handlers::PrismaResponse;
 use std::{error::Error, process};
 use structopt::StructOpt;
-use tracing::subscriber;
-use tracing_subscriber::{EnvFilter, FmtSubscriber};
+use tracing::{subscriber, Subscriber};
+use tracing_subscriber::{
+    registry::{LookupSpan, Registry},
+    EnvFilter, FmtSubscriber, Layer,
+};
 
 use tide_server_timing::TimingLayer;
 use tracing_subscriber::layer::SubscriberExt;
@@ -21,6 +25,7 @@ mod exec_loader;
 mod opt;
 mod request_handlers;
 mod server;
+mod telemetry;
 
 #[cfg(test)]
 mod tests;
@@ -44,7 +49,17 @@ async fn main() -> Result<(), AnyError> {
 
     async fn main() -> Result<(), PrismaError> {
         let opts = PrismaOpt::from_args();
-        init_logger(opts.log_format());
+
+        // The exporter is uninstalled when the guard is dropped, on shutdown.
+        let (tracer, _exporter_guard) = match &opts.open_telemetry_endpoint {
+            Some(endpoint) => {
+                let (tracer, guard) = telemetry::install_exporter(endpoint)?;
+                (Some(tracer), Some(guard))
+            }
+            None => (None, None),
+        };
+
+        init_logger(opts.log_format(), tracer);
         feature_flags::initialize(opts.raw_feature_flags.as_slice())?;
         match CliCommand::from_opt(&opts)? {
             Some(cmd) => cmd.execute().await?,
@@ -57,7 +72,7 @@ async fn main() -> Result<(), AnyError> {
     }
 }
 
-fn init_logger(log_format: LogFormat) {
+fn init_logger(log_format: LogFormat, tracer: Option<Tracer>) {
     // Enable `tide` logs to be captured.
     let filter = EnvFilter::from_default_env().add_directive("tide=info".parse().unwrap());
 
@@ -68,19 +83,51 @@ fn init_logger(log_format: LogFormat) {
                 .finish()
                 .with(TimingLayer::new());
 
-            subscriber::set_global_default(subscriber).expect("Could not initialize logger");
-        }
-        LogFormat::Json => {
-            let subscriber = FmtSubscriber::builder()
-                .json()
-                .with_env_filter(filter)
-                .finish()
-                .with(TimingLayer::new());
-            subscriber::set_global_default(subscriber).expect("Could not initialize logger");
+            set_global_subscriber(subscriber, tracer);
         }
+        LogFormat::Json => match tracer {
+            Some(tracer) => {
+                let subscriber = json_subscriber(filter, tracing_opentelemetry::layer().with_tracer(tracer));
+                subscriber::set_global_default(subscriber).expect("Could not initialize logger");
+            }
+            None => {
+                let subscriber = FmtSubscriber::builder()
+                    .json()
+                    .with_env_filter(filter)
+                    .finish()
+                    .with(TimingLayer::new());
+
+                set_global_subscriber(subscriber, None);
+            }
+        },
     }
 }
 
+/// Logs the events passing the filter as JSON, and passes every span to the exporter.
+fn json_subscriber<L>(filter: EnvFilter, exporter: L) -> impl Subscriber + Send + Sync + 'static
+where
+    L: Layer<Registry> + Send + Sync + 'static,
+{
+    let logger = telemetry::EventFilter::new(filter, tracing_subscriber::fmt::layer().json());
+
+    Registry::default().with(exporter).with(logger).with(TimingLayer::new())
+}
+
+/// Sets the subscriber for the process, exporting spans with the OpenTelemetry tracer if given.
+fn set_global_subscriber<S>(subscriber: S, tracer: Option<Tracer>)
+where
+    S: Subscriber + for<'span> LookupSpan<'span> + Send + Sync + 'static,
+{
+    let result = match tracer {
+        Some(tracer) => {
+            subscriber::set_global_default(subscriber.with(tracing_opentelemetry::layer().with_tracer(tracer)))
+        }
+        None => subscriber::set_global_default(subscriber),
+    };
+
+    result.expect("Could not initialize logger");
+}
+
 fn set_panic_hook(log_format: LogFormat) {
     if let LogFormat::Json = log_format {
         std::panic::set_hook(Box::new(|info| {
@@ -91,6 +91,25 @@ pub struct PrismaOpt {
     #[structopt(long = "log-format", env = "RUST_LOG_FORMAT")]
     log_format: Option<String>,
 
+    /// Exports tracing spans over OTLP to the OpenTelemetry collector at the endpoint.
+    #[structopt(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    pub open_telemetry_endpoint: Option<String>,
+
+    /// Default timeout of requests in milliseconds, after which their queries are cancelled. Can be
+    /// overridden per request with the `x-prisma-query-timeout` header.
+    #[structopt(long, env = "PRISMA_QUERY_TIMEOUT")]
+    pub query_timeout: Option<u64>,
+
+    /// Caches the responses of up to this many read-only operations in the process, invalidated by
+    /// the writes of the process. Disabled if not set.
+    #[structopt(long, env = "PRISMA_QUERY_CACHE_SIZE")]
+    pub query_cache_size: Option<usize>,
+
+    /// Time in milliseconds the server waits for in-flight requests on SIGTERM or SIGINT, before rolling
+    /// back the transactions still running and exiting.
+    #[structopt(long, env = "PRISMA_SHUTDOWN_GRACE_PERIOD", default_value = "10000")]
+    pub shutdown_grace_period: u64,
+
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 
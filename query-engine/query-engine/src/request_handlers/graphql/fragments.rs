@@ -0,0 +1,154 @@
+use crate::{error::PrismaError, PrismaResult};
+use graphql_parser::query::{
+    Definition, Directive, Document, Field, FragmentDefinition, Selection as GqlSelection, SelectionSet, TypeCondition,
+    Value,
+};
+use indexmap::IndexMap;
+use std::collections::HashMap;
+
+/// Fields of selection sets by response key, i.e. by alias or name. Fields with the same response key
+/// are merged into one field of the response.
+pub type CollectedFields<'a> = IndexMap<String, Vec<&'a Field<String>>>;
+
+/// The fragment definitions of a document, to expand named fragments and inline fragments into the
+/// fields of selection sets.
+pub struct Fragments<'a> {
+    definitions: HashMap<&'a str, &'a FragmentDefinition<String>>,
+
+    /// The values of the Boolean variables of the operation, for the conditions of `@skip` and `@include`.
+    conditions: HashMap<String, bool>,
+}
+
+impl<'a> Fragments<'a> {
+    pub fn new(doc: &'a Document<String>, conditions: HashMap<String, bool>) -> Self {
+        let definitions = doc
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                Definition::Fragment(fragment) => Some((fragment.name.as_str(), fragment)),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            definitions,
+            conditions,
+        }
+    }
+
+    /// Collects the fields of the selection sets, including the fields of their fragments, by response key.
+    ///
+    /// Fragments only apply if their type condition is the given type. Without a type, all fragments
+    /// apply. Like in the GraphQL spec, a named fragment spread more than once in the same selection
+    /// only contributes its fields once, which also stops fragments from spreading themselves forever.
+    /// Fields and fragments excluded by `@skip` or `@include` are left out, other directives are ignored.
+    pub fn collect_fields(
+        &self,
+        selection_sets: &[&'a SelectionSet<String>],
+        type_name: Option<&str>,
+    ) -> PrismaResult<CollectedFields<'a>> {
+        let mut fields = IndexMap::new();
+        let mut visited_fragments = Vec::new();
+
+        for selection_set in selection_sets {
+            self.collect_selection_set(selection_set, type_name, &mut fields, &mut visited_fragments)?;
+        }
+
+        Ok(fields)
+    }
+
+    fn collect_selection_set(
+        &self,
+        selection_set: &'a SelectionSet<String>,
+        type_name: Option<&str>,
+        fields: &mut CollectedFields<'a>,
+        visited_fragments: &mut Vec<&'a str>,
+    ) -> PrismaResult<()> {
+        for item in selection_set.items.iter() {
+            let directives = match item {
+                GqlSelection::Field(field) => &field.directives,
+                GqlSelection::FragmentSpread(spread) => &spread.directives,
+                GqlSelection::InlineFragment(fragment) => &fragment.directives,
+            };
+
+            if !self.is_included(directives)? {
+                continue;
+            }
+
+            match item {
+                GqlSelection::Field(field) => {
+                    let key = field.alias.as_ref().unwrap_or(&field.name).clone();
+                    fields.entry(key).or_insert_with(Vec::new).push(field);
+                }
+
+                GqlSelection::FragmentSpread(spread) => {
+                    let fragment: &'a FragmentDefinition<String> = self
+                        .definitions
+                        .get(spread.fragment_name.as_str())
+                        .copied()
+                        .ok_or_else(|| {
+                            PrismaError::QueryConversionError(format!(
+                                "Unknown fragment '{}', at position {}.",
+                                spread.fragment_name, spread.position
+                            ))
+                        })?;
+
+                    if visited_fragments.contains(&fragment.name.as_str()) {
+                        continue;
+                    }
+
+                    visited_fragments.push(&fragment.name);
+
+                    if applies_to(Some(&fragment.type_condition), type_name) {
+                        self.collect_selection_set(&fragment.selection_set, type_name, fields, visited_fragments)?;
+                    }
+                }
+
+                GqlSelection::InlineFragment(fragment) => {
+                    if applies_to(fragment.type_condition.as_ref(), type_name) {
+                        self.collect_selection_set(&fragment.selection_set, type_name, fields, visited_fragments)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the `@skip` and `@include` directives of a selection include it.
+    fn is_included(&self, directives: &[Directive<String>]) -> PrismaResult<bool> {
+        for directive in directives {
+            let included_if = match directive.name.as_str() {
+                "skip" => false,
+                "include" => true,
+                _ => continue,
+            };
+
+            let condition = match directive.arguments.iter().find(|(name, _)| name == "if") {
+                Some((_, Value::Boolean(condition))) => Some(*condition),
+                Some((_, Value::Variable(name))) => self.conditions.get(name).copied(),
+                _ => None,
+            };
+
+            match condition {
+                Some(condition) if condition != included_if => return Ok(false),
+                Some(_) => (),
+                None => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Directive '@{}', at position {}, requires a Boolean argument 'if'.",
+                        directive.name, directive.position
+                    )))
+                }
+            }
+        }
+
+        Ok(true)
+    }
+}
+
+fn applies_to(type_condition: Option<&TypeCondition<String>>, type_name: Option<&str>) -> bool {
+    match (type_condition, type_name) {
+        (Some(TypeCondition::On(condition)), Some(type_name)) => condition == type_name,
+        _ => true,
+    }
+}
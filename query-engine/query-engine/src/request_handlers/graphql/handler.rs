@@ -1,9 +1,12 @@
-use super::{protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
-use crate::{context::PrismaContext, PrismaResponse, PrismaResult};
+use super::{introspection::GraphQLIntrospection, protocol_adapter::GraphQLProtocolAdapter, GQLResponse};
+use crate::{context::PrismaContext, PrismaError, PrismaResponse, PrismaResult};
 use futures::FutureExt;
 use graphql_parser as gql;
 use indexmap::IndexMap;
-use query_core::{BatchDocument, CompactedDocument, Item, Operation, QueryDocument, QueryValue, ResponseData};
+use query_core::{
+    BatchDocument, CompactedDocument, ExecutionOptions, Item, Operation, QueryDocument, QueryPlan, QueryValue,
+    ResponseData,
+};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, panic::AssertUnwindSafe, sync::Arc};
 
@@ -19,7 +22,8 @@ pub enum GraphQlBody {
 pub struct SingleQuery {
     query: String,
     operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    #[serde(default)]
+    variables: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -45,15 +49,35 @@ impl From<&str> for SingleQuery {
     }
 }
 
+/// A parsed request: either a `QueryDocument` to execute, or an introspection query that is answered
+/// from the query schema, with its operation name and variables.
+pub(crate) enum RequestDocument {
+    Query(QueryDocument),
+    Introspection(
+        gql::query::Document<String>,
+        Option<String>,
+        HashMap<String, serde_json::Value>,
+    ),
+}
+
 impl GraphQlBody {
-    /// Convert a `GraphQlBody` into a `QueryDocument`.
-    pub(crate) fn into_doc(self) -> PrismaResult<QueryDocument> {
+    /// Convert a `GraphQlBody` into a `RequestDocument`.
+    pub(crate) fn into_doc(self) -> PrismaResult<RequestDocument> {
         match self {
             GraphQlBody::Single(body) => {
                 let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
 
-                Ok(QueryDocument::Single(operation))
+                if GraphQLIntrospection::is_introspection(&gql_doc, body.operation_name.as_deref()) {
+                    return Ok(RequestDocument::Introspection(
+                        gql_doc,
+                        body.operation_name,
+                        body.variables,
+                    ));
+                }
+
+                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)?;
+
+                Ok(RequestDocument::Query(QueryDocument::Single(operation)))
             }
             GraphQlBody::Multi(bodies) => {
                 let operations: PrismaResult<Vec<Operation>> = bodies
@@ -61,37 +85,73 @@ impl GraphQlBody {
                     .into_iter()
                     .map(|body| {
                         let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
+                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name, body.variables)
                     })
                     .collect();
 
-                Ok(QueryDocument::Multi(BatchDocument::new(
+                Ok(RequestDocument::Query(QueryDocument::Multi(BatchDocument::new(
                     operations?,
                     bodies.transaction,
-                )))
+                ))))
             }
         }
     }
 }
 
 /// Handle a Graphql request.
-pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>) -> PrismaResponse {
+pub(crate) async fn handle(body: GraphQlBody, cx: Arc<PrismaContext>, options: ExecutionOptions) -> PrismaResponse {
     debug!("Incoming GraphQL query: {:?}", body);
 
-    match body.into_doc() {
-        Ok(QueryDocument::Single(query)) => handle_single_query(query, cx.clone()).await,
-        Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx).await,
-            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx).await,
+    let doc = info_span!("parse_request").in_scope(|| body.into_doc());
+
+    match doc {
+        Ok(RequestDocument::Query(QueryDocument::Single(query))) => {
+            handle_single_query(query, cx.clone(), options).await
+        }
+        Ok(RequestDocument::Query(QueryDocument::Multi(batch))) => match batch.compact() {
+            BatchDocument::Multi(batch, transactional) => handle_batch(batch, transactional, &cx, options).await,
+            BatchDocument::Compact(compacted) => handle_compacted(compacted, &cx, options).await,
         },
+        Ok(RequestDocument::Introspection(doc, operation, variables)) => {
+            match GraphQLIntrospection::execute(&doc, operation.as_deref(), variables, cx.query_schema()) {
+                Ok(response) => PrismaResponse::Single(response),
+                Err(err) => PrismaResponse::Single(err.into()),
+            }
+        }
         Err(err) => PrismaResponse::Single(err.into()),
     }
 }
 
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+/// Explain how the single query of a GraphQL request runs, with the statements explained by the database if
+/// `explain_statements` is set.
+pub(crate) async fn explain(
+    body: GraphQlBody,
+    cx: Arc<PrismaContext>,
+    options: ExecutionOptions,
+    explain_statements: bool,
+) -> PrismaResult<QueryPlan> {
+    debug!("Incoming GraphQL query to explain: {:?}", body);
+
+    let operation = match body.into_doc()? {
+        RequestDocument::Query(QueryDocument::Single(operation)) => operation,
+        _ => {
+            return Err(PrismaError::UnsupportedFeatureError(
+                "Explaining batches and introspection queries",
+                "Only single queries can be explained.".to_owned(),
+            ))
+        }
+    };
+
+    Ok(cx
+        .executor
+        .explain(operation, Arc::clone(cx.query_schema()), options, explain_statements)
+        .await?)
+}
+
+async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>, options: ExecutionOptions) -> PrismaResponse {
     use user_facing_errors::Error;
 
-    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
+    let gql_response = match AssertUnwindSafe(handle_graphql_query(query, &*ctx, options))
         .catch_unwind()
         .await
     {
@@ -107,12 +167,17 @@ async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> Prism
     PrismaResponse::Single(gql_response)
 }
 
-async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_batch(
+    queries: Vec<Operation>,
+    transactional: bool,
+    ctx: &Arc<PrismaContext>,
+    options: ExecutionOptions,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     match AssertUnwindSafe(
         ctx.executor
-            .execute_batch(queries, transactional, ctx.query_schema().clone()),
+            .execute_batch(queries, transactional, ctx.query_schema().clone(), options),
     )
     .catch_unwind()
     .await
@@ -139,16 +204,20 @@ async fn handle_batch(queries: Vec<Operation>, transactional: bool, ctx: &Arc<Pr
     }
 }
 
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+async fn handle_compacted(
+    document: CompactedDocument,
+    ctx: &Arc<PrismaContext>,
+    options: ExecutionOptions,
+) -> PrismaResponse {
     use user_facing_errors::Error;
 
     let plural_name = document.plural_name();
-    let singular_name = document.single_name();
+    let singular_key = document.single_key();
     let keys = document.keys;
     let arguments = document.arguments;
     let nested_selection = document.nested_selection;
 
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx, options))
         .catch_unwind()
         .await
     {
@@ -182,10 +251,10 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
                                 .filter(|(k, _)| nested_selection.contains(k))
                                 .collect();
 
-                            responses.insert_data(&singular_name, Item::Map(result));
+                            responses.insert_data(&singular_key, Item::Map(result));
                         }
                         _ => {
-                            responses.insert_data(&singular_name, Item::null());
+                            responses.insert_data(&singular_key, Item::null());
                         }
                     }
 
@@ -206,6 +275,13 @@ async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>)
     }
 }
 
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<ResponseData> {
-    Ok(ctx.executor.execute(query_doc, Arc::clone(ctx.query_schema())).await?)
+async fn handle_graphql_query(
+    query_doc: Operation,
+    ctx: &PrismaContext,
+    options: ExecutionOptions,
+) -> PrismaResult<ResponseData> {
+    Ok(ctx
+        .executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()), options)
+        .await?)
 }
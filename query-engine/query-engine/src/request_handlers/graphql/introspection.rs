@@ -0,0 +1,595 @@
+use super::{fragments::Fragments, GQLResponse};
+use crate::{error::PrismaError, PrismaResult};
+use graphql_parser::query::{
+    Definition, Document, Field as GqlField, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    VariableDefinition,
+};
+use indexmap::IndexMap;
+use prisma_models::PrismaValue;
+use query_core::{response_ir::Item, schema::*};
+use std::collections::HashMap;
+
+/// Answers GraphQL introspection queries from the query schema, so that GraphQL tooling can work
+/// against the query engine.
+///
+/// An operation is an introspection query if all of its root fields are introspection fields, i.e.
+/// `__schema`, `__type` or `__typename`. Introspection fields can't be mixed with other fields.
+///
+/// The introspected types are the types of the SDL served on `/sdl`, with the same nullability.
+/// The query schema has no descriptions, deprecations or interfaces. The introspected directives are
+/// `@skip` and `@include`, which requests may use, and `@deprecated`, which GraphQL tooling expects.
+/// Variables may be used for the `name` of `__type` and for the conditions of `@skip` and `@include`.
+pub struct GraphQLIntrospection<'a> {
+    query_schema: &'a QuerySchema,
+
+    /// All types reachable from the query and mutation types, by name.
+    types: IndexMap<String, TypeDefinition>,
+
+    fragments: Fragments<'a>,
+
+    /// The values of the variables of the operation, or their default values.
+    variables: HashMap<String, serde_json::Value>,
+}
+
+/// A named type of the query schema.
+enum TypeDefinition {
+    Scalar,
+    Object(ObjectTypeStrongRef),
+    InputObject(InputObjectTypeStrongRef),
+    Enum(EnumTypeRef),
+}
+
+/// A reference to a type, e.g. the type of a field.
+#[derive(Debug, Clone, PartialEq)]
+enum TypeRef {
+    NonNull(Box<TypeRef>),
+    List(Box<TypeRef>),
+    Named(String),
+}
+
+/// An object of the introspection schema.
+enum IntrospectionObject<'a> {
+    Schema,
+    Type(TypeRef),
+    Field(&'a FieldRef),
+    InputValue(&'a str, TypeRef, Option<&'static str>),
+    EnumValue(String),
+    Directive(&'static DirectiveDefinition),
+}
+
+/// A directive of the introspection schema. All of them have a single argument.
+struct DirectiveDefinition {
+    name: &'static str,
+    locations: &'static [&'static str],
+    argument: &'static str,
+    argument_type: &'static str,
+    required: bool,
+
+    /// The default value of the argument, as a GraphQL literal.
+    default_value: Option<&'static str>,
+}
+
+/// The value of a field of an introspection object, before the sub-selections are applied.
+enum Resolved<'a> {
+    Leaf(Item),
+    Object(IntrospectionObject<'a>),
+    List(Vec<IntrospectionObject<'a>>),
+    Null,
+}
+
+/// The variable definitions and the selection set of an operation.
+type Operation<'d> = (&'d [VariableDefinition<String>], &'d SelectionSet<String>);
+
+/// Scalar types that always exist, in addition to the ones referenced by the query schema.
+const BUILT_IN_SCALARS: &[&str] = &["String", "Int", "Float", "Boolean", "DateTime", "Json", "UUID"];
+
+const DIRECTIVES: &[DirectiveDefinition] = &[
+    DirectiveDefinition {
+        name: "skip",
+        locations: &["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+        argument: "if",
+        argument_type: "Boolean",
+        required: true,
+        default_value: None,
+    },
+    DirectiveDefinition {
+        name: "include",
+        locations: &["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"],
+        argument: "if",
+        argument_type: "Boolean",
+        required: true,
+        default_value: None,
+    },
+    DirectiveDefinition {
+        name: "deprecated",
+        locations: &["FIELD_DEFINITION", "ENUM_VALUE"],
+        argument: "reason",
+        argument_type: "String",
+        required: false,
+        default_value: Some("\"No longer supported\""),
+    },
+];
+
+impl<'a> GraphQLIntrospection<'a> {
+    /// True if the operation of the document that would be executed is an introspection query.
+    pub fn is_introspection(doc: &Document<String>, operation: Option<&str>) -> bool {
+        match Self::find_operation(doc, operation) {
+            Some((_, selection_set)) => {
+                !selection_set.items.is_empty()
+                    && selection_set.items.iter().all(|item| match item {
+                        GqlSelection::Field(field) => field.name.starts_with("__"),
+                        _ => false,
+                    })
+            }
+            None => false,
+        }
+    }
+
+    /// Executes the introspection query of the document, with the values of its variables.
+    pub fn execute(
+        doc: &'a Document<String>,
+        operation: Option<&str>,
+        mut variables: HashMap<String, serde_json::Value>,
+        query_schema: &'a QuerySchema,
+    ) -> PrismaResult<GQLResponse> {
+        let (variable_definitions, selection_set) = Self::find_operation(doc, operation)
+            .ok_or_else(|| PrismaError::QueryConversionError("Document contained no operations.".into()))?;
+
+        for definition in variable_definitions {
+            if let Some(default_value) = definition.default_value.as_ref().and_then(literal) {
+                variables.entry(definition.name.clone()).or_insert(default_value);
+            }
+        }
+
+        let conditions = variables
+            .iter()
+            .filter_map(|(name, value)| value.as_bool().map(|condition| (name.clone(), condition)))
+            .collect();
+
+        let mut introspection = Self {
+            query_schema,
+            types: IndexMap::new(),
+            fragments: Fragments::new(doc, conditions),
+            variables,
+        };
+
+        introspection.collect_types();
+
+        let query_type = query_schema.query();
+        let fields = introspection
+            .fragments
+            .collect_fields(&[selection_set], Some(query_type.name()))?;
+        let mut response = GQLResponse::with_capacity(fields.len());
+
+        for (key, fields) in fields {
+            let field = fields[0];
+
+            let item = match field.name.as_str() {
+                "__typename" => string(query_type.name()),
+                "__schema" => introspection.complete(Resolved::Object(IntrospectionObject::Schema), &fields)?,
+                "__type" => {
+                    let name = match argument(field, "name") {
+                        Some(Value::String(name)) => Some(name.as_str()),
+                        Some(Value::Variable(variable)) => {
+                            introspection.variables.get(variable).and_then(|value| value.as_str())
+                        }
+                        _ => None,
+                    };
+
+                    let name = match name {
+                        Some(name) => name,
+                        None => {
+                            return Err(PrismaError::QueryConversionError(
+                                "Field '__type' requires a string argument 'name'.".into(),
+                            ))
+                        }
+                    };
+
+                    let resolved = if introspection.types.contains_key(name) {
+                        Resolved::Object(IntrospectionObject::Type(TypeRef::Named(name.to_owned())))
+                    } else {
+                        Resolved::Null
+                    };
+
+                    introspection.complete(resolved, &fields)?
+                }
+                name => return Err(unknown_field(query_type.name(), name)),
+            };
+
+            response.insert_data(key, item);
+        }
+
+        Ok(response)
+    }
+
+    /// The variable definitions and the selection set of the operation to execute: the one with the given
+    /// name, or the last one of the document, like for other operations.
+    fn find_operation<'d>(doc: &'d Document<String>, operation: Option<&str>) -> Option<Operation<'d>> {
+        let operation_of = |def: &'d Definition<String>| match def {
+            Definition::Operation(OperationDefinition::SelectionSet(s)) => Some((None, (&[][..], s))),
+            Definition::Operation(OperationDefinition::Query(q)) => {
+                Some((q.name.as_ref(), (q.variable_definitions.as_slice(), &q.selection_set)))
+            }
+            Definition::Operation(OperationDefinition::Mutation(m)) => {
+                Some((m.name.as_ref(), (m.variable_definitions.as_slice(), &m.selection_set)))
+            }
+            _ => None,
+        };
+
+        let mut operations = doc.definitions.iter().filter_map(operation_of);
+
+        match operation {
+            Some(operation) => operations
+                .find(|(name, _)| name.map(|name| name == operation).unwrap_or(false))
+                .map(|(_, o)| o),
+            None => operations.last().map(|(_, o)| o),
+        }
+    }
+
+    /// Collects the named types reachable from the query and mutation types.
+    fn collect_types(&mut self) {
+        for scalar in BUILT_IN_SCALARS {
+            self.types.insert(scalar.to_string(), TypeDefinition::Scalar);
+        }
+
+        let query_type = self.query_schema.query();
+        let mutation_type = self.query_schema.mutation();
+
+        self.collect_object_type(query_type);
+        self.collect_object_type(mutation_type);
+    }
+
+    fn collect_object_type(&mut self, object_type: ObjectTypeStrongRef) {
+        if self.types.contains_key(object_type.name()) {
+            return;
+        }
+
+        self.types.insert(
+            object_type.name().to_owned(),
+            TypeDefinition::Object(object_type.clone()),
+        );
+
+        for field in object_type.get_fields() {
+            for argument in field.arguments.iter() {
+                self.collect_input_type(&argument.argument_type);
+            }
+
+            self.collect_output_type(&field.field_type);
+        }
+    }
+
+    fn collect_output_type(&mut self, output_type: &OutputType) {
+        match output_type {
+            OutputType::Object(obj) => self.collect_object_type(obj.into_arc()),
+            OutputType::Enum(et) | OutputType::Scalar(ScalarType::Enum(et)) => self.collect_enum_type(et),
+            OutputType::List(inner) | OutputType::Opt(inner) => self.collect_output_type(inner),
+            OutputType::Scalar(_) => (),
+        }
+    }
+
+    fn collect_input_type(&mut self, input_type: &InputType) {
+        match input_type {
+            InputType::Object(obj) => {
+                let obj = obj.into_arc();
+
+                if self.types.contains_key(&obj.name) {
+                    return;
+                }
+
+                self.types
+                    .insert(obj.name.clone(), TypeDefinition::InputObject(obj.clone()));
+
+                for field in obj.get_fields() {
+                    self.collect_input_type(&field.field_type);
+                }
+            }
+            InputType::Enum(et) | InputType::Scalar(ScalarType::Enum(et)) => self.collect_enum_type(et),
+            InputType::List(inner) | InputType::Opt(inner) | InputType::Null(inner) => self.collect_input_type(inner),
+            InputType::Scalar(_) => (),
+        }
+    }
+
+    fn collect_enum_type(&mut self, enum_type: &EnumTypeRef) {
+        self.types
+            .entry(enum_type.name().to_owned())
+            .or_insert_with(|| TypeDefinition::Enum(enum_type.clone()));
+    }
+
+    /// Applies the sub-selections of the fields to the resolved value.
+    fn complete<'s>(&'s self, resolved: Resolved<'s>, fields: &[&'a GqlField<String>]) -> PrismaResult<Item> {
+        match resolved {
+            Resolved::Leaf(item) => Ok(item),
+            Resolved::Null => Ok(Item::null()),
+            Resolved::Object(object) => self.complete_object(object, fields),
+            Resolved::List(objects) => {
+                let items = objects
+                    .into_iter()
+                    .map(|object| self.complete_object(object, fields))
+                    .collect::<PrismaResult<Vec<_>>>()?;
+
+                Ok(Item::list(items))
+            }
+        }
+    }
+
+    fn complete_object<'s>(
+        &'s self,
+        object: IntrospectionObject<'s>,
+        fields: &[&'a GqlField<String>],
+    ) -> PrismaResult<Item> {
+        let type_name = object.type_name();
+        let selection_sets: Vec<&'a SelectionSet<String>> = fields.iter().map(|field| &field.selection_set).collect();
+
+        if selection_sets
+            .iter()
+            .all(|selection_set| selection_set.items.is_empty())
+        {
+            return Err(PrismaError::QueryConversionError(format!(
+                "Field '{}' of type '{}' must have a selection of subfields.",
+                fields[0].name, type_name
+            )));
+        }
+
+        let mut map = IndexMap::new();
+
+        for (key, fields) in self.fragments.collect_fields(&selection_sets, Some(type_name))? {
+            let resolved = match fields[0].name.as_str() {
+                "__typename" => Resolved::Leaf(string(type_name)),
+                name => self.resolve_field(&object, name)?,
+            };
+
+            map.insert(key, self.complete(resolved, &fields)?);
+        }
+
+        Ok(Item::Map(map))
+    }
+
+    fn resolve_field<'s>(&'s self, object: &IntrospectionObject<'s>, name: &str) -> PrismaResult<Resolved<'s>> {
+        let resolved = match (object, name) {
+            (_, "description") => Resolved::Null,
+
+            (IntrospectionObject::Schema, "types") => Resolved::List(
+                self.types
+                    .keys()
+                    .map(|name| IntrospectionObject::Type(TypeRef::Named(name.clone())))
+                    .collect(),
+            ),
+            (IntrospectionObject::Schema, "queryType") => Resolved::Object(IntrospectionObject::Type(TypeRef::Named(
+                self.query_schema.query().name().to_owned(),
+            ))),
+            (IntrospectionObject::Schema, "mutationType") => Resolved::Object(IntrospectionObject::Type(
+                TypeRef::Named(self.query_schema.mutation().name().to_owned()),
+            )),
+            (IntrospectionObject::Schema, "subscriptionType") => Resolved::Null,
+            (IntrospectionObject::Schema, "directives") => {
+                Resolved::List(DIRECTIVES.iter().map(IntrospectionObject::Directive).collect())
+            }
+
+            (IntrospectionObject::Type(type_ref), name) => self.resolve_type_field(type_ref, name)?,
+
+            (IntrospectionObject::Field(field), "name") => Resolved::Leaf(string(&field.name)),
+            (IntrospectionObject::Field(field), "args") => Resolved::List(
+                field
+                    .arguments
+                    .iter()
+                    .map(|argument| {
+                        IntrospectionObject::InputValue(&argument.name, input_type_ref(&argument.argument_type), None)
+                    })
+                    .collect(),
+            ),
+            (IntrospectionObject::Field(field), "type") => {
+                Resolved::Object(IntrospectionObject::Type(output_type_ref(&field.field_type)))
+            }
+
+            (IntrospectionObject::InputValue(name, _, _), "name") => Resolved::Leaf(string(name)),
+            (IntrospectionObject::InputValue(_, type_ref, _), "type") => {
+                Resolved::Object(IntrospectionObject::Type(type_ref.clone()))
+            }
+            // The query schema doesn't define default values for arguments or input fields, only directives do.
+            (IntrospectionObject::InputValue(_, _, default_value), "defaultValue") => match default_value {
+                Some(default_value) => Resolved::Leaf(string(default_value)),
+                None => Resolved::Null,
+            },
+
+            (IntrospectionObject::EnumValue(name), "name") => Resolved::Leaf(string(name)),
+
+            (IntrospectionObject::Directive(directive), "name") => Resolved::Leaf(string(directive.name)),
+            (IntrospectionObject::Directive(directive), "locations") => Resolved::Leaf(Item::list(
+                directive.locations.iter().map(|location| string(location)).collect(),
+            )),
+            (IntrospectionObject::Directive(directive), "args") => {
+                let argument_type = TypeRef::Named(directive.argument_type.to_owned());
+                let argument_type = if directive.required {
+                    argument_type.non_null()
+                } else {
+                    argument_type
+                };
+
+                Resolved::List(vec![IntrospectionObject::InputValue(
+                    directive.argument,
+                    argument_type,
+                    directive.default_value,
+                )])
+            }
+            (IntrospectionObject::Directive(_), "isRepeatable") => {
+                Resolved::Leaf(Item::Value(PrismaValue::Boolean(false)))
+            }
+
+            (IntrospectionObject::Field(_), "isDeprecated") | (IntrospectionObject::EnumValue(_), "isDeprecated") => {
+                Resolved::Leaf(Item::Value(PrismaValue::Boolean(false)))
+            }
+            (IntrospectionObject::Field(_), "deprecationReason")
+            | (IntrospectionObject::EnumValue(_), "deprecationReason") => Resolved::Null,
+
+            (object, name) => return Err(unknown_field(object.type_name(), name)),
+        };
+
+        Ok(resolved)
+    }
+
+    fn resolve_type_field<'s>(&'s self, type_ref: &TypeRef, name: &str) -> PrismaResult<Resolved<'s>> {
+        let definition = match type_ref {
+            TypeRef::Named(type_name) => self.types.get(type_name),
+            _ => None,
+        };
+
+        let resolved = match (name, type_ref, definition) {
+            ("kind", TypeRef::NonNull(_), _) => Resolved::Leaf(string("NON_NULL")),
+            ("kind", TypeRef::List(_), _) => Resolved::Leaf(string("LIST")),
+            ("kind", TypeRef::Named(_), Some(definition)) => Resolved::Leaf(string(definition.kind())),
+
+            ("name", TypeRef::Named(type_name), _) => Resolved::Leaf(string(type_name)),
+            ("name", _, _) => Resolved::Null,
+
+            ("ofType", TypeRef::NonNull(inner), _) | ("ofType", TypeRef::List(inner), _) => {
+                Resolved::Object(IntrospectionObject::Type((**inner).clone()))
+            }
+            ("ofType", _, _) => Resolved::Null,
+
+            ("fields", _, Some(TypeDefinition::Object(object_type))) => Resolved::List(
+                object_type
+                    .get_fields()
+                    .iter()
+                    .map(IntrospectionObject::Field)
+                    .collect(),
+            ),
+            ("fields", _, _) => Resolved::Null,
+
+            ("inputFields", _, Some(TypeDefinition::InputObject(input_type))) => Resolved::List(
+                input_type
+                    .get_fields()
+                    .iter()
+                    .map(|field| IntrospectionObject::InputValue(&field.name, input_type_ref(&field.field_type), None))
+                    .collect(),
+            ),
+            ("inputFields", _, _) => Resolved::Null,
+
+            ("enumValues", _, Some(TypeDefinition::Enum(enum_type))) => Resolved::List(
+                enum_values(enum_type)
+                    .into_iter()
+                    .map(IntrospectionObject::EnumValue)
+                    .collect(),
+            ),
+            ("enumValues", _, _) => Resolved::Null,
+
+            ("interfaces", _, Some(TypeDefinition::Object(_))) => Resolved::List(vec![]),
+            ("interfaces", _, _) | ("possibleTypes", _, _) | ("specifiedByUrl", _, _) => Resolved::Null,
+
+            (name, _, _) => return Err(unknown_field("__Type", name)),
+        };
+
+        Ok(resolved)
+    }
+}
+
+impl<'a> IntrospectionObject<'a> {
+    fn type_name(&self) -> &'static str {
+        match self {
+            Self::Schema => "__Schema",
+            Self::Type(_) => "__Type",
+            Self::Field(_) => "__Field",
+            Self::InputValue(_, _, _) => "__InputValue",
+            Self::EnumValue(_) => "__EnumValue",
+            Self::Directive(_) => "__Directive",
+        }
+    }
+}
+
+impl TypeDefinition {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Scalar => "SCALAR",
+            Self::Object(_) => "OBJECT",
+            Self::InputObject(_) => "INPUT_OBJECT",
+            Self::Enum(_) => "ENUM",
+        }
+    }
+}
+
+impl TypeRef {
+    fn non_null(self) -> Self {
+        Self::NonNull(Box::new(self))
+    }
+
+    fn nullable(self) -> Self {
+        match self {
+            Self::NonNull(inner) => *inner,
+            other => other,
+        }
+    }
+}
+
+/// Like in the SDL, types are required unless they're optional.
+fn output_type_ref(output_type: &OutputType) -> TypeRef {
+    match output_type {
+        OutputType::Object(obj) => TypeRef::Named(obj.into_arc().name().to_owned()).non_null(),
+        OutputType::Enum(et) | OutputType::Scalar(ScalarType::Enum(et)) => {
+            TypeRef::Named(et.name().to_owned()).non_null()
+        }
+        OutputType::List(inner) => TypeRef::List(Box::new(output_type_ref(inner))).non_null(),
+        OutputType::Opt(inner) => output_type_ref(inner).nullable(),
+        OutputType::Scalar(scalar) => TypeRef::Named(scalar_name(scalar).to_owned()).non_null(),
+    }
+}
+
+/// Like in the SDL, nullability has no representation, only optional inputs are nullable.
+fn input_type_ref(input_type: &InputType) -> TypeRef {
+    match input_type {
+        InputType::Object(obj) => TypeRef::Named(obj.into_arc().name.clone()).non_null(),
+        InputType::Enum(et) | InputType::Scalar(ScalarType::Enum(et)) => {
+            TypeRef::Named(et.name().to_owned()).non_null()
+        }
+        InputType::List(inner) => TypeRef::List(Box::new(input_type_ref(inner))).non_null(),
+        InputType::Opt(inner) => input_type_ref(inner).nullable(),
+        InputType::Null(inner) => input_type_ref(inner),
+        InputType::Scalar(scalar) => TypeRef::Named(scalar_name(scalar).to_owned()).non_null(),
+    }
+}
+
+fn scalar_name(scalar: &ScalarType) -> &'static str {
+    match scalar {
+        ScalarType::String => "String",
+        ScalarType::Int => "Int",
+        ScalarType::Boolean => "Boolean",
+        ScalarType::Float => "Float",
+        ScalarType::DateTime => "DateTime",
+        ScalarType::Json => "Json",
+        ScalarType::UUID => "UUID",
+        ScalarType::JsonList => "Json",
+        ScalarType::Enum(_) => unreachable!(), // Handled separately by the callers.
+    }
+}
+
+fn enum_values(enum_type: &EnumType) -> Vec<String> {
+    match enum_type {
+        EnumType::String(s) => s.values().to_vec(),
+        EnumType::Internal(i) => i.values.iter().map(|value| value.name.clone()).collect(),
+        EnumType::FieldRef(f) => f.values(),
+    }
+}
+
+fn argument<'f>(field: &'f GqlField<String>, name: &str) -> Option<&'f Value<String>> {
+    field
+        .arguments
+        .iter()
+        .find(|(argument, _)| argument == name)
+        .map(|(_, value)| value)
+}
+
+/// The JSON value of a literal, for the default values of variables. Only strings and Booleans are used
+/// by introspection queries, for the `name` of `__type` and the conditions of directives.
+fn literal(value: &Value<String>) -> Option<serde_json::Value> {
+    match value {
+        Value::String(value) => Some(serde_json::Value::String(value.clone())),
+        Value::Boolean(value) => Some(serde_json::Value::Bool(*value)),
+        _ => None,
+    }
+}
+
+fn string(value: &str) -> Item {
+    Item::Value(PrismaValue::String(value.to_owned()))
+}
+
+fn unknown_field(type_name: &str, field_name: &str) -> PrismaError {
+    PrismaError::QueryConversionError(format!("Cannot query field '{}' on type '{}'.", field_name, type_name))
+}
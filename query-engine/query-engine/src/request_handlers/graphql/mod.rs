@@ -1,9 +1,12 @@
+mod fragments;
 mod handler;
+mod introspection;
 mod protocol_adapter;
 mod response;
 mod schema_renderer;
 
 pub use handler::*;
+pub use introspection::*;
 pub use protocol_adapter::*;
 pub use response::*;
 pub use schema_renderer::*;
@@ -1,11 +1,15 @@
+use super::fragments::Fragments;
 use crate::{error::PrismaError, PrismaResult};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, Field as GqlField, OperationDefinition, SelectionSet, Type, Value, VariableDefinition,
 };
 use indexmap::IndexMap;
 use query_core::query_document::*;
 use rust_decimal::Decimal;
-use std::str::FromStr;
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -13,35 +17,58 @@ use std::str::FromStr;
 /// - Every field of a `query { ... }` or single selection block `{ ... }` is mapped to an `Operation::Read`.
 /// - Every field of a single `mutation { ... }` is mapped to an `Operation::Write`.
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
-/// - Fields on the queries are mapped to `Field`s, including arguments.
+/// - Fields on the queries are mapped to `Field`s, including arguments and aliases.
+/// - Named and inline fragments are expanded into the fields they're spread in. Type conditions are
+///   not checked, as all types of the query schema are object types.
+/// - Fields with the same alias or name are merged, like fields selected by several fragments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables are checked against their declared types and substituted with the JSON values of the
+///   payload, or their default values. Arguments set to variables without a value are left out.
+/// - Fields and fragments are left out according to their `@skip` and `@include` directives.
 ///
 /// Currently unsupported features:
-/// - Fragments in any form.
-/// - Variables.
 /// - Subscription queries.
-/// - Query names are ignored
+/// - Other directives, which are ignored.
 pub struct GraphQLProtocolAdapter;
 
+/// The state of the conversion of an operation.
+struct Conversion<'a> {
+    fragments: Fragments<'a>,
+
+    /// The values of the variables of the operation, except for the ones without a value.
+    variables: HashMap<String, QueryValue>,
+
+    /// The names of all variables defined by the operation.
+    defined_variables: HashSet<String>,
+}
+
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document<String>, operation: Option<String>) -> PrismaResult<Operation> {
-        let mut operations: Vec<Operation> = match operation {
+    pub fn convert(
+        gql_doc: Document<String>,
+        operation: Option<String>,
+        variables: HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Operation> {
+        let definition = match operation {
             Some(ref op) => gql_doc
                 .definitions
-                .into_iter()
+                .iter()
                 .find(|def| Self::matches_operation(def, op))
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
-                })
-                .and_then(Self::convert_definition),
+                })?,
 
             None => gql_doc
                 .definitions
-                .into_iter()
-                .map(Self::convert_definition)
-                .collect::<PrismaResult<Vec<Vec<Operation>>>>()
-                .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
-        }?;
+                .iter()
+                .filter(|def| match def {
+                    Definition::Operation(_) => true,
+                    Definition::Fragment(_) => false,
+                })
+                .last()
+                .ok_or_else(|| PrismaError::QueryConversionError("Document contained no operations.".into()))?,
+        };
+
+        let mut operations = Self::convert_definition(definition, &gql_doc, variables)?;
 
         let operation = operations
             .pop()
@@ -51,72 +78,39 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition<String>) -> PrismaResult<Vec<Operation>> {
-        match def {
-            Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
-                "Fragment definition",
-                format!("Fragment '{}', at position {}.", f.name, f.position),
-            )),
+    fn convert_definition<'a>(
+        def: &'a Definition<String>,
+        gql_doc: &'a Document<String>,
+        variables: HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Vec<Operation>> {
+        let (variable_definitions, selection_set, is_mutation) = match def {
+            Definition::Fragment(f) => {
+                return Err(PrismaError::QueryConversionError(format!(
+                    "Fragment '{}', at position {}, is not an operation.",
+                    f.name, f.position
+                )))
+            }
             Definition::Operation(op) => match op {
-                OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
-                    "Subscription query",
-                    format!("At position {}.", s.position),
-                )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
-            },
-        }
-    }
-
-    fn convert_query(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
-            .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
-    }
-
-    fn convert_mutation(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
-            fields
-                .into_iter()
-                .map(|selection| Operation::Write(selection))
-                .collect()
-        })
-    }
-
-    fn convert_selection_set(selection_set: SelectionSet<String>) -> PrismaResult<Vec<Selection>> {
-        selection_set
-            .items
-            .into_iter()
-            .map(|item| match item {
-                GqlSelection::Field(f) => {
-                    let arguments: Vec<(String, QueryValue)> = f
-                        .arguments
-                        .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
-                        .collect::<PrismaResult<Vec<_>>>()?;
-
-                    let mut builder = Selection::builder(f.name);
-                    builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
-
-                    if let Some(alias) = f.alias {
-                        builder.alias(alias);
-                    };
-
-                    Ok(builder.build())
+                OperationDefinition::Subscription(s) => {
+                    return Err(PrismaError::UnsupportedFeatureError(
+                        "Subscription query",
+                        format!("At position {}.", s.position),
+                    ))
                 }
+                OperationDefinition::SelectionSet(s) => (&[][..], s, false),
+                OperationDefinition::Query(q) => (q.variable_definitions.as_slice(), &q.selection_set, false),
+                OperationDefinition::Mutation(m) => (m.variable_definitions.as_slice(), &m.selection_set, true),
+            },
+        };
 
-                GqlSelection::FragmentSpread(fs) => Err(PrismaError::UnsupportedFeatureError(
-                    "Fragment spread",
-                    format!("Fragment '{}', at position {}.", fs.fragment_name, fs.position),
-                )),
+        let conversion = Conversion::new(gql_doc, variable_definitions, variables)?;
+        let selections = conversion.convert_selection_set(&[selection_set])?;
 
-                GqlSelection::InlineFragment(i) => Err(PrismaError::UnsupportedFeatureError(
-                    "Inline fragment",
-                    format!("At position {}.", i.position),
-                )),
-            })
-            .collect()
+        if is_mutation {
+            Ok(selections.into_iter().map(Operation::Write).collect())
+        } else {
+            Ok(selections.into_iter().map(Operation::Read).collect())
+        }
     }
 
     /// Checks if the given GraphQL definition matches the operation name that should be executed.
@@ -132,49 +126,290 @@ impl GraphQLProtocolAdapter {
             },
         }
     }
+}
 
-    fn convert_value(value: Value<String>) -> PrismaResult<QueryValue> {
-        match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
-            Value::Int(i) => match i.as_i64() {
-                Some(i) => Ok(QueryValue::Int(i)),
-                None => Err(PrismaError::QueryConversionError(format!(
-                    "Invalid 64 bit integer: {:?}",
-                    i
-                ))),
-            },
-            // We can't use Decimal::from_f64 here due to a bug in rust_decimal.
-            // Issue: https://github.com/paupino/rust-decimal/issues/228<Paste>
-            Value::Float(f) => match Decimal::from_str(&f.to_string()).ok() {
-                Some(dec) => Ok(QueryValue::Float(dec)),
-                None => Err(PrismaError::QueryConversionError(format!(
-                    "invalid 64-bit float: {:?}",
-                    f
-                ))),
-            },
-            Value::String(s) => Ok(QueryValue::String(s)),
-            Value::Boolean(b) => Ok(QueryValue::Boolean(b)),
-            Value::Null => Ok(QueryValue::Null),
-            Value::Enum(e) => Ok(QueryValue::Enum(e)),
+impl<'a> Conversion<'a> {
+    fn new(
+        gql_doc: &'a Document<String>,
+        variable_definitions: &[VariableDefinition<String>],
+        mut values: HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Self> {
+        let mut variables = HashMap::with_capacity(variable_definitions.len());
+        let mut defined_variables = HashSet::with_capacity(variable_definitions.len());
+
+        for definition in variable_definitions {
+            let value = match values.remove(&definition.name) {
+                Some(value) => Some(coerce_variable(&definition.name, value, &definition.var_type)?),
+                None => match definition.default_value {
+                    Some(ref default_value) => Some(convert_literal(default_value)?),
+                    None if is_non_null(&definition.var_type) => {
+                        return Err(PrismaError::QueryConversionError(format!(
+                            "Variable '${}' of required type '{}' was not provided.",
+                            definition.name,
+                            render_type(&definition.var_type)
+                        )))
+                    }
+                    None => None,
+                },
+            };
+
+            if let Some(value) = value {
+                variables.insert(definition.name.clone(), value);
+            }
+
+            defined_variables.insert(definition.name.clone());
+        }
+
+        let conditions = variables
+            .iter()
+            .filter_map(|(name, value)| match value {
+                QueryValue::Boolean(condition) => Some((name.clone(), *condition)),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self {
+            fragments: Fragments::new(gql_doc, conditions),
+            variables,
+            defined_variables,
+        })
+    }
+
+    /// Converts the fields of the selection sets, merging the fields with the same response key.
+    fn convert_selection_set(&self, selection_sets: &[&'a SelectionSet<String>]) -> PrismaResult<Vec<Selection>> {
+        self.fragments
+            .collect_fields(selection_sets, None)?
+            .into_iter()
+            .map(|(_, fields)| self.convert_field(&fields))
+            .collect()
+    }
+
+    fn convert_field(&self, fields: &[&'a GqlField<String>]) -> PrismaResult<Selection> {
+        let field = fields[0];
+
+        if let Some(conflict) = fields
+            .iter()
+            .find(|other| other.name != field.name || other.arguments != field.arguments)
+        {
+            return Err(PrismaError::QueryConversionError(format!(
+                "Fields '{}' at positions {} and {} select different fields or arguments.",
+                field.alias.as_ref().unwrap_or(&field.name),
+                field.position,
+                conflict.position
+            )));
+        }
+
+        let mut arguments: Vec<(String, QueryValue)> = Vec::with_capacity(field.arguments.len());
+
+        for (name, value) in field.arguments.iter() {
+            if let Some(value) = self.convert_value(value)? {
+                arguments.push((name.clone(), value));
+            }
+        }
+
+        let selection_sets: Vec<&'a SelectionSet<String>> = fields.iter().map(|field| &field.selection_set).collect();
+
+        let mut builder = Selection::builder(field.name.clone());
+        builder.set_arguments(arguments);
+        builder.nested_selections(self.convert_selection_set(&selection_sets)?);
+
+        if let Some(ref alias) = field.alias {
+            builder.alias(alias.clone());
+        };
+
+        Ok(builder.build())
+    }
+
+    /// Converts the value, substituting variables. `None` for variables without a value.
+    fn convert_value(&self, value: &Value<String>) -> PrismaResult<Option<QueryValue>> {
+        let value = match value {
+            Value::Variable(name) => {
+                return match self.variables.get(name) {
+                    Some(value) => Ok(Some(value.clone())),
+                    None if self.defined_variables.contains(name) => Ok(None),
+                    None => Err(PrismaError::QueryConversionError(format!(
+                        "Variable '${}' is not defined by the operation.",
+                        name
+                    ))),
+                };
+            }
             Value::List(values) => {
+                // Variables without a value are null in lists.
                 let values: Vec<QueryValue> = values
-                    .into_iter()
-                    .map(Self::convert_value)
+                    .iter()
+                    .map(|value| Ok(self.convert_value(value)?.unwrap_or(QueryValue::Null)))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
-                Ok(QueryValue::List(values))
+                QueryValue::List(values)
             }
             Value::Object(map) => {
-                let values = map
-                    .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
-                    .collect::<PrismaResult<IndexMap<String, QueryValue>>>()?;
+                // Fields set to variables without a value are left out, like arguments.
+                let mut values = IndexMap::with_capacity(map.len());
 
-                Ok(QueryValue::Object(values))
+                for (k, v) in map.iter() {
+                    if let Some(v) = self.convert_value(v)? {
+                        values.insert(k.clone(), v);
+                    }
+                }
+
+                QueryValue::Object(values)
             }
+            value => convert_literal(value)?,
+        };
+
+        Ok(Some(value))
+    }
+}
+
+/// Converts a value without variables, e.g. the default value of a variable.
+fn convert_literal(value: &Value<String>) -> PrismaResult<QueryValue> {
+    match value {
+        Value::Variable(name) => Err(PrismaError::QueryConversionError(format!(
+            "Variable '${}' can't be used in a constant value.",
+            name
+        ))),
+        Value::Int(i) => match i.as_i64() {
+            Some(i) => Ok(QueryValue::Int(i)),
+            None => Err(PrismaError::QueryConversionError(format!(
+                "Invalid 64 bit integer: {:?}",
+                i
+            ))),
+        },
+        Value::Float(f) => convert_float(*f),
+        Value::String(s) => Ok(QueryValue::String(s.clone())),
+        Value::Boolean(b) => Ok(QueryValue::Boolean(*b)),
+        Value::Null => Ok(QueryValue::Null),
+        Value::Enum(e) => Ok(QueryValue::Enum(e.clone())),
+        Value::List(values) => {
+            let values: Vec<QueryValue> = values
+                .iter()
+                .map(convert_literal)
+                .collect::<PrismaResult<Vec<QueryValue>>>()?;
+
+            Ok(QueryValue::List(values))
+        }
+        Value::Object(map) => {
+            let values = map
+                .iter()
+                .map(|(k, v)| convert_literal(v).map(|v| (k.clone(), v)))
+                .collect::<PrismaResult<IndexMap<String, QueryValue>>>()?;
+
+            Ok(QueryValue::Object(values))
+        }
+    }
+}
+
+// We can't use Decimal::from_f64 here due to a bug in rust_decimal.
+// Issue: https://github.com/paupino/rust-decimal/issues/228<Paste>
+fn convert_float(f: f64) -> PrismaResult<QueryValue> {
+    match Decimal::from_str(&f.to_string()).ok() {
+        Some(dec) => Ok(QueryValue::Float(dec)),
+        None => Err(PrismaError::QueryConversionError(format!(
+            "invalid 64-bit float: {:?}",
+            f
+        ))),
+    }
+}
+
+/// Renders the type as in the query, e.g. `[String!]`.
+fn render_type(var_type: &Type<String>) -> String {
+    match var_type {
+        Type::NamedType(name) => name.clone(),
+        Type::ListType(inner) => format!("[{}]", render_type(inner)),
+        Type::NonNullType(inner) => format!("{}!", render_type(inner)),
+    }
+}
+
+fn is_non_null(var_type: &Type<String>) -> bool {
+    match var_type {
+        Type::NonNullType(_) => true,
+        _ => false,
+    }
+}
+
+/// Checks the JSON value of a variable against the declared type of the variable, and converts it.
+///
+/// The built-in scalars are checked here. Values of other types, i.e. enums and input objects, are
+/// converted as they are, and validated against the query schema with the rest of the query.
+fn coerce_variable(name: &str, value: serde_json::Value, var_type: &Type<String>) -> PrismaResult<QueryValue> {
+    use serde_json::Value as Json;
+
+    let invalid = |value: &Json| {
+        PrismaError::QueryConversionError(format!(
+            "Variable '${}' got invalid value {}, expected a value of type '{}'.",
+            name,
+            value,
+            render_type(var_type)
+        ))
+    };
+
+    match (var_type, value) {
+        (Type::NonNullType(_), Json::Null) => Err(invalid(&Json::Null)),
+        (Type::NonNullType(inner), value) => coerce_variable(name, value, inner),
+        (_, Json::Null) => Ok(QueryValue::Null),
+
+        (Type::ListType(inner), Json::Array(values)) => {
+            let values = values
+                .into_iter()
+                .map(|value| coerce_variable(name, value, inner))
+                .collect::<PrismaResult<Vec<QueryValue>>>()?;
+
+            Ok(QueryValue::List(values))
+        }
+
+        // A single value is a list of one value.
+        (Type::ListType(inner), value) => Ok(QueryValue::List(vec![coerce_variable(name, value, inner)?])),
+
+        (Type::NamedType(type_name), value) => match (type_name.as_str(), value) {
+            ("Int", Json::Number(n)) if n.is_i64() => Ok(QueryValue::Int(n.as_i64().unwrap())),
+            ("Float", Json::Number(n)) => convert_float(n.as_f64().unwrap()),
+            ("Boolean", Json::Bool(b)) => Ok(QueryValue::Boolean(b)),
+            ("String", Json::String(s)) | ("DateTime", Json::String(s)) | ("UUID", Json::String(s)) => {
+                Ok(QueryValue::String(s))
+            }
+
+            // Json values are sent to the engine as strings, but can be given as JSON in variables.
+            ("Json", Json::String(s)) => Ok(QueryValue::String(s)),
+            ("Json", value) => Ok(QueryValue::String(value.to_string())),
+
+            ("Int", value)
+            | ("Float", value)
+            | ("Boolean", value)
+            | ("String", value)
+            | ("DateTime", value)
+            | ("UUID", value) => Err(invalid(&value)),
+
+            (_, value) => convert_json(value),
+        },
+    }
+}
+
+fn convert_json(value: serde_json::Value) -> PrismaResult<QueryValue> {
+    use serde_json::Value as Json;
+
+    match value {
+        Json::Null => Ok(QueryValue::Null),
+        Json::Bool(b) => Ok(QueryValue::Boolean(b)),
+        Json::Number(n) => match n.as_i64() {
+            Some(i) => Ok(QueryValue::Int(i)),
+            None => convert_float(n.as_f64().unwrap()),
+        },
+        Json::String(s) => Ok(QueryValue::String(s)),
+        Json::Array(values) => {
+            let values = values
+                .into_iter()
+                .map(convert_json)
+                .collect::<PrismaResult<Vec<QueryValue>>>()?;
+
+            Ok(QueryValue::List(values))
+        }
+        Json::Object(map) => {
+            let values = map
+                .into_iter()
+                .map(|(k, v)| convert_json(v).map(|v| (k, v)))
+                .collect::<PrismaResult<IndexMap<String, QueryValue>>>()?;
+
+            Ok(QueryValue::Object(values))
         }
     }
 }
@@ -34,8 +34,11 @@ impl GQLResponse {
         self.data.insert(key.into(), item);
     }
 
+    /// Adds an error, and counts it in the error metrics by its error code.
     pub fn insert_error(&mut self, error: impl Into<GQLError>) {
-        self.errors.push(error.into());
+        let error = error.into();
+        query_engine_metrics::ERRORS.inc(&[error.code()]);
+        self.errors.push(error);
     }
 
     pub fn take_data(&mut self, key: impl AsRef<str>) -> Option<Item> {
@@ -43,6 +46,13 @@ impl GQLResponse {
     }
 }
 
+impl GQLError {
+    /// The user facing error code, or `unknown` for errors without one.
+    pub fn code(&self) -> &str {
+        self.user_facing_error.error_code().unwrap_or("unknown")
+    }
+}
+
 impl From<PrismaError> for GQLResponse {
     fn from(err: PrismaError) -> Self {
         let mut responses = Self::default();
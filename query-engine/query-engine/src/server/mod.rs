@@ -3,34 +3,57 @@
 use crate::context::PrismaContext;
 use crate::dmmf;
 use crate::opt::PrismaOpt;
-use crate::request_handlers::graphql::{self, GraphQLSchemaRenderer, GraphQlBody};
-use crate::PrismaResult;
+use crate::request_handlers::graphql::{self, GQLResponse, GraphQLSchemaRenderer, GraphQlBody};
+use crate::{telemetry, PrismaResult};
 use elapsed_middleware::ElapsedMiddleware;
+use shutdown::TerminationSignals;
 
-use query_core::schema::QuerySchemaRenderer;
+pub(crate) use shutdown::{Shutdown, ShutdownMiddleware};
+
+use futures::{
+    future::{self, Either},
+    pin_mut,
+};
+use query_core::{schema::QuerySchemaRenderer, ExecutionOptions};
+use serde::Deserialize;
 use serde_json::json;
 use tide::http::{mime, StatusCode};
 use tide::{Body, Request, Response};
 use tide_server_timing::TimingMiddleware;
+use tracing_futures::Instrument;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Duration;
 
 mod elapsed_middleware;
+mod shutdown;
 
 //// Shared application state.
 pub(crate) struct State {
     cx: Arc<PrismaContext>,
     enable_playground: bool,
     enable_debug_mode: bool,
+    query_timeout: Option<Duration>,
+    shutdown: Arc<Shutdown>,
 }
 
 impl State {
     /// Create a new instance of `State`.
-    fn new(cx: PrismaContext, enable_playground: bool, enable_debug_mode: bool) -> Self {
+    pub(crate) fn new(
+        cx: Arc<PrismaContext>,
+        enable_playground: bool,
+        enable_debug_mode: bool,
+        query_timeout: Option<Duration>,
+        shutdown: Arc<Shutdown>,
+    ) -> Self {
         Self {
-            cx: Arc::new(cx),
+            cx,
             enable_playground,
             enable_debug_mode,
+            query_timeout,
+            shutdown,
         }
     }
 }
@@ -41,45 +64,109 @@ impl Clone for State {
             cx: self.cx.clone(),
             enable_playground: self.enable_playground,
             enable_debug_mode: self.enable_debug_mode,
+            query_timeout: self.query_timeout,
+            shutdown: self.shutdown.clone(),
         }
     }
 }
 
-/// Create a new server and listen.
+/// Create a new server and listen, until shut down by SIGTERM or SIGINT.
+///
+/// On shutdown, the server refuses new requests and waits for the in-flight requests for up to the
+/// grace period. Then the executor aborts the operations still running, waits for up to the grace
+/// period again for their transactions to roll back, and closes the connection pools. A second signal
+/// exits right away.
 pub async fn listen(opts: PrismaOpt) -> PrismaResult<()> {
     let config = opts.configuration(false)?.validate_that_one_datasource_is_provided()?;
     let datamodel = opts.datamodel(false)?;
     let cx = PrismaContext::builder(config, datamodel)
         .legacy(opts.legacy)
         .enable_raw_queries(opts.enable_raw_queries)
+        .query_cache_size(opts.query_cache_size)
         .build()
         .await?;
 
-    let mut app = tide::with_state(State::new(cx, opts.enable_playground, opts.enable_debug_mode));
+    let cx = Arc::new(cx);
+    let shutdown = Arc::new(Shutdown::new());
+    let mut termination_signals = TerminationSignals::new()?;
+    let query_timeout = opts.query_timeout.map(Duration::from_millis);
+    let app = routes(State::new(
+        cx.clone(),
+        opts.enable_playground,
+        opts.enable_debug_mode,
+        query_timeout,
+        shutdown.clone(),
+    ));
+
+    // NOTE: This println is essential for the correct working of the client.
+    info!("Started http server");
+
+    // Start the Tide server and log the server details.
+    // TODO: Tide should have a panicking listen_unix impl.
+    let server = async {
+        if let Some(path) = opts.unix_path() {
+            app.listen(&*format!("http+unix://{}", path)).await
+        } else {
+            app.listen((&*opts.host, opts.port)).await
+        }
+    };
+
+    {
+        let termination_signal = termination_signals.recv();
+        pin_mut!(server, termination_signal);
+
+        if let Either::Left((result, _)) = future::select(server, termination_signal).await {
+            result?;
+            return Ok(());
+        }
+    }
+
+    async_std::task::spawn(async move {
+        termination_signals.recv().await;
+        warn!("Received a second termination signal, exiting without shutting down.");
+        std::process::exit(1)
+    });
+
+    info!("Shutting down, waiting for in-flight requests.");
+    let grace_period = Duration::from_millis(opts.shutdown_grace_period);
+    let in_flight = shutdown.drain(grace_period).await;
+
+    if in_flight > 0 {
+        warn!(
+            "{} requests still in flight after the grace period, rolling back their transactions.",
+            in_flight
+        );
+    }
+
+    cx.executor.shutdown(grace_period).await;
+    info!("Shut down.");
+
+    Ok(())
+}
+
+/// The server with its middleware and routes, ready to listen or to respond to requests directly.
+pub(crate) fn routes(state: State) -> tide::Server<State> {
+    let enable_playground = state.enable_playground;
+    let shutdown = state.shutdown.clone();
+    let mut app = tide::with_state(state);
+
+    app.with(ShutdownMiddleware::new(shutdown));
     app.with(ElapsedMiddleware::new());
 
-    if opts.enable_playground {
+    if enable_playground {
         app.with(TimingMiddleware::new());
     }
 
     app.at("/").post(graphql_handler);
+    app.at("/explain").post(explain_handler);
     app.at("/").get(playground_handler);
     app.at("/sdl").get(sdl_handler);
     app.at("/dmmf").get(dmmf_handler);
     app.at("/server_info").get(server_info_handler);
+    app.at("/metrics").get(metrics_handler);
     app.at("/status").get(|_| async move { Ok(json!({"status": "ok"})) });
 
-    // NOTE: This println is essential for the correct working of the client.
-    info!("Started http server");
-
-    // Start the Tide server and log the server details.
-    // TODO: Tide should have a panicking listen_unix impl.
-    if let Some(path) = opts.unix_path() {
-        app.listen(&*format!("http+unix://{}", path)).await?;
-    } else {
-        app.listen((&*opts.host, opts.port)).await?;
-    }
-    Ok(())
+    app
 }
 
 /// The main query handler. This handles incoming GraphQL queries and passes it
@@ -92,14 +179,97 @@ async fn graphql_handler(mut req: Request<State>) -> tide::Result {
         }
     }
 
-    let body: GraphQlBody = req.body_json().await?;
+    // Spans of the request continue the trace of the caller.
+    let span = info_span!("request");
+    span.set_parent(&trace_context(&req));
+
+    let options = execution_options(&req)?;
+    let body: GraphQlBody = req.body_json().instrument(span.clone()).await?;
     let cx = req.state().cx.clone();
-    let result = graphql::handle(body, cx).await;
+    let result = graphql::handle(body, cx, options).instrument(span).await;
     let mut res = Response::new(StatusCode::Ok);
     res.set_body(Body::from_json(&result)?);
     Ok(res)
 }
 
+/// Explains how a GraphQL request runs: the query graph, the expression tree of the interpreter and the SQL
+/// statements, explained by the database with the `explainStatements=true` query parameter. Only available
+/// in debug mode.
+///
+/// # Security
+///
+/// The request runs on the database to record its statements. Its writes are rolled back.
+async fn explain_handler(mut req: Request<State>) -> tide::Result {
+    #[derive(Debug, Default, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct ExplainParams {
+        #[serde(default)]
+        explain_statements: bool,
+    }
+
+    if !req.state().enable_debug_mode {
+        return Ok(Response::new(StatusCode::NotFound));
+    }
+
+    let params: ExplainParams = req.query()?;
+    let options = execution_options(&req)?;
+    let body: GraphQlBody = req.body_json().await?;
+    let cx = req.state().cx.clone();
+    let mut res = Response::new(StatusCode::Ok);
+
+    match graphql::explain(body, cx, options, params.explain_statements).await {
+        Ok(plan) => res.set_body(Body::from_json(&plan)?),
+        Err(err) => {
+            let response: GQLResponse = err.into();
+            res.set_body(Body::from_json(&response)?);
+        }
+    }
+
+    Ok(res)
+}
+
+/// The execution options of the request, from its headers and the server defaults.
+fn execution_options(req: &Request<State>) -> tide::Result<ExecutionOptions> {
+    /// Header to read from the primary even if the datasource has read replicas, e.g. right after a
+    /// write that might not have been replicated yet.
+    static FORCE_PRIMARY_HEADER: &str = "x-prisma-force-primary";
+
+    /// Header overriding the default query timeout, in milliseconds.
+    static QUERY_TIMEOUT_HEADER: &str = "x-prisma-query-timeout";
+
+    let timeout = match req.header(QUERY_TIMEOUT_HEADER) {
+        Some(values) => {
+            let millis: u64 = values.last().as_str().parse().map_err(|_| {
+                tide::Error::from_str(
+                    StatusCode::BadRequest,
+                    format!("The {} header must be a number of milliseconds.", QUERY_TIMEOUT_HEADER),
+                )
+            })?;
+
+            Some(Duration::from_millis(millis))
+        }
+        None => req.state().query_timeout,
+    };
+
+    Ok(ExecutionOptions {
+        force_primary: req.header(FORCE_PRIMARY_HEADER).is_some(),
+        timeout,
+    })
+}
+
+/// The OpenTelemetry context propagated in the trace context headers of the request.
+fn trace_context(req: &Request<State>) -> opentelemetry::Context {
+    let headers: HashMap<String, String> = telemetry::TRACE_CONTEXT_HEADERS
+        .iter()
+        .filter_map(|name| {
+            req.header(*name)
+                .map(|values| (name.to_string(), values.last().as_str().to_owned()))
+        })
+        .collect();
+
+    telemetry::trace_context(&headers)
+}
+
 /// Expose the GraphQL playground if enabled.
 ///
 /// # Security
@@ -142,6 +312,14 @@ async fn server_info_handler(req: Request<State>) -> tide::Result<impl Into<Resp
     }))
 }
 
+/// Exports the metrics of the query engine, in the Prometheus text format.
+async fn metrics_handler(_: Request<State>) -> tide::Result {
+    let mut res = Response::new(StatusCode::Ok);
+    res.set_body(query_engine_metrics::render());
+    res.set_content_type(mime::PLAIN);
+    Ok(res)
+}
+
 /// Handle debug headers inside the main GraphQL endpoint.
 async fn handle_debug_headers(req: &Request<State>) -> tide::Result<Option<impl Into<Response>>> {
     /// Debug header that triggers a panic in the request thread.
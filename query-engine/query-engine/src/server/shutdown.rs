@@ -0,0 +1,132 @@
+use tide::http::StatusCode;
+use tide::{Body, Middleware, Next, Request, Response};
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the server checks whether the in-flight requests finished while draining.
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Graceful shutdown of the server on a termination signal.
+pub(crate) struct Shutdown {
+    draining: AtomicBool,
+    in_flight: AtomicUsize,
+}
+
+impl Shutdown {
+    /// Creates a new `Shutdown`.
+    pub fn new() -> Self {
+        Self {
+            draining: AtomicBool::new(false),
+            in_flight: AtomicUsize::new(0),
+        }
+    }
+
+    /// Refuses new requests and waits until the in-flight requests finished, at most for the grace period.
+    /// Returns the number of requests still in flight.
+    pub async fn drain(&self, grace_period: Duration) -> usize {
+        self.draining.store(true, Ordering::SeqCst);
+        let deadline = Instant::now() + grace_period;
+
+        loop {
+            let in_flight = self.in_flight.load(Ordering::SeqCst);
+
+            if in_flight == 0 || Instant::now() >= deadline {
+                return in_flight;
+            }
+
+            async_std::task::sleep(POLL_INTERVAL).await;
+        }
+    }
+}
+
+/// Middleware counting the in-flight requests, and refusing new requests once the server drains.
+#[derive(Clone)]
+pub(crate) struct ShutdownMiddleware {
+    shutdown: Arc<Shutdown>,
+}
+
+impl ShutdownMiddleware {
+    /// Creates a new `ShutdownMiddleware`.
+    pub fn new(shutdown: Arc<Shutdown>) -> Self {
+        Self { shutdown }
+    }
+}
+
+/// Counts a request as in flight until dropped.
+struct InFlight<'a>(&'a Shutdown);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+#[tide::utils::async_trait]
+impl<State: Clone + Send + Sync + 'static> Middleware<State> for ShutdownMiddleware {
+    async fn handle(&self, req: Request<State>, next: Next<'_, State>) -> tide::Result {
+        // Counting first makes sure draining waits for every request that didn't see it start.
+        self.shutdown.in_flight.fetch_add(1, Ordering::SeqCst);
+        let _in_flight = InFlight(&self.shutdown);
+
+        if self.shutdown.draining.load(Ordering::SeqCst) {
+            return shutting_down_response();
+        }
+
+        Ok(next.run(req).await)
+    }
+}
+
+/// The response to requests the server refuses because it shuts down.
+fn shutting_down_response() -> tide::Result {
+    let err: user_facing_errors::Error =
+        user_facing_errors::KnownError::new(user_facing_errors::query_engine::ShuttingDown)
+            .unwrap()
+            .into();
+
+    let mut res = Response::new(StatusCode::ServiceUnavailable);
+    res.set_body(Body::from_json(&err)?);
+    Ok(res)
+}
+
+/// Receives SIGTERM and SIGINT. The handlers are installed on creation, so signals received before
+/// `recv` is called are not missed.
+pub(crate) struct TerminationSignals {
+    #[cfg(unix)]
+    terminate: tokio::signal::unix::Signal,
+    #[cfg(unix)]
+    interrupt: tokio::signal::unix::Signal,
+}
+
+impl TerminationSignals {
+    pub fn new() -> std::io::Result<Self> {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+
+            Ok(Self {
+                terminate: signal(SignalKind::terminate())?,
+                interrupt: signal(SignalKind::interrupt())?,
+            })
+        }
+
+        #[cfg(not(unix))]
+        {
+            Ok(Self {})
+        }
+    }
+
+    /// Resolves on the next SIGTERM or SIGINT.
+    pub async fn recv(&mut self) {
+        #[cfg(unix)]
+        {
+            futures::future::select(Box::pin(self.terminate.recv()), Box::pin(self.interrupt.recv())).await;
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! Export of the tracing spans of the query engine to an OpenTelemetry collector over OTLP.
+
+use crate::{PrismaError, PrismaResult};
+use opentelemetry::{
+    global,
+    sdk::{propagation::TraceContextPropagator, trace::Tracer},
+    Context as OtelContext,
+};
+use std::collections::HashMap;
+use tracing::{
+    span::{Attributes, Id, Record},
+    subscriber::Interest,
+    Event, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, Layer},
+    registry::LookupSpan,
+    EnvFilter,
+};
+
+/// Headers of the W3C trace context, see https://www.w3.org/TR/trace-context/.
+pub(crate) const TRACE_CONTEXT_HEADERS: &[&str] = &["traceparent", "tracestate"];
+
+/// Installs the exporter sending spans to the collector at the endpoint. Spans are exported until
+/// the returned guard is dropped.
+pub(crate) fn install_exporter(endpoint: &str) -> PrismaResult<(Tracer, opentelemetry_otlp::Uninstall)> {
+    global::set_text_map_propagator(TraceContextPropagator::new());
+
+    opentelemetry_otlp::new_pipeline()
+        .with_endpoint(endpoint)
+        .install()
+        .map_err(|err| PrismaError::ConfigurationError(format!("Could not install the OTLP exporter: {}", err)))
+}
+
+/// The trace context of the caller, from the values of the `TRACE_CONTEXT_HEADERS` of a request.
+pub(crate) fn trace_context(headers: &HashMap<String, String>) -> OtelContext {
+    global::get_text_map_propagator(|propagator| propagator.extract(headers))
+}
+
+/// Applies a filter to the events of a layer only. The filter of a subscriber applies to all of its
+/// layers, and would keep the spans below the logged level from being exported.
+pub(crate) struct EventFilter<L> {
+    filter: EnvFilter,
+    layer: L,
+}
+
+impl<L> EventFilter<L> {
+    pub(crate) fn new(filter: EnvFilter, layer: L) -> Self {
+        Self { filter, layer }
+    }
+}
+
+impl<S, L> Layer<S> for EventFilter<L>
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+    L: Layer<S>,
+{
+    fn register_callsite(&self, metadata: &'static Metadata<'static>) -> Interest {
+        // The filter keeps track of the callsites its span directives match, but must not disable them.
+        self.filter.register_callsite(metadata);
+        self.layer.register_callsite(metadata)
+    }
+
+    fn new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        self.filter.new_span(attrs, id, ctx.clone());
+        self.layer.new_span(attrs, id, ctx);
+    }
+
+    fn on_record(&self, span: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        self.filter.on_record(span, values, ctx.clone());
+        self.layer.on_record(span, values, ctx);
+    }
+
+    fn on_follows_from(&self, span: &Id, follows: &Id, ctx: Context<'_, S>) {
+        self.layer.on_follows_from(span, follows, ctx);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if self.filter.enabled(event.metadata(), ctx.clone()) {
+            self.layer.on_event(event, ctx);
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        self.filter.on_enter(id, ctx.clone());
+        self.layer.on_enter(id, ctx);
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        self.filter.on_exit(id, ctx.clone());
+        self.layer.on_exit(id, ctx);
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        self.filter.on_close(id.clone(), ctx.clone());
+        self.layer.on_close(id, ctx);
+    }
+}
@@ -1,4 +1,18 @@
+mod aliases;
+mod composite_types;
 mod dmmf;
 mod execute_raw;
+mod explain;
+mod field_validations;
+mod find_one_loader;
+mod introspection;
+mod metrics;
+mod protocol_adapter;
+mod query_cache;
+mod query_timeout;
+mod read_replicas;
+mod shutdown;
+mod statement_cancellation;
+mod telemetry;
 mod test_api;
 mod type_mappings;
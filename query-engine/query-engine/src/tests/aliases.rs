@@ -0,0 +1,126 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+use tide::http::StatusCode;
+
+static OWNER: &str = indoc! {"
+    model Owner {
+        id   Int   @id
+        cats Cat[]
+    }
+
+    model Cat {
+        id      Int    @id
+        name    String
+        owner   Owner? @relation(fields: [ownerId], references: [id])
+        ownerId Int?
+    }
+"};
+
+async fn engine_with_cats(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    let engine = api.create_engine(OWNER).await?;
+
+    engine
+        .request(
+            r#"mutation {
+                createOneOwner(data: { id: 1, cats: { create: [{ id: 1, name: "Felix" }, { id: 2, name: "Tom" }] } }) {
+                    id
+                }
+            }"#,
+        )
+        .await;
+
+    Ok(engine)
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn a_scalar_must_be_returned_under_each_alias(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+
+    let response = engine
+        .request("query { cats: findManyCat(take: 1) { key: id other: id name } }")
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "cats": [{ "key": 1, "other": 1, "name": "Felix" }] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn a_relation_must_be_read_with_the_arguments_of_each_alias(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+
+    let response = engine
+        .request(
+            r#"query {
+                findOneOwner(where: { id: 1 }) {
+                    felix: cats(where: { name: { equals: "Felix" } }) { name }
+                    others: cats(where: { name: { not: "Felix" } }) { id name }
+                }
+            }"#,
+        )
+        .await;
+
+    assert_eq!(
+        response,
+        json!({
+            "data": {
+                "findOneOwner": {
+                    "felix": [{ "name": "Felix" }],
+                    "others": [{ "id": 2, "name": "Tom" }],
+                }
+            }
+        })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn aggregations_must_be_returned_under_their_aliases(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+
+    let response = engine
+        .request("query { stats: aggregateCat { total: count all: count highest: max { id } } }")
+        .await;
+
+    assert_eq!(
+        response,
+        json!({ "data": { "stats": { "total": 2, "all": 2, "highest": { "id": 2 } } } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn compacted_batches_must_return_the_alias_of_their_queries(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+    let query = |id: u32| format!("query {{ cat: findOneCat(where: {{ id: {} }}) {{ id name }} }}", id);
+
+    let body = json!({
+        "batch": [
+            { "query": query(2), "variables": {} },
+            { "query": query(1), "variables": {} },
+            { "query": query(3), "variables": {} },
+        ],
+        "transaction": false,
+    });
+
+    let (status, response) = post(&engine.server(), "/", body, &[]).await?;
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(
+        response,
+        json!([
+            { "data": { "cat": { "id": 2, "name": "Tom" } } },
+            { "data": { "cat": { "id": 1, "name": "Felix" } } },
+            { "data": { "cat": null } },
+        ])
+    );
+
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static USER_WITH_ADDRESS: &str = indoc! {"
+    model User {
+        id      String   @id
+        address Address
+        billing Address?
+    }
+
+    type Address {
+        street String
+        zip    String?
+        tags   String[]
+    }
+"};
+
+/// Composite values are returned as serialized JSON documents.
+fn composite_value(response: &serde_json::Value, pointer: &str) -> serde_json::Value {
+    let serialized = response
+        .pointer(pointer)
+        .and_then(|value| value.as_str())
+        .unwrap_or_else(|| panic!("No composite value at `{}` in {}", pointer, response));
+
+    serde_json::from_str(serialized).unwrap()
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_values_can_be_created_and_read(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER_WITH_ADDRESS).await?;
+
+    let create = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: "alice"
+                address: { street: "Main Street 1", zip: "10115", tags: ["home", "office"] }
+            }) {
+                id
+                address
+                billing
+            }
+        }
+    "#};
+
+    let response = query_engine.request(create).await;
+
+    assert_eq!(
+        composite_value(&response, "/data/createOneUser/address"),
+        json!({ "street": "Main Street 1", "zip": "10115", "tags": ["home", "office"] })
+    );
+    assert_eq!(
+        response.pointer("/data/createOneUser/billing"),
+        Some(&serde_json::Value::Null)
+    );
+
+    let read = indoc! {r#"
+        query {
+            findOneUser(where: { id: "alice" }) {
+                address
+            }
+        }
+    "#};
+
+    let response = query_engine.request(read).await;
+
+    assert_eq!(
+        composite_value(&response, "/data/findOneUser/address"),
+        json!({ "street": "Main Street 1", "zip": "10115", "tags": ["home", "office"] })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_values_are_replaced_as_a_whole_on_update(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER_WITH_ADDRESS).await?;
+
+    let create = indoc! {r#"
+        mutation {
+            createOneUser(data: { id: "alice", address: { street: "Main Street 1", zip: "10115" } }) {
+                id
+            }
+        }
+    "#};
+
+    query_engine.request(create).await;
+
+    let update = indoc! {r#"
+        mutation {
+            updateOneUser(where: { id: "alice" }, data: { address: { street: "Side Street 2" } }) {
+                address
+            }
+        }
+    "#};
+
+    let response = query_engine.request(update).await;
+
+    assert_eq!(
+        composite_value(&response, "/data/updateOneUser/address"),
+        json!({ "street": "Side Street 2" })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn composite_values_missing_required_fields_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER_WITH_ADDRESS).await?;
+
+    let create = indoc! {r#"
+        mutation {
+            createOneUser(data: { id: "alice", address: { zip: "10115" } }) {
+                id
+            }
+        }
+    "#};
+
+    let response = query_engine.request(create).await;
+
+    assert!(response.pointer("/data").map(|data| data.is_null()).unwrap_or(true));
+    assert!(
+        response
+            .pointer("/errors/0/error")
+            .and_then(|error| error.as_str())
+            .map_or(false, |error| error.contains("street")),
+        "Expected an error about the missing `street` field, got {}",
+        response
+    );
+
+    let read = indoc! {r#"
+        query {
+            findManyUser {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        query_engine.request(read).await,
+        json!({ "data": { "findManyUser": [] } })
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn composite_values_can_be_filtered_on_by_equality(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(USER_WITH_ADDRESS).await?;
+
+    let create_alice = indoc! {r#"
+        mutation {
+            createOneUser(data: {
+                id: "alice"
+                address: { street: "Main Street 1", zip: "10115" }
+                billing: { street: "Main Street 1", zip: "10115" }
+            }) {
+                id
+            }
+        }
+    "#};
+
+    let create_bob = indoc! {r#"
+        mutation {
+            createOneUser(data: { id: "bob", address: { street: "Side Street 2" } }) {
+                id
+            }
+        }
+    "#};
+
+    query_engine.request(create_alice).await;
+    query_engine.request(create_bob).await;
+
+    let equals = indoc! {r#"
+        query {
+            findManyUser(where: { address: { equals: { zip: "10115", street: "Main Street 1" } } }) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        query_engine.request(equals).await,
+        json!({ "data": { "findManyUser": [{ "id": "alice" }] } })
+    );
+
+    let not_equals = indoc! {r#"
+        query {
+            findManyUser(where: { NOT: [{ address: { equals: { street: "Main Street 1", zip: "10115" } } }] }) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        query_engine.request(not_equals).await,
+        json!({ "data": { "findManyUser": [{ "id": "bob" }] } })
+    );
+
+    let null_billing = indoc! {r#"
+        query {
+            findManyUser(where: { billing: { equals: null } }) {
+                id
+            }
+        }
+    "#};
+
+    assert_eq!(
+        query_engine.request(null_billing).await,
+        json!({ "data": { "findManyUser": [{ "id": "bob" }] } })
+    );
+
+    Ok(())
+}
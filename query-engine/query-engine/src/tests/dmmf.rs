@@ -184,7 +184,7 @@ fn list_of_reserved_model_names_must_be_up_to_date() {
     }
 }
 
-fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
+pub(super) fn get_query_schema(datamodel_string: &str) -> (QuerySchema, datamodel::dml::Datamodel) {
     feature_flags::initialize(&vec![String::from("all")]).unwrap();
 
     let dm = datamodel::parse_datamodel_and_ignore_datasource_urls(datamodel_string).unwrap();
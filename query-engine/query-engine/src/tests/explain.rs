@@ -0,0 +1,115 @@
+use super::test_api::*;
+use connector::{filter::Filter, Connector, ReadOperations, ScalarCompare, Transaction, WriteOperations};
+use indoc::indoc;
+use prisma_models::{Field, ModelProjection};
+use serde_json::{json, Value};
+use sql_connector::{FromSource, Sqlite};
+use test_macros::*;
+use tide::http::StatusCode;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+async fn explain(engine: &QueryEngine, query: &str, explain_statements: bool) -> anyhow::Result<Value> {
+    let path = format!("/explain?explainStatements={}", explain_statements);
+    let (status, plan) = post(&engine.server(), &path, json!({ "query": query }), &[]).await?;
+
+    assert_eq!(status, StatusCode::Ok);
+
+    Ok(plan)
+}
+
+fn statements(plan: &Value) -> &Vec<Value> {
+    plan["statements"].as_array().unwrap()
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reads_must_be_explained(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(CAT).await?;
+    let plan = explain(&engine, "query { findManyCat { name } }", false).await?;
+
+    assert!(plan["queryGraph"].as_str().unwrap().contains("Cat"));
+    assert!(plan["expression"].as_str().unwrap().contains("Cat"));
+
+    let statements = statements(&plan);
+
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0]["statement"].as_str().unwrap().starts_with("SELECT"));
+    assert_eq!(statements[0]["plan"], Value::Null);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn statements_must_be_explained_by_the_database(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(CAT).await?;
+    let plan = explain(&engine, r#"query { findOneCat(where: { id: 1 }) { name } }"#, true).await?;
+    let statements = statements(&plan);
+
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0]["parameters"].as_array().unwrap().contains(&json!(1)));
+    assert!(!statements[0]["plan"].as_array().unwrap().is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn explained_writes_must_be_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(CAT).await?;
+    let create = r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#;
+    let plan = explain(&engine, create, true).await?;
+
+    assert!(statements(&plan)
+        .iter()
+        .any(|statement| statement["statement"].as_str().unwrap().starts_with("INSERT")));
+
+    let response = engine.request("query { findManyCat { name } }").await;
+    assert_eq!(response["data"]["findManyCat"], json!([]));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn raw_queries_must_not_be_explained(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(CAT).await?;
+    let query = r#"mutation { executeRaw(query: "DELETE FROM Cat", parameters: "[]") }"#;
+    let plan = explain(&engine, query, true).await?;
+
+    assert!(plan["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("Explaining raw queries."));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn only_engine_statements_must_be_recorded(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine(CAT).await?;
+    let model = engine.model("Cat");
+
+    let connector = Sqlite::from_url(&api.url()).await?;
+    let conn = connector.get_connection().await?;
+    let tx = conn.start_transaction().await?;
+
+    tx.record_statements(true);
+    tx.query_raw("SELECT * FROM Cat".to_owned(), vec![]).await?;
+
+    let filter: Filter = model.fields().find_from_scalar("id")?.equals(1);
+    let selected_fields = ModelProjection::new(model.fields().scalar().into_iter().map(Field::from).collect());
+    let record = tx.get_single_record(&model, &filter, &selected_fields).await?;
+
+    let statements = tx.recorded_statements();
+    tx.rollback().await?;
+
+    assert!(record.is_none());
+    assert_eq!(statements.len(), 1);
+    assert!(statements[0].statement.starts_with("SELECT"));
+    assert!(statements[0].plan.is_some());
+
+    Ok(())
+}
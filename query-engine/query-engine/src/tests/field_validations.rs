@@ -0,0 +1,166 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::test_each_connector;
+
+static PRODUCT: &str = indoc! {r#"
+    model Product {
+        id       String @id
+        sku      String @pattern("^[A-Z]{3}-[0-9]+$")
+        quantity Int    @range(min: 0, max: 100)
+    }
+"#};
+
+fn error_code(response: &serde_json::Value) -> Option<&str> {
+    response["errors"][0]["user_facing_error"]["error_code"].as_str()
+}
+
+async fn products(query_engine: &QueryEngine) -> serde_json::Value {
+    let query = indoc! {r#"
+        query {
+            findManyProduct {
+                id
+                sku
+                quantity
+            }
+        }
+    "#};
+
+    query_engine.request(query).await["data"]["findManyProduct"].clone()
+}
+
+#[test_each_connector]
+async fn validations_are_enforced_on_create(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(PRODUCT).await?;
+
+    let valid = indoc! {r#"
+        mutation {
+            createOneProduct(data: { id: "1", sku: "ABC-1", quantity: 10 }) { id }
+        }
+    "#};
+
+    assert_eq!(
+        query_engine.request(valid).await,
+        json!({ "data": { "createOneProduct": { "id": "1" } } })
+    );
+
+    let bad_pattern = indoc! {r#"
+        mutation {
+            createOneProduct(data: { id: "2", sku: "abc-1", quantity: 10 }) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(bad_pattern).await), Some("P2022"));
+
+    let out_of_range = indoc! {r#"
+        mutation {
+            createOneProduct(data: { id: "3", sku: "ABC-3", quantity: 101 }) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(out_of_range).await), Some("P2022"));
+
+    assert_eq!(
+        products(&query_engine).await,
+        json!([{ "id": "1", "sku": "ABC-1", "quantity": 10 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn validations_are_enforced_on_update(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(PRODUCT).await?;
+
+    let create = indoc! {r#"
+        mutation {
+            createOneProduct(data: { id: "1", sku: "ABC-1", quantity: 10 }) { id }
+        }
+    "#};
+
+    query_engine.request(create).await;
+
+    let bad_pattern = indoc! {r#"
+        mutation {
+            updateOneProduct(where: { id: "1" }, data: { sku: { set: "nope" } }) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(bad_pattern).await), Some("P2022"));
+
+    let out_of_range = indoc! {r#"
+        mutation {
+            updateOneProduct(where: { id: "1" }, data: { quantity: { set: -1 } }) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(out_of_range).await), Some("P2022"));
+
+    // The result of an atomic operation can't be checked, so it is rejected on fields with a range.
+    let increment = indoc! {r#"
+        mutation {
+            updateOneProduct(where: { id: "1" }, data: { quantity: { increment: 1000 } }) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(increment).await), Some("P2022"));
+
+    let update_many = indoc! {r#"
+        mutation {
+            updateManyProduct(data: { quantity: { set: 1000 } }) { count }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(update_many).await), Some("P2022"));
+
+    assert_eq!(
+        products(&query_engine).await,
+        json!([{ "id": "1", "sku": "ABC-1", "quantity": 10 }])
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn validations_are_enforced_on_upsert(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(PRODUCT).await?;
+
+    let create_branch = indoc! {r#"
+        mutation {
+            upsertOneProduct(
+                where: { id: "1" }
+                create: { id: "1", sku: "abc", quantity: 10 }
+                update: { quantity: { set: 20 } }
+            ) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(create_branch).await), Some("P2022"));
+
+    let create = indoc! {r#"
+        mutation {
+            createOneProduct(data: { id: "1", sku: "ABC-1", quantity: 10 }) { id }
+        }
+    "#};
+
+    query_engine.request(create).await;
+
+    let update_branch = indoc! {r#"
+        mutation {
+            upsertOneProduct(
+                where: { id: "1" }
+                create: { id: "1", sku: "ABC-1", quantity: 10 }
+                update: { quantity: { set: 500 } }
+            ) { id }
+        }
+    "#};
+
+    assert_eq!(error_code(&query_engine.request(update_branch).await), Some("P2022"));
+
+    assert_eq!(
+        products(&query_engine).await,
+        json!([{ "id": "1", "sku": "ABC-1", "quantity": 10 }])
+    );
+
+    Ok(())
+}
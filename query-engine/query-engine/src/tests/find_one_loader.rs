@@ -0,0 +1,232 @@
+use super::test_api::*;
+use async_trait::async_trait;
+use connector::{
+    filter::Filter, AggregationResult, Aggregator, Connection, ConnectionLike, Connector, QueryArguments,
+    ReadOperations, RecordFilter, ScalarCompare, StatementCanceller, Transaction, WriteArgs, WriteOperations,
+};
+use futures::future;
+use indoc::indoc;
+use prisma_models::{
+    Field, ManyRecords, ModelProjection, ModelRef, PrismaValue, RecordProjection, RelationFieldRef, SingleRecord,
+};
+use query_core::FindOneLoader;
+use sql_connector::{FromSource, Sqlite};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+async fn engine_with_cats(api: &TestApi) -> anyhow::Result<QueryEngine> {
+    let engine = api.create_engine(CAT).await?;
+
+    engine
+        .request(r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+
+    engine
+        .request(r#"mutation { createOneCat(data: { id: 2, name: "Garfield" }) { id } }"#)
+        .await;
+
+    Ok(engine)
+}
+
+/// A connection counting the reads run on it. Every read of a model without relations is one statement.
+struct CountingConnection {
+    inner: Box<dyn Connection>,
+    reads: AtomicUsize,
+}
+
+impl CountingConnection {
+    fn new(inner: Box<dyn Connection>) -> Self {
+        Self {
+            inner,
+            reads: AtomicUsize::new(0),
+        }
+    }
+
+    fn reads(&self) -> usize {
+        self.reads.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl Connection for CountingConnection {
+    async fn start_transaction<'a>(&'a self) -> connector::Result<Box<dyn Transaction + 'a>> {
+        self.inner.start_transaction().await
+    }
+
+    async fn statement_canceller(&self) -> connector::Result<Option<Box<dyn StatementCanceller>>> {
+        self.inner.statement_canceller().await
+    }
+}
+
+#[async_trait]
+impl ReadOperations for CountingConnection {
+    async fn get_single_record(
+        &self,
+        model: &ModelRef,
+        filter: &Filter,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<Option<SingleRecord>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner.get_single_record(model, filter, selected_fields).await
+    }
+
+    async fn get_many_records(
+        &self,
+        model: &ModelRef,
+        query_arguments: QueryArguments,
+        selected_fields: &ModelProjection,
+    ) -> connector::Result<ManyRecords> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner
+            .get_many_records(model, query_arguments, selected_fields)
+            .await
+    }
+
+    async fn get_related_m2m_record_ids(
+        &self,
+        from_field: &RelationFieldRef,
+        from_record_ids: &[RecordProjection],
+    ) -> connector::Result<Vec<(RecordProjection, RecordProjection)>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner.get_related_m2m_record_ids(from_field, from_record_ids).await
+    }
+
+    async fn aggregate_records(
+        &self,
+        model: &ModelRef,
+        aggregators: Vec<Aggregator>,
+        query_arguments: QueryArguments,
+    ) -> connector::Result<Vec<AggregationResult>> {
+        self.reads.fetch_add(1, Ordering::SeqCst);
+        self.inner.aggregate_records(model, aggregators, query_arguments).await
+    }
+}
+
+#[async_trait]
+impl WriteOperations for CountingConnection {
+    async fn create_record(&self, model: &ModelRef, args: WriteArgs) -> connector::Result<RecordProjection> {
+        self.inner.create_record(model, args).await
+    }
+
+    async fn update_records(
+        &self,
+        model: &ModelRef,
+        record_filter: RecordFilter,
+        args: WriteArgs,
+    ) -> connector::Result<Vec<RecordProjection>> {
+        self.inner.update_records(model, record_filter, args).await
+    }
+
+    async fn delete_records(&self, model: &ModelRef, record_filter: RecordFilter) -> connector::Result<usize> {
+        self.inner.delete_records(model, record_filter).await
+    }
+
+    async fn connect(
+        &self,
+        field: &RelationFieldRef,
+        parent_id: &RecordProjection,
+        child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.inner.connect(field, parent_id, child_ids).await
+    }
+
+    async fn disconnect(
+        &self,
+        field: &RelationFieldRef,
+        parent_id: &RecordProjection,
+        child_ids: &[RecordProjection],
+    ) -> connector::Result<()> {
+        self.inner.disconnect(field, parent_id, child_ids).await
+    }
+
+    async fn execute_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<usize> {
+        self.inner.execute_raw(query, parameters).await
+    }
+
+    async fn query_raw(&self, query: String, parameters: Vec<PrismaValue>) -> connector::Result<serde_json::Value> {
+        self.inner.query_raw(query, parameters).await
+    }
+}
+
+/// Looks up the cats with the given ids concurrently on a plain connection, like the reads of the executor,
+/// and returns their names and the number of statements run. The connection is checked out before, so all
+/// lookups join the batch of the first one. With `drop_table`, the table is dropped before the lookups.
+async fn find_cats(
+    api: &TestApi,
+    model: &ModelRef,
+    ids: &[i64],
+    drop_table: bool,
+) -> anyhow::Result<(Vec<connector::Result<Option<String>>>, usize)> {
+    let connector = Sqlite::from_url(&api.url()).await?;
+    let conn = CountingConnection::new(connector.get_connection().await?);
+
+    if drop_table {
+        conn.execute_raw("DROP TABLE Cat".to_owned(), vec![]).await?;
+    }
+
+    let loader = FindOneLoader::new();
+    let conn_like = ConnectionLike::Connection(&conn);
+    let id_field = model.fields().find_from_scalar("id")?;
+    let selected_fields = ModelProjection::new(model.fields().scalar().into_iter().map(Field::from).collect());
+
+    let lookups = ids.iter().map(|id| {
+        let filter: Filter = id_field.equals(*id);
+        let (loader, conn, selected_fields) = (&loader, &conn_like, &selected_fields);
+
+        async move {
+            let record = loader.get_single_record(conn, model, &filter, selected_fields).await?;
+            Ok(record.map(|record| record.get_field_value("name").unwrap().clone().into_string().unwrap()))
+        }
+    });
+
+    let names = future::join_all(lookups).await;
+
+    Ok((names, conn.reads()))
+}
+
+fn cat_names(results: Vec<connector::Result<Option<String>>>) -> Vec<Option<String>> {
+    results.into_iter().map(|result| result.unwrap()).collect()
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn concurrent_lookups_must_share_one_query(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+    let (results, statements) = find_cats(api, &engine.model("Cat"), &[1, 2, 1], false).await?;
+
+    let felix = Some("Felix".to_owned());
+    let garfield = Some("Garfield".to_owned());
+
+    assert_eq!(cat_names(results), vec![felix.clone(), garfield, felix]);
+    assert_eq!(statements, 1);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn lookups_without_a_record_must_fall_back_to_their_own_query(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+    let (results, statements) = find_cats(api, &engine.model("Cat"), &[1, 3], false).await?;
+
+    assert_eq!(cat_names(results), vec![Some("Felix".to_owned()), None]);
+    assert_eq!(statements, 2);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn a_failed_batch_must_fail_every_lookup(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_cats(api).await?;
+    let (results, _) = find_cats(api, &engine.model("Cat"), &[1, 2, 3], true).await?;
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|result| result.is_err()));
+
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use super::dmmf::get_query_schema;
+use crate::request_handlers::graphql::GraphQLIntrospection;
+use graphql_parser as gql;
+use serde_json::{json, Value};
+use serial_test::serial;
+use std::collections::HashMap;
+
+// Tests in this file run serially because the function `get_query_schema` depends on setting an env var.
+
+const DATAMODEL: &str = r#"
+    model User {
+        id    String  @id
+        name  String?
+        role  Role
+        posts Post[]
+    }
+
+    model Post {
+        id       String @id
+        authorId String
+        author   User   @relation(fields: [authorId], references: [id])
+    }
+
+    enum Role {
+        ADMIN
+        USER
+    }
+"#;
+
+#[test]
+#[serial]
+fn introspection_describes_the_root_types() {
+    let response = introspect(
+        r#"
+        query IntrospectionQuery {
+            __schema {
+                queryType { name }
+                mutationType { name }
+                subscriptionType { name }
+                types { ...TypeName }
+            }
+        }
+
+        fragment TypeName on __Type {
+            kind
+            name
+        }
+    "#,
+    );
+
+    let schema = &response["data"]["__schema"];
+
+    assert_eq!(schema["queryType"], json!({ "name": "Query" }));
+    assert_eq!(schema["mutationType"], json!({ "name": "Mutation" }));
+    assert_eq!(schema["subscriptionType"], Value::Null);
+
+    let types = schema["types"].as_array().unwrap();
+
+    for (kind, name) in &[
+        ("OBJECT", "Query"),
+        ("OBJECT", "User"),
+        ("INPUT_OBJECT", "UserWhereUniqueInput"),
+        ("ENUM", "Role"),
+        ("SCALAR", "DateTime"),
+    ] {
+        assert!(
+            types.contains(&json!({ "kind": kind, "name": name })),
+            "Type {} of kind {} is missing",
+            name,
+            kind
+        );
+    }
+}
+
+#[test]
+#[serial]
+fn introspection_describes_fields_and_their_types() {
+    let response = introspect(
+        r#"
+        {
+            user: __type(name: "User") {
+                __typename
+                fields {
+                    name
+                    type { kind name ofType { kind name } }
+                }
+            }
+            role: __type(name: "Role") {
+                enumValues { name isDeprecated }
+            }
+            missing: __type(name: "Missing") { name }
+        }
+    "#,
+    );
+
+    let user = &response["data"]["user"];
+    let fields = user["fields"].as_array().unwrap();
+    let field = |name: &str| fields.iter().find(|field| field["name"] == name).unwrap().clone();
+
+    assert_eq!(user["__typename"], "__Type");
+    assert_eq!(
+        field("id")["type"],
+        json!({ "kind": "NON_NULL", "name": null, "ofType": { "kind": "SCALAR", "name": "String" } })
+    );
+    assert_eq!(
+        field("name")["type"],
+        json!({ "kind": "SCALAR", "name": "String", "ofType": null })
+    );
+
+    assert_eq!(
+        response["data"]["role"]["enumValues"],
+        json!([{ "name": "ADMIN", "isDeprecated": false }, { "name": "USER", "isDeprecated": false }])
+    );
+    assert_eq!(response["data"]["missing"], Value::Null);
+}
+
+#[test]
+#[serial]
+fn only_introspection_fields_are_introspection_queries() {
+    let introspection = gql::parse_query("{ __typename __schema { types { name } } }").unwrap();
+    let mixed = gql::parse_query("{ __typename findManyUser { id } }").unwrap();
+
+    assert!(GraphQLIntrospection::is_introspection(&introspection, None));
+    assert!(!GraphQLIntrospection::is_introspection(&mixed, None));
+}
+
+#[test]
+#[serial]
+fn introspection_rejects_unknown_fields() {
+    let (query_schema, _) = get_query_schema(DATAMODEL);
+    let doc = gql::parse_query("{ __schema { unknownField } }").unwrap();
+
+    assert!(GraphQLIntrospection::execute(&doc, None, HashMap::new(), &query_schema).is_err());
+}
+
+#[test]
+#[serial]
+fn introspection_describes_the_directives() {
+    let response = introspect(
+        r#"
+        {
+            __schema {
+                directives {
+                    name
+                    locations
+                    args { name defaultValue type { kind name ofType { name } } }
+                }
+            }
+        }
+    "#,
+    );
+
+    let directives = response["data"]["__schema"]["directives"].as_array().unwrap();
+    let names: Vec<_> = directives.iter().map(|directive| &directive["name"]).collect();
+
+    assert_eq!(names, vec!["skip", "include", "deprecated"]);
+    assert_eq!(
+        directives[0]["locations"],
+        json!(["FIELD", "FRAGMENT_SPREAD", "INLINE_FRAGMENT"])
+    );
+    assert_eq!(
+        directives[0]["args"],
+        json!([{
+            "name": "if",
+            "defaultValue": null,
+            "type": { "kind": "NON_NULL", "name": null, "ofType": { "name": "Boolean" } }
+        }])
+    );
+    assert_eq!(
+        directives[2]["args"],
+        json!([{
+            "name": "reason",
+            "defaultValue": "\"No longer supported\"",
+            "type": { "kind": "SCALAR", "name": "String", "ofType": null }
+        }])
+    );
+}
+
+#[test]
+#[serial]
+fn introspection_uses_the_variables_of_the_request() {
+    let query = r#"
+        query ($name: String!, $withFields: Boolean = false) {
+            __type(name: $name) {
+                name
+                fields @include(if: $withFields) { name }
+            }
+        }
+    "#;
+
+    let response = introspect_with_variables(query, json!({ "name": "Post" }));
+    assert_eq!(response["data"]["__type"], json!({ "name": "Post" }));
+
+    let response = introspect_with_variables(query, json!({ "name": "Post", "withFields": true }));
+    let fields = response["data"]["__type"]["fields"].as_array().unwrap();
+    assert!(fields.contains(&json!({ "name": "author" })));
+}
+
+fn introspect(query: &str) -> Value {
+    introspect_with_variables(query, json!({}))
+}
+
+fn introspect_with_variables(query: &str, variables: Value) -> Value {
+    let (query_schema, _) = get_query_schema(DATAMODEL);
+    let doc = gql::parse_query(query).unwrap();
+    let variables = serde_json::from_value(variables).unwrap();
+    let response = GraphQLIntrospection::execute(&doc, None, variables, &query_schema).unwrap();
+
+    serde_json::to_value(&response).unwrap()
+}
@@ -0,0 +1,65 @@
+use super::test_api::*;
+use crate::server::State;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+use tide::http::StatusCode;
+
+static METER: &str = indoc! {"
+    model Meter {
+        id   Int    @id
+        name String
+    }
+"};
+
+/// The value of the sample with exactly these name and labels in the exported metrics, 0 if not exported yet.
+fn sample(metrics: &str, series: &str) -> u64 {
+    metrics
+        .lines()
+        .find_map(|line| line.strip_prefix(series)?.strip_prefix(' '))
+        .map(|value| value.parse().expect("Counters are integers"))
+        .unwrap_or(0)
+}
+
+/// The series the test checks. Metrics are global to the process, so other tests may increase them too.
+static SERIES: &[&str] = &[
+    r#"prisma_requests_total{operation="findMany",model="Meter"}"#,
+    r#"prisma_requests_total{operation="createOne",model="Meter"}"#,
+    r#"prisma_requests_total{operation="findOne",model="Meter"}"#,
+    r#"prisma_transactions_total{outcome="commit"}"#,
+    r#"prisma_errors_total{code="P2009"}"#,
+];
+
+async fn samples(server: &tide::Server<State>) -> anyhow::Result<Vec<u64>> {
+    let (status, metrics) = get(server, "/metrics").await?;
+    assert_eq!(status, StatusCode::Ok);
+
+    Ok(SERIES.iter().map(|series| sample(&metrics, series)).collect())
+}
+
+#[test_each_connector]
+async fn requests_transactions_and_errors_must_be_exported(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(METER).await?;
+    let server = query_engine.server();
+    let before = samples(&server).await?;
+
+    let requests = [
+        "query { findManyMeter { id } }",
+        r#"mutation { createOneMeter(data: { id: 1, name: "Gas" }) { id } }"#,
+        // Fails to build, as the selected field does not exist.
+        "query { findOneMeter(where: { id: 1 }) { volume } }",
+    ];
+
+    for request in &requests {
+        let (status, _) = post(&server, "/", json!({ "query": request, "variables": {} }), &[]).await?;
+        assert_eq!(status, StatusCode::Ok);
+    }
+
+    let after = samples(&server).await?;
+
+    for ((series, before), after) in SERIES.iter().zip(before).zip(after) {
+        assert!(after > before, "{} must increase from {}.", series, before);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use crate::request_handlers::graphql::GraphQLProtocolAdapter;
+use graphql_parser as gql;
+use query_core::{Operation, QueryValue, Selection};
+use serde_json::json;
+
+#[test]
+fn variables_are_substituted() {
+    let operation = convert(
+        r#"
+        query FindUser($id: String!, $take: Int = 10, $orderBy: UserOrderByInput) {
+            findManyUser(where: { id: { equals: $id } }, take: $take, orderBy: $orderBy) { id }
+        }
+    "#,
+        json!({ "id": "a" }),
+    )
+    .unwrap();
+
+    let selection = operation.into_read().unwrap();
+
+    assert_eq!(
+        selection.arguments(),
+        &[
+            (
+                "where".to_owned(),
+                QueryValue::Object(
+                    vec![(
+                        "id".to_owned(),
+                        QueryValue::Object(
+                            vec![("equals".to_owned(), QueryValue::String("a".into()))]
+                                .into_iter()
+                                .collect()
+                        )
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+            ),
+            ("take".to_owned(), QueryValue::Int(10)),
+        ]
+    );
+}
+
+#[test]
+fn variables_are_checked_against_their_types() {
+    let query = "query ($take: Int!) { findManyUser(take: $take) { id } }";
+
+    assert!(convert(query, json!({})).is_err());
+    assert!(convert(query, json!({ "take": "ten" })).is_err());
+    assert!(convert("{ findManyUser(take: $take) { id } }", json!({ "take": 1 })).is_err());
+}
+
+#[test]
+fn fragments_are_expanded_and_fields_merged() {
+    let operation = convert(
+        r#"
+        {
+            findManyUser {
+                id
+                ...UserFields
+                ... on User { posts { id } }
+            }
+        }
+
+        fragment UserFields on User {
+            name
+            posts { title }
+        }
+    "#,
+        json!({}),
+    )
+    .unwrap();
+
+    let selection = operation.into_read().unwrap();
+
+    assert_eq!(keys(selection.nested_selections()), vec!["id", "name", "posts"]);
+    assert_eq!(
+        keys(selection.nested_selections()[2].nested_selections()),
+        vec!["title", "id"]
+    );
+}
+
+#[test]
+fn aliases_are_kept() {
+    let operation = convert("{ users: findManyUser { key: id other: id name } }", json!({})).unwrap();
+
+    let selection = operation.into_read().unwrap();
+
+    assert_eq!(selection.response_key(), "users");
+    assert_eq!(keys(selection.nested_selections()), vec!["key", "other", "name"]);
+    assert!(convert("{ findManyUser { id: name id } }", json!({})).is_err());
+}
+
+#[test]
+fn skip_and_include_leave_out_selections() {
+    let query = r#"
+        query ($withPosts: Boolean!) {
+            findManyUser {
+                id
+                name @skip(if: true)
+                posts @include(if: $withPosts) { id }
+                ... on User @skip(if: $withPosts) { email }
+            }
+        }
+    "#;
+
+    let selection = |with_posts: bool| {
+        let operation = convert(query, json!({ "withPosts": with_posts })).unwrap();
+        operation.into_read().unwrap()
+    };
+
+    assert_eq!(keys(selection(true).nested_selections()), vec!["id", "posts"]);
+    assert_eq!(keys(selection(false).nested_selections()), vec!["id", "email"]);
+
+    assert!(convert("{ findManyUser { id @skip(if: $undefined) } }", json!({})).is_err());
+}
+
+fn convert(query: &str, variables: serde_json::Value) -> crate::PrismaResult<Operation> {
+    let doc = gql::parse_query(query).unwrap();
+    let variables = serde_json::from_value(variables).unwrap();
+
+    GraphQLProtocolAdapter::convert(doc, None, variables)
+}
+
+fn keys(selections: &[Selection]) -> Vec<&str> {
+    selections.iter().map(|selection| selection.response_key()).collect()
+}
@@ -0,0 +1,215 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::ExecutionOptions;
+use query_engine_metrics::{QUERY_CACHE_HITS, QUERY_CACHE_MISSES};
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+static OWNER: &str = indoc! {"
+    model Owner {
+        id   Int   @id
+        cats Cat[]
+    }
+
+    model Cat {
+        id      Int    @id
+        name    String
+        owner   Owner? @relation(fields: [ownerId], references: [id])
+        ownerId Int?
+    }
+"};
+
+static FIND_CATS: &str = "query { findManyCat { name } }";
+
+const QUERY_CACHE_SIZE: Option<usize> = Some(100);
+
+async fn create_cat(engine: &QueryEngine, id: u32, name: &str) {
+    let mutation = format!(
+        r#"mutation {{ createOneCat(data: {{ id: {}, name: "{}" }}) {{ id }} }}"#,
+        id, name
+    );
+
+    engine.request(mutation).await;
+}
+
+async fn find_cats(engine: &QueryEngine, force_primary: bool) -> Vec<String> {
+    find_cats_with(engine, FIND_CATS, force_primary).await
+}
+
+/// The names of the cats the `findManyCat` query returns.
+async fn find_cats_with(engine: &QueryEngine, query: &str, force_primary: bool) -> Vec<String> {
+    let options = ExecutionOptions {
+        force_primary,
+        ..Default::default()
+    };
+
+    engine.request_with_options(query, options).await["data"]["findManyCat"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|cat| cat["name"].as_str().unwrap().to_owned())
+        .collect()
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reads_must_be_served_from_the_cache(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(CAT, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+
+    // Writes of other processes don't invalidate the cache.
+    let other_engine = api.create_engine(CAT).await?;
+    create_cat(&other_engine, 2, "Garfield").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+    assert_eq!(find_cats(&engine, true).await, vec!["Felix", "Garfield"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn writes_must_invalidate_the_cache(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(CAT, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+
+    create_cat(&engine, 2, "Garfield").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix", "Garfield"]);
+
+    engine
+        .request(r#"mutation { deleteOneCat(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Garfield"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn replica_reads_must_not_be_cached(api: &TestApi) -> anyhow::Result<()> {
+    let replica = sqlite_test_api("replica_reads_must_not_be_cached_replica").await;
+    let replica_engine = replica.create_engine(CAT).await?;
+    create_cat(&replica_engine, 1, "Felix").await;
+
+    let engine = api
+        .create_engine_with_query_cache(CAT, &[&replica], QUERY_CACHE_SIZE)
+        .await?;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+
+    create_cat(&replica_engine, 2, "Tom").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix", "Tom"]);
+
+    // Reads of the primary are cached, and the cached responses are served to reads of replicas.
+    create_cat(&engine, 3, "Garfield").await;
+
+    assert_eq!(find_cats(&engine, true).await, vec!["Garfield"]);
+    assert_eq!(find_cats(&engine, false).await, vec!["Garfield"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn nested_writes_must_invalidate_the_cache(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(OWNER, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+
+    engine
+        .request(r#"mutation { createOneOwner(data: { id: 1, cats: { create: { id: 2, name: "Tom" } } }) { id } }"#)
+        .await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix", "Tom"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn raw_writes_must_invalidate_the_cache(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(CAT, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+
+    engine
+        .request(r#"mutation { executeRaw(query: "DELETE FROM Cat") }"#)
+        .await;
+
+    assert!(find_cats(&engine, false).await.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_least_recently_used_response_must_be_evicted(api: &TestApi) -> anyhow::Result<()> {
+    static WITHOUT_TOM: &str = r#"query { findManyCat(where: { name: { not: "Tom" } }) { name } }"#;
+    static WITHOUT_FELIX: &str = r#"query { findManyCat(where: { name: { not: "Felix" } }) { name } }"#;
+
+    let engine = api.create_engine_with_query_cache(CAT, &[], Some(2)).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+    assert_eq!(find_cats_with(&engine, WITHOUT_TOM, false).await, vec!["Felix"]);
+
+    // Using the first response makes the second one the least recently used, evicted by the third one.
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+    assert!(find_cats_with(&engine, WITHOUT_FELIX, false).await.is_empty());
+
+    // Writes of other processes don't invalidate the cache, only evicted responses are read again.
+    let other_engine = api.create_engine(CAT).await?;
+    create_cat(&other_engine, 2, "Garfield").await;
+
+    assert_eq!(find_cats(&engine, false).await, vec!["Felix"]);
+    assert_eq!(
+        find_cats_with(&engine, WITHOUT_TOM, false).await,
+        vec!["Felix", "Garfield"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reordered_input_object_fields_must_share_the_cached_response(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(CAT, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    let query = r#"query { findManyCat(where: { id: { gt: 0 }, name: { contains: "e", not: "Tom" } }) { name } }"#;
+    assert_eq!(find_cats_with(&engine, query, false).await, vec!["Felix"]);
+
+    let other_engine = api.create_engine(CAT).await?;
+    create_cat(&other_engine, 2, "Garfield").await;
+
+    let query = r#"query { findManyCat(where: { name: { not: "Tom", contains: "e" }, id: { gt: 0 } }) { name } }"#;
+    assert_eq!(find_cats_with(&engine, query, false).await, vec!["Felix"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn cache_hits_and_misses_must_be_counted(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api.create_engine_with_query_cache(CAT, &[], QUERY_CACHE_SIZE).await?;
+    create_cat(&engine, 1, "Felix").await;
+
+    // The counters are global to the process, other tests may increase them too.
+    let labels = ["findMany", "Cat"];
+    let (hits, misses) = (QUERY_CACHE_HITS.get(&labels), QUERY_CACHE_MISSES.get(&labels));
+
+    find_cats(&engine, false).await;
+    assert!(QUERY_CACHE_MISSES.get(&labels) > misses);
+
+    find_cats(&engine, false).await;
+    assert!(QUERY_CACHE_HITS.get(&labels) > hits);
+
+    Ok(())
+}
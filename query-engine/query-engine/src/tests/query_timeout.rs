@@ -0,0 +1,99 @@
+use super::test_api::*;
+use indoc::indoc;
+use quaint::{ast::*, connector::ConnectionInfo, prelude::*, single::Quaint};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use test_macros::*;
+use tide::http::{Method, StatusCode, Url};
+
+static OWNER: &str = indoc! {"
+    model Owner {
+        id   Int   @id
+        cats Cat[]
+    }
+
+    model Cat {
+        id      Int    @id
+        owner   Owner? @relation(fields: [ownerId], references: [id])
+        ownerId Int?
+    }
+"};
+
+static TIMEOUT_HEADER: &str = "x-prisma-query-timeout";
+
+fn sleep_query(api: &TestApi) -> &'static str {
+    match api.connection_info() {
+        ConnectionInfo::Postgres(_) => "SELECT pg_sleep(10)",
+        _ => "SELECT SLEEP(10)",
+    }
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn queries_must_time_out_after_the_timeout_of_the_header(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(OWNER).await?;
+    let server = query_engine.server();
+    let query = format!(r#"mutation {{ queryRaw(query: "{}") }}"#, sleep_query(api));
+    let start = Instant::now();
+
+    let (status, response) = post(
+        &server,
+        "/",
+        json!({ "query": query, "variables": {} }),
+        &[(TIMEOUT_HEADER, "200")],
+    )
+    .await?;
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2023");
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn timed_out_writes_must_be_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(OWNER).await?;
+    let server = query_engine.server();
+
+    // The uncommitted cat of another transaction blocks the nested create after the owner is created.
+    let blocker = Quaint::new(&api.url()).await?;
+    let tx = blocker.start_transaction().await?;
+    tx.insert(Insert::single_into("Cat").value("id", 1).into()).await?;
+
+    let mutation = "mutation { createOneOwner(data: { id: 1, cats: { create: { id: 1 } } }) { id } }";
+    let (status, response) = post(
+        &server,
+        "/",
+        json!({ "query": mutation, "variables": {} }),
+        &[(TIMEOUT_HEADER, "500")],
+    )
+    .await?;
+
+    tx.rollback().await?;
+
+    assert_eq!(status, StatusCode::Ok);
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2023");
+
+    let owners = query_engine.request("query { findManyOwner { id } }").await;
+    assert_eq!(owners, json!({ "data": { "findManyOwner": [] } }));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn invalid_timeouts_in_the_header_must_be_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(OWNER).await?;
+    let server = query_engine.server();
+
+    let mut req = tide::http::Request::new(Method::Post, Url::parse("http://localhost/")?);
+    req.insert_header(TIMEOUT_HEADER, "soon");
+    req.set_body(
+        tide::Body::from_json(&json!({ "query": "query { findManyOwner { id } }", "variables": {} }))
+            .map_err(|err| err.into_inner())?,
+    );
+
+    let res: tide::http::Response = server.respond(req).await.map_err(|err| err.into_inner())?;
+    assert_eq!(res.status(), StatusCode::BadRequest);
+
+    Ok(())
+}
@@ -0,0 +1,119 @@
+use super::test_api::*;
+use indoc::indoc;
+use query_core::ExecutionOptions;
+use serde_json::json;
+use test_macros::*;
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+static FIND_CATS: &str = "query { findManyCat { name } }";
+
+/// An engine whose replica is a separate database, only containing the cat Felix.
+async fn engine_with_replica(api: &TestApi, replica_db_name: &str) -> anyhow::Result<QueryEngine> {
+    let replica = sqlite_test_api(replica_db_name).await;
+    let replica_engine = replica.create_engine(CAT).await?;
+
+    replica_engine
+        .request(r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .await;
+
+    api.create_engine_with_replicas(CAT, &[&replica]).await
+}
+
+fn cat_names(response: &serde_json::Value) -> Vec<&str> {
+    response["data"]["findManyCat"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|cat| cat["name"].as_str().unwrap())
+        .collect()
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reads_must_run_on_the_replica(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_replica(api, "reads_must_run_on_the_replica_replica").await?;
+
+    assert_eq!(cat_names(&engine.request(FIND_CATS).await), vec!["Felix"]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn writes_must_run_on_the_primary(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_replica(api, "writes_must_run_on_the_primary_replica").await?;
+
+    // The selection of the write is read in its transaction, on the primary.
+    let response = engine
+        .request(r#"mutation { createOneCat(data: { id: 2, name: "Garfield" }) { name } }"#)
+        .await;
+
+    assert_eq!(response["data"]["createOneCat"]["name"], "Garfield");
+    assert_eq!(cat_names(&engine.request(FIND_CATS).await), vec!["Felix"]);
+
+    let options = ExecutionOptions {
+        force_primary: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        cat_names(&engine.request_with_options(FIND_CATS, options).await),
+        vec!["Garfield"]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn reads_in_transactions_must_run_on_the_primary(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_replica(api, "reads_in_transactions_must_run_on_the_primary_replica").await?;
+
+    engine
+        .request(r#"mutation { createOneCat(data: { id: 2, name: "Garfield" }) { id } }"#)
+        .await;
+
+    let batch = |transaction: bool| {
+        json!({
+            "batch": [{ "query": FIND_CATS }, { "query": FIND_CATS }],
+            "transaction": transaction,
+        })
+    };
+
+    let (_, response) = post(&engine.server(), "/", batch(true), &[]).await?;
+
+    for response in response.as_array().unwrap() {
+        assert_eq!(cat_names(response), vec!["Garfield"]);
+    }
+
+    let (_, response) = post(&engine.server(), "/", batch(false), &[]).await?;
+
+    for response in response.as_array().unwrap() {
+        assert_eq!(cat_names(response), vec!["Felix"]);
+    }
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_force_primary_header_must_send_reads_to_the_primary(api: &TestApi) -> anyhow::Result<()> {
+    let engine = engine_with_replica(api, "the_force_primary_header_must_send_reads_to_the_primary_replica").await?;
+
+    engine
+        .request(r#"mutation { createOneCat(data: { id: 2, name: "Garfield" }) { id } }"#)
+        .await;
+
+    let server = engine.server();
+    let query = json!({ "query": FIND_CATS });
+
+    let (_, response) = post(&server, "/", query.clone(), &[("x-prisma-force-primary", "true")]).await?;
+    assert_eq!(cat_names(&response), vec!["Garfield"]);
+
+    let (_, response) = post(&server, "/", query, &[]).await?;
+    assert_eq!(cat_names(&response), vec!["Felix"]);
+
+    Ok(())
+}
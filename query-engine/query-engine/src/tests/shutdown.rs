@@ -0,0 +1,144 @@
+use super::test_api::*;
+use crate::server::{Shutdown, ShutdownMiddleware};
+use futures::future::{self, Either};
+use indoc::indoc;
+use quaint::{ast::*, prelude::*, single::Quaint};
+use query_core::ExecutionOptions;
+use serde_json::json;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use test_macros::*;
+use tide::http::{Method, StatusCode, Url};
+
+/// A server taking `duration` to respond to requests.
+fn slow_server(shutdown: Arc<Shutdown>, duration: Duration) -> tide::Server<()> {
+    let mut app = tide::new();
+    app.with(ShutdownMiddleware::new(shutdown));
+
+    app.at("/").get(move |_| async move {
+        async_std::task::sleep(duration).await;
+        Ok::<_, tide::Error>("Done")
+    });
+
+    app
+}
+
+async fn get(server: &tide::Server<()>) -> tide::http::Response {
+    let req = tide::http::Request::new(Method::Get, Url::parse("http://localhost/").unwrap());
+    server.respond(req).await.unwrap()
+}
+
+#[tokio::test]
+async fn draining_must_wait_for_in_flight_requests() {
+    let shutdown = Arc::new(Shutdown::new());
+    let server = slow_server(shutdown.clone(), Duration::from_millis(200));
+
+    let (response, in_flight) = tokio::join!(get(&server), async {
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        shutdown.drain(Duration::from_secs(10)).await
+    });
+
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert_eq!(in_flight, 0);
+}
+
+#[tokio::test]
+async fn draining_must_stop_after_the_grace_period() {
+    let shutdown = Arc::new(Shutdown::new());
+    let server = slow_server(shutdown.clone(), Duration::from_secs(10));
+    let start = Instant::now();
+
+    let drained = async {
+        async_std::task::sleep(Duration::from_millis(50)).await;
+        shutdown.drain(Duration::from_millis(100)).await
+    };
+
+    match future::select(Box::pin(get(&server)), Box::pin(drained)).await {
+        Either::Right((in_flight, _)) => assert_eq!(in_flight, 1),
+        Either::Left(_) => panic!("The request must still be in flight."),
+    }
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn draining_servers_must_refuse_requests() {
+    let shutdown = Arc::new(Shutdown::new());
+    let server = slow_server(shutdown.clone(), Duration::from_millis(0));
+
+    assert_eq!(shutdown.drain(Duration::from_secs(10)).await, 0);
+    assert_eq!(get(&server).await.status(), StatusCode::ServiceUnavailable);
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn draining_engines_must_refuse_queries(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api
+        .create_engine(indoc! {"
+            model Cat {
+                id Int @id
+            }
+        "})
+        .await?;
+
+    let shutdown = Arc::new(Shutdown::new());
+    let server = engine.server_with_shutdown(shutdown.clone());
+    let query = json!({ "query": "query { findManyCat { id } }" });
+
+    let (status, _) = post(&server, "/", query.clone(), &[]).await?;
+    assert_eq!(status, StatusCode::Ok);
+
+    shutdown.drain(Duration::from_secs(10)).await;
+
+    let (status, response) = post(&server, "/", query, &[]).await?;
+    assert_eq!(status, StatusCode::ServiceUnavailable);
+    assert_eq!(response["error_code"], "P2024");
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn writes_aborted_by_the_executor_shutdown_must_be_rolled_back(api: &TestApi) -> anyhow::Result<()> {
+    let engine = api
+        .create_engine(indoc! {"
+            model Owner {
+                id   Int   @id
+                cats Cat[]
+            }
+
+            model Cat {
+                id      Int    @id
+                owner   Owner? @relation(fields: [ownerId], references: [id])
+                ownerId Int?
+            }
+        "})
+        .await?;
+
+    // The uncommitted cat of another transaction blocks the nested create after the owner is created.
+    let blocker = Quaint::new(&api.url()).await?;
+    let tx = blocker.start_transaction().await?;
+    tx.insert(Insert::single_into("Cat").value("id", 1).into()).await?;
+
+    // With a timeout, the aborted statement is cancelled instead of waited for.
+    let options = ExecutionOptions {
+        timeout: Some(Duration::from_secs(30)),
+        ..Default::default()
+    };
+
+    let mutation = "mutation { createOneOwner(data: { id: 1, cats: { create: { id: 1 } } }) { id } }";
+
+    let (response, _) = tokio::join!(engine.request_with_options(mutation, options), async {
+        async_std::task::sleep(Duration::from_millis(200)).await;
+        engine.shut_down_executor(Duration::from_secs(10)).await
+    });
+
+    tx.rollback().await?;
+
+    assert_eq!(response["errors"][0]["user_facing_error"]["error_code"], "P2024");
+
+    let owners = blocker.select(Select::from_table("Owner")).await?;
+    assert!(owners.is_empty());
+
+    Ok(())
+}
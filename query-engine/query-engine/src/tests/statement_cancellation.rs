@@ -0,0 +1,75 @@
+use super::test_api::*;
+use connector::{Connection, Connector};
+use quaint::connector::ConnectionInfo;
+use sql_connector::{FromSource, Mysql, PostgreSql, Sqlite};
+use std::time::{Duration, Instant};
+use test_macros::*;
+
+/// A connector to the test database with a single pooled connection, so cancelling can't use the pool.
+async fn single_connection_connector(api: &TestApi) -> anyhow::Result<Box<dyn Connector>> {
+    let url = api.url();
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{}{}connection_limit=1", url, separator);
+
+    let connector: Box<dyn Connector> = match api.connection_info() {
+        ConnectionInfo::Postgres(_) => Box::new(PostgreSql::from_url(&url).await?),
+        ConnectionInfo::Mysql(_) => Box::new(Mysql::from_url(&url).await?),
+        _ => Box::new(Sqlite::from_url(&url).await?),
+    };
+
+    Ok(connector)
+}
+
+fn sleep_query(api: &TestApi) -> String {
+    match api.connection_info() {
+        ConnectionInfo::Postgres(_) => "SELECT pg_sleep(10)".to_owned(),
+        _ => "SELECT SLEEP(10)".to_owned(),
+    }
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn cancelling_must_interrupt_the_statement_when_the_pool_is_exhausted(api: &TestApi) -> anyhow::Result<()> {
+    let connector = single_connection_connector(api).await?;
+    let conn = connector.get_connection().await?;
+    let canceller = conn.statement_canceller().await?.expect("A statement canceller");
+
+    let start = Instant::now();
+
+    let (_, cancelled) = tokio::join!(conn.query_raw(sleep_query(api), vec![]), async {
+        async_std::task::sleep(Duration::from_millis(200)).await;
+        canceller.cancel().await
+    });
+
+    cancelled?;
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn cancelling_an_idle_connection_must_not_interrupt_its_next_statement(api: &TestApi) -> anyhow::Result<()> {
+    let connector = single_connection_connector(api).await?;
+    let conn = connector.get_connection().await?;
+    let canceller = conn.statement_canceller().await?.expect("A statement canceller");
+
+    canceller.cancel().await?;
+
+    let result = conn.query_raw("SELECT 1 AS one".to_owned(), vec![]).await?;
+    assert_eq!(result[0]["one"], 1);
+
+    // The session id is cached, a second canceller cancels the same session.
+    let canceller = conn.statement_canceller().await?.expect("A statement canceller");
+    canceller.cancel().await?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn statements_must_not_be_cancelled_on_sqlite(api: &TestApi) -> anyhow::Result<()> {
+    let connector = single_connection_connector(api).await?;
+    let conn = connector.get_connection().await?;
+
+    assert!(conn.statement_canceller().await?.is_none());
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+use super::test_api::*;
+use indoc::indoc;
+use std::sync::{Arc, Mutex};
+use test_macros::*;
+use tracing::{
+    span::{Attributes, Id},
+    Subscriber,
+};
+use tracing_futures::Instrument;
+use tracing_subscriber::{
+    layer::{Context, Layer},
+    registry::LookupSpan,
+    EnvFilter,
+};
+
+static CAT: &str = indoc! {"
+    model Cat {
+        id   Int    @id
+        name String
+    }
+"};
+
+/// Records the spans passed to the exporting layer, with the names of their ancestors.
+#[derive(Clone, Default)]
+struct SpanTree(Arc<Mutex<Vec<(String, Vec<String>)>>>);
+
+impl SpanTree {
+    fn ancestors_of(&self, name: &str) -> Vec<Vec<String>> {
+        self.0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(span_name, _)| span_name == name)
+            .map(|(_, ancestors)| ancestors.clone())
+            .collect()
+    }
+}
+
+impl<S> Layer<S> for SpanTree
+where
+    S: Subscriber + for<'span> LookupSpan<'span>,
+{
+    fn new_span(&self, _: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let span = ctx.span(id).expect("The span was just created");
+        let ancestors = span.parents().map(|parent| parent.name().to_owned()).collect();
+
+        self.0.lock().unwrap().push((span.name().to_owned(), ancestors));
+    }
+}
+
+#[test_each_connector]
+async fn spans_below_the_log_filter_must_be_exported(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(CAT).await?;
+    let spans = SpanTree::default();
+
+    let _guard = tracing::subscriber::set_default(crate::json_subscriber(EnvFilter::new("error"), spans.clone()));
+
+    let response = query_engine
+        .request(r#"mutation { createOneCat(data: { id: 1, name: "Felix" }) { id } }"#)
+        .instrument(info_span!("request"))
+        .await;
+
+    assert_eq!(response["data"]["createOneCat"]["id"], 1);
+
+    let has_ancestor = |ancestors: &Vec<String>, name: &str| ancestors.iter().any(|ancestor| ancestor == name);
+
+    for name in &["parse_request", "build_query_graph", "expression"] {
+        let ancestors = spans.ancestors_of(name);
+
+        assert!(!ancestors.is_empty(), "Expected a `{}` span", name);
+        assert!(ancestors.iter().all(|ancestors| has_ancestor(ancestors, "request")));
+    }
+
+    let statements = spans.ancestors_of("sql_statement");
+
+    assert!(!statements.is_empty());
+    assert!(statements
+        .iter()
+        .all(|ancestors| has_ancestor(ancestors, "expression") && has_ancestor(ancestors, "request")));
+
+    Ok(())
+}
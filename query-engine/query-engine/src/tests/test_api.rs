@@ -1,20 +1,24 @@
 use crate::{
     context::PrismaContext,
     request_handlers::{graphql, GraphQlBody, SingleQuery},
+    server::{self, Shutdown, State},
     PrismaResponse,
 };
 use migration_core::{
     api::{GenericApi, MigrationApi},
     commands::SchemaPushInput,
 };
+use prisma_models::ModelRef;
 use quaint::{
     ast::*,
     connector::ConnectionInfo,
     visitor::{self, Visitor},
 };
+use query_core::ExecutionOptions;
 use sql_migration_connector::{sql_migration::SqlMigration, SqlMigrationConnector};
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use test_setup::*;
+use tide::http::{Method, StatusCode, Url};
 
 pub struct QueryEngine {
     context: Arc<PrismaContext>,
@@ -26,13 +30,77 @@ impl QueryEngine {
     }
 
     pub async fn request(&self, body: impl Into<SingleQuery>) -> serde_json::Value {
+        self.request_with_options(body, ExecutionOptions::default()).await
+    }
+
+    /// Executes the request with the options the server derives from its headers.
+    pub async fn request_with_options(
+        &self,
+        body: impl Into<SingleQuery>,
+        options: ExecutionOptions,
+    ) -> serde_json::Value {
         let body = GraphQlBody::Single(body.into());
         let cx = self.context.clone();
-        match graphql::handle(body, cx).await {
+        match graphql::handle(body, cx, options).await {
             PrismaResponse::Single(response) => serde_json::to_value(response).unwrap(),
             _ => unreachable!(),
         }
     }
+
+    /// The HTTP server of the engine in debug mode, to send requests with headers or batches.
+    pub fn server(&self) -> tide::Server<State> {
+        self.server_with_shutdown(Arc::new(Shutdown::new()))
+    }
+
+    /// The HTTP server of the engine in debug mode, shutting down with the given `Shutdown`.
+    pub fn server_with_shutdown(&self, shutdown: Arc<Shutdown>) -> tide::Server<State> {
+        server::routes(State::new(self.context.clone(), false, true, None, shutdown))
+    }
+
+    /// Shuts the executor of the engine down, aborting the running executions.
+    pub async fn shut_down_executor(&self, timeout: Duration) {
+        self.context.executor.shutdown(timeout).await;
+    }
+
+    /// The model of the internal data model of the engine, to call connectors directly.
+    pub fn model(&self, name: &str) -> ModelRef {
+        self.context
+            .query_schema()
+            .internal_data_model
+            .find_model(name)
+            .unwrap()
+    }
+}
+
+/// Posts the JSON body to the server, and returns the status and JSON body of the response.
+pub async fn post(
+    server: &tide::Server<State>,
+    path: &str,
+    body: serde_json::Value,
+    headers: &[(&str, &str)],
+) -> anyhow::Result<(StatusCode, serde_json::Value)> {
+    let mut req = tide::http::Request::new(Method::Post, Url::parse("http://localhost/")?.join(path)?);
+
+    for (name, value) in headers {
+        req.insert_header(*name, *value);
+    }
+
+    req.set_body(tide::Body::from_json(&body).map_err(|err| err.into_inner())?);
+
+    let mut res: tide::http::Response = server.respond(req).await.map_err(|err| err.into_inner())?;
+    let body = res.body_json().await.map_err(|err| err.into_inner())?;
+
+    Ok((res.status(), body))
+}
+
+/// Gets the path from the server, and returns the status and text body of the response.
+pub async fn get(server: &tide::Server<State>, path: &str) -> anyhow::Result<(StatusCode, String)> {
+    let req = tide::http::Request::new(Method::Get, Url::parse("http://localhost/")?.join(path)?);
+
+    let mut res: tide::http::Response = server.respond(req).await.map_err(|err| err.into_inner())?;
+    let body = res.body_string().await.map_err(|err| err.into_inner())?;
+
+    Ok((res.status(), body))
 }
 
 pub struct TestApi {
@@ -43,9 +111,40 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_replicas(datamodel, &[]).await
+    }
+
+    /// Creates an engine reading from the databases of the given test APIs as read replicas. Their schemas
+    /// are not pushed, and they don't replicate the primary, so their data tells where a query ran.
+    pub async fn create_engine_with_replicas(
+        &self,
+        datamodel: &str,
+        replicas: &[&TestApi],
+    ) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_query_cache(datamodel, replicas, None).await
+    }
+
+    /// Creates an engine with read replicas, caching up to `query_cache_size` responses.
+    pub async fn create_engine_with_query_cache(
+        &self,
+        datamodel: &str,
+        replicas: &[&TestApi],
+        query_cache_size: Option<usize>,
+    ) -> anyhow::Result<QueryEngine> {
+        // The schema builder and the executor read the feature flags, e.g. for atomic number operations.
+        feature_flags::initialize(&[String::from("all")]).unwrap();
+
         let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
         let dml = datamodel::parse_datamodel(&datamodel_string).unwrap();
-        let config = datamodel::parse_configuration(&datamodel_string).unwrap();
+        let mut config = datamodel::parse_configuration(&datamodel_string).unwrap();
+
+        config.datasources[0].replica_urls = replicas
+            .iter()
+            .map(|replica| {
+                let replica_config = datamodel::parse_configuration(&replica.config).unwrap();
+                replica_config.datasources[0].url().clone()
+            })
+            .collect();
 
         self.migration_api
             .schema_push(&SchemaPushInput {
@@ -56,6 +155,7 @@ impl TestApi {
 
         let context = PrismaContext::builder(config, dml)
             .enable_raw_queries(true)
+            .query_cache_size(query_cache_size)
             .build()
             .await
             .unwrap();
@@ -69,6 +169,12 @@ impl TestApi {
         &self.connection_info
     }
 
+    /// The URL of the test database.
+    pub fn url(&self) -> String {
+        let config = datamodel::parse_configuration(&self.config).unwrap();
+        config.datasources[0].url().value.clone()
+    }
+
     pub fn to_sql_string<'a>(&'a self, query: impl Into<Query<'a>>) -> quaint::Result<(String, Vec<Value>)> {
         match self.connection_info() {
             ConnectionInfo::Postgres(..) => visitor::Postgres::build(query),